@@ -0,0 +1,101 @@
+use std::marker::PhantomData;
+
+use arrow::array::{Array, AsArray, DictionaryArray, PrimitiveIter};
+use arrow::datatypes::{ArrowDictionaryKeyType, ArrowNativeType};
+
+use super::{ArrowArray, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::{ArrowDictionaryKey, ArrowField, Dictionary};
+
+/// Internal `ArrowArray` helper to iterate over a `DictionaryArray` as its decoded values.
+///
+/// `K` is the Arrow key type and `V` the value type being deserialized.
+pub struct DecodedDictionaryArray<K, V>(PhantomData<(K, V)>);
+
+/// Iterator for for [`DecodedDictionaryArray`]
+pub struct DecodedDictionaryArrayIter<'a, K: ArrowDictionaryKeyType, V: ArrowField> {
+    keys: PrimitiveIter<'a, K>,
+    values: Vec<Option<<V as ArrowField>::Type>>,
+}
+
+impl<K, V> Iterator for DecodedDictionaryArrayIter<'_, K, V>
+where
+    K: ArrowDictionaryKeyType,
+    V: ArrowField,
+    <V as ArrowField>::Type: Clone,
+{
+    type Item = Option<<V as ArrowField>::Type>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys
+            .next()
+            .map(|key| key.and_then(|k| self.values[k.as_usize()].clone()))
+    }
+}
+
+impl<K, V> ArrowArrayIterable for DecodedDictionaryArray<K, V>
+where
+    K: ArrowDictionaryKeyType,
+    V: ArrowField + 'static,
+    <V as ArrowField>::Type: Clone,
+{
+    type Item<'a> = Option<<V as ArrowField>::Type>;
+
+    type Iter<'a> = DecodedDictionaryArrayIter<'a, K, V>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl<K, V> ArrowArray for DecodedDictionaryArray<K, V>
+where
+    K: ArrowDictionaryKeyType,
+    V: ArrowDeserialize + 'static,
+    <V as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <V as ArrowField>::Type: Clone,
+{
+    type BaseArrayType = DictionaryArray<K>;
+
+    #[inline]
+    fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let arr = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        // the dictionary values are decoded once and cloned for each key
+        let values = <<V as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(arr.values().as_ref())
+            .map(<V as ArrowDeserialize>::arrow_deserialize)
+            .collect();
+        DecodedDictionaryArrayIter {
+            keys: arr.keys().iter(),
+            values,
+        }
+    }
+}
+
+impl<K, V> ArrowDeserialize for Dictionary<K, V>
+where
+    K: ArrowDictionaryKey,
+    V: ArrowDeserialize + 'static,
+    <V as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <V as ArrowField>::Type: Clone,
+{
+    type ArrayType = DecodedDictionaryArray<<K as ArrowDictionaryKey>::KeyType, V>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<<V as ArrowField>::Type>) -> Option<<V as ArrowField>::Type> {
+        v
+    }
+
+    #[inline]
+    fn validate(v: &<V as ArrowField>::Type) -> Result<(), String> {
+        <V as ArrowDeserialize>::validate(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        // all the values are decoded, including the ones without keys
+        match array.as_dictionary_opt::<<K as ArrowDictionaryKey>::KeyType>() {
+            Some(array) => <V as ArrowDeserialize>::validate_array(array.values().as_ref()),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use arrow::array::{Array, StructArray};
+
+use super::{ArrowArray, ArrowArrayIterable};
+
+/// Internal `ArrowArray` helper to iterate over a `StructArray` without fields.
+///
+/// Valid slots are yielded as `Some(())` and null slots as `None`.
+pub struct EmptyStructArray;
+
+/// Iterator for for [`EmptyStructArray`]
+pub struct EmptyStructArrayIter<'a> {
+    arr: &'a StructArray,
+    index_iter: std::ops::Range<usize>,
+}
+
+impl Iterator for EmptyStructArrayIter<'_> {
+    type Item = Option<()>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index_iter.next()?;
+        Some(self.arr.is_valid(index).then_some(()))
+    }
+}
+
+impl ArrowArrayIterable for EmptyStructArray {
+    type Item<'a> = Option<()>;
+
+    type Iter<'a> = EmptyStructArrayIter<'a>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl ArrowArray for EmptyStructArray {
+    type BaseArrayType = StructArray;
+
+    #[inline]
+    fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let arr = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        EmptyStructArrayIter {
+            arr,
+            index_iter: 0..arr.len(),
+        }
+    }
+}
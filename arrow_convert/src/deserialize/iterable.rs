@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
-use super::{BufferBinaryArray, BufferBinaryArrayIter};
-use arrow::{array::*, datatypes::ArrowPrimitiveType};
+use super::{BufferBinaryArray, BufferBinaryArrayIter, LargeBufferBinaryArray, LargeBufferBinaryArrayIter};
+use arrow::{
+    array::*,
+    datatypes::{ArrowPrimitiveType, ByteViewType},
+};
 
 /// A trait for Arrow arrays that can be transformed into an iterator.
 pub trait ArrowArrayIterable {
@@ -39,6 +42,16 @@ impl<OffsetSize: OffsetSizeTrait> ArrowArrayIterable for GenericStringArray<Offs
     }
 }
 
+impl<T: ByteViewType + ?Sized> ArrowArrayIterable for GenericByteViewArray<T> {
+    type Item<'a> = Option<&'a T::Native>;
+
+    type Iter<'a> = ArrayIter<&'a GenericByteViewArray<T>>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
 impl ArrowArrayIterable for BooleanArray {
     type Item<'a> = Option<bool>;
 
@@ -49,6 +62,16 @@ impl ArrowArrayIterable for BooleanArray {
     }
 }
 
+impl ArrowArrayIterable for NullArray {
+    type Item<'a> = Option<()>;
+
+    type Iter<'a> = std::iter::Take<std::iter::Repeat<Option<()>>>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        std::iter::repeat(None).take(self.len())
+    }
+}
+
 impl ArrowArrayIterable for BufferBinaryArray {
     type Item<'a> = Option<&'a [u8]>;
 
@@ -59,6 +82,16 @@ impl ArrowArrayIterable for BufferBinaryArray {
     }
 }
 
+impl ArrowArrayIterable for LargeBufferBinaryArray {
+    type Item<'a> = Option<arrow::buffer::Buffer>;
+
+    type Iter<'a> = LargeBufferBinaryArrayIter<'a>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait> ArrowArrayIterable for GenericBinaryArray<OffsetSize> {
     type Item<'a> = Option<&'a [u8]>;
 
@@ -89,6 +122,16 @@ impl<OffsetSize: OffsetSizeTrait> ArrowArrayIterable for GenericListArray<Offset
     }
 }
 
+impl<OffsetSize: OffsetSizeTrait> ArrowArrayIterable for GenericListViewArray<OffsetSize> {
+    type Item<'a> = Option<Arc<dyn Array>>;
+
+    type Iter<'a> = GenericListViewArrayIter<'a, OffsetSize>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
 impl ArrowArrayIterable for FixedSizeListArray {
     type Item<'a> = Option<Arc<dyn Array>>;
 
@@ -98,3 +141,13 @@ impl ArrowArrayIterable for FixedSizeListArray {
         self.iter()
     }
 }
+
+impl ArrowArrayIterable for MapArray {
+    type Item<'a> = Option<StructArray>;
+
+    type Iter<'a> = MapArrayIter<'a>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
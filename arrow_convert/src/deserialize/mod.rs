@@ -2,12 +2,36 @@
 mod iterable;
 pub use iterable::*;
 
+mod tuple_array;
+pub use tuple_array::*;
+
+mod dictionary_array;
+pub use dictionary_array::*;
+
+mod run_end_array;
+pub use run_end_array::*;
+
+mod union_pair_array;
+pub use union_pair_array::*;
+
+mod empty_struct_array;
+pub use empty_struct_array::*;
+
+mod view;
+pub use view::*;
+
+mod typed_array;
+pub use typed_array::*;
+
+use arrow::record_batch::RecordBatch;
 use arrow::{
     array::*,
     buffer::{Buffer, ScalarBuffer},
-    datatypes::{self, ArrowNativeType, ArrowPrimitiveType, Decimal128Type},
+    datatypes::{self, ArrowNativeType, ArrowPrimitiveType, Decimal128Type, UInt8Type},
 };
 use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
 
 use crate::field::*;
 
@@ -22,6 +46,48 @@ where
     /// Deserialize this field from arrow
     fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type>;
 
+    /// Returns whether an array of `data_type` can be deserialized to this field.
+    ///
+    /// Defaults to an exact match with [`ArrowField::data_type`]. Derived structs override it to also accept
+    /// struct arrays whose columns differ in ways allowed by the field attributes. Lists, maps, tuples, ranges,
+    /// results and nested options check their children, so they accept these struct arrays as well.
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        data_type == &<Self as ArrowField>::data_type()
+    }
+
+    /// Returns the columns of an array of `data_type` that are rejected because they don't belong to this field.
+    ///
+    /// Defaults to none. Derived structs with the `deny_unknown_fields` attribute return their unknown columns,
+    /// and the unknown columns of their struct fields prefixed by the field name, such as `inner.extra`. Lists,
+    /// maps, tuples, ranges, results and nested options return the unknown columns of their children.
+    #[inline]
+    fn unknown_fields(_data_type: &datatypes::DataType) -> Vec<String> {
+        vec![]
+    }
+
+    /// Checks a deserialized value, returning a description of the first constraint it violates.
+    ///
+    /// Defaults to accepting all values. Derived structs call the functions of their `validate` attributes, and
+    /// validate their fields. Options, lists, maps, tuples, ranges, results, derived enums, numeric wrappers and
+    /// dictionary or run-end encoded values validate their values. [`TryIntoCollection`] validates every row.
+    #[inline]
+    fn validate(_v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Checks an array before it's deserialized, returning a description of the first value that can't be
+    /// deserialized to this field.
+    ///
+    /// Defaults to accepting all arrays. Fields that can't hold every value of their data type, such as `NotNan` or
+    /// collections with a capacity, check the values. Derived enums without a fallback variant check their names or
+    /// codes, and containers, tuples, derived structs and derived enums check their children. It's called after
+    /// checking the data type of the array, so that deserializing returns an error rather than panicking.
+    #[inline]
+    fn validate_array(_array: &dyn Array) -> Result<(), String> {
+        Ok(())
+    }
+
     #[inline]
     #[doc(hidden)]
     /// For internal use only
@@ -95,12 +161,307 @@ where
         Self::arrow_deserialize_internal(v).map(Some)
     }
 
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        <T as ArrowDeserialize>::is_deserializable_from(data_type)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        <T as ArrowDeserialize>::unknown_fields(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        match v {
+            Some(v) => <T as ArrowDeserialize>::validate(v),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        <T as ArrowDeserialize>::validate_array(array)
+    }
+
     #[inline]
     fn arrow_deserialize_internal(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> <Self as ArrowField>::Type {
         <T as ArrowDeserialize>::arrow_deserialize(v)
     }
 }
 
+impl<T> ArrowDeserialize for Box<T>
+where
+    T: ArrowDeserialize,
+    T::ArrayType: 'static + ArrowArray,
+    T::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = <T as ArrowDeserialize>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type> {
+        <T as ArrowDeserialize>::arrow_deserialize(v).map(Box::new)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        <T as ArrowDeserialize>::is_deserializable_from(data_type)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        <T as ArrowDeserialize>::unknown_fields(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        <T as ArrowDeserialize>::validate(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        <T as ArrowDeserialize>::validate_array(array)
+    }
+
+    #[inline]
+    fn arrow_deserialize_internal(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> <Self as ArrowField>::Type {
+        Box::new(<T as ArrowDeserialize>::arrow_deserialize_internal(v))
+    }
+}
+
+impl<T> ArrowDeserialize for NestedOption<T>
+where
+    T: ArrowDeserialize + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    type ArrayType = TupleArray<(Option<T>,)>;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type> {
+        Some(Self::arrow_deserialize_internal(v))
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        <(Option<T>,) as ArrowDeserialize>::is_deserializable_from(data_type)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        <(Option<T>,) as ArrowDeserialize>::unknown_fields(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        match v {
+            Some(Some(v)) => <T as ArrowDeserialize>::validate(v),
+            _ => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        match array.as_struct_opt() {
+            Some(array) => <T as ArrowDeserialize>::validate_array(array.column(0).as_ref()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn arrow_deserialize_internal(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> <Self as ArrowField>::Type {
+        v.map(|(t,)| t)
+    }
+}
+
+impl<T, E> ArrowDeserialize for Result<T, E>
+where
+    T: ArrowDeserialize + 'static,
+    E: ArrowDeserialize + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <E as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    type ArrayType = UnionPairArray<T, E>;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type> {
+        v
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        is_union_pair_deserializable_from::<T, E>(data_type, datatypes::UnionMode::Dense)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        union_pair_unknown_fields::<T, E>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        match v {
+            Ok(v) => <T as ArrowDeserialize>::validate(v).map_err(|e| format!("{RESULT_OK_FIELD_NAME}: {e}")),
+            Err(v) => <E as ArrowDeserialize>::validate(v).map_err(|e| format!("{RESULT_ERR_FIELD_NAME}: {e}")),
+        }
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_union_pair_array::<T, E>(array, RESULT_OK_FIELD_NAME, RESULT_ERR_FIELD_NAME)
+    }
+}
+
+impl<T> ArrowDeserialize for std::ops::Range<T>
+where
+    T: ArrowDeserialize + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    // the struct fields are read positionally
+    type ArrayType = TupleArray<(T, T)>;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type> {
+        v.map(|(start, end)| start..end)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        <(T, T) as ArrowDeserialize>::is_deserializable_from(data_type)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        <(T, T) as ArrowDeserialize>::unknown_fields(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_bounds::<T>(&v.start, &v.end)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_column_array::<T>(array, 0, RANGE_START_FIELD_NAME)?;
+        validate_column_array::<T>(array, 1, RANGE_END_FIELD_NAME)
+    }
+}
+
+impl<T> ArrowDeserialize for std::ops::RangeInclusive<T>
+where
+    T: ArrowDeserialize + 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    // the struct fields are read positionally
+    type ArrayType = TupleArray<(T, T)>;
+
+    #[inline]
+    fn arrow_deserialize(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<<Self as ArrowField>::Type> {
+        v.map(|(start, end)| start..=end)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        <(T, T) as ArrowDeserialize>::is_deserializable_from(data_type)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        <(T, T) as ArrowDeserialize>::unknown_fields(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_bounds::<T>(v.start(), v.end())
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_column_array::<T>(array, 0, RANGE_START_FIELD_NAME)?;
+        validate_column_array::<T>(array, 1, RANGE_END_FIELD_NAME)
+    }
+}
+
+/// Validates the bounds of a range, prefixing the description of a violation with `start` or `end`.
+fn validate_bounds<T: ArrowDeserialize>(
+    start: &<T as ArrowField>::Type,
+    end: &<T as ArrowField>::Type,
+) -> Result<(), String> {
+    <T as ArrowDeserialize>::validate(start).map_err(|e| format!("{RANGE_START_FIELD_NAME}: {e}"))?;
+    <T as ArrowDeserialize>::validate(end).map_err(|e| format!("{RANGE_END_FIELD_NAME}: {e}"))
+}
+
+/// Checks the column at `idx` of a struct array with `T`, prefixing the description of a violation with `name`.
+pub(crate) fn validate_column_array<T: ArrowDeserialize>(
+    array: &dyn Array,
+    idx: usize,
+    name: &str,
+) -> Result<(), String> {
+    match array.as_struct_opt().and_then(|array| array.columns().get(idx)) {
+        Some(column) => <T as ArrowDeserialize>::validate_array(column.as_ref()).map_err(|e| format!("{name}: {e}")),
+        None => Ok(()),
+    }
+}
+
+/// Checks the children of a union array of two variants with `L` and `R`, prefixing the description of a violation
+/// with `left_name` or `right_name`.
+pub(crate) fn validate_union_pair_array<L, R>(
+    array: &dyn Array,
+    left_name: &str,
+    right_name: &str,
+) -> Result<(), String>
+where
+    L: ArrowDeserialize,
+    R: ArrowDeserialize,
+{
+    let Some(array) = array.as_union_opt() else {
+        return Ok(());
+    };
+    <L as ArrowDeserialize>::validate_array(array.child(0).as_ref()).map_err(|e| format!("{left_name}: {e}"))?;
+    <R as ArrowDeserialize>::validate_array(array.child(1).as_ref()).map_err(|e| format!("{right_name}: {e}"))
+}
+
+/// Returns whether a union type of `mode` with the type ids `0` and `1` can be deserialized to a pair of `L` and `R`.
+pub(crate) fn is_union_pair_deserializable_from<L, R>(
+    data_type: &datatypes::DataType,
+    mode: datatypes::UnionMode,
+) -> bool
+where
+    L: ArrowDeserialize,
+    R: ArrowDeserialize,
+{
+    // the variants are read by type id, so their names aren't checked
+    let datatypes::DataType::Union(fields, union_mode) = data_type else {
+        return false;
+    };
+    let fields: Vec<_> = fields.iter().collect();
+    match fields.as_slice() {
+        [(0, left), (1, right)] => {
+            *union_mode == mode && is_child_deserializable_from::<L>(left) && is_child_deserializable_from::<R>(right)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the unknown fields of the variants of a union type, prefixed by the name of the variant, such as `ok.extra`.
+pub(crate) fn union_pair_unknown_fields<L, R>(data_type: &datatypes::DataType) -> Vec<String>
+where
+    L: ArrowDeserialize,
+    R: ArrowDeserialize,
+{
+    let datatypes::DataType::Union(fields, _) = data_type else {
+        return vec![];
+    };
+    let fields: Vec<_> = fields.iter().collect();
+    match fields.as_slice() {
+        [(0, left), (1, right)] => {
+            let mut unknown = child_unknown_fields::<L>(left);
+            unknown.extend(child_unknown_fields::<R>(right));
+            unknown
+        }
+        _ => vec![],
+    }
+}
+
 impl_arrow_deserialize_primitive!(u8, datatypes::UInt8Type);
 impl_arrow_deserialize_primitive!(u16, datatypes::UInt16Type);
 impl_arrow_deserialize_primitive!(u32, datatypes::UInt32Type);
@@ -113,6 +474,39 @@ impl_arrow_deserialize_primitive!(half::f16, datatypes::Float16Type);
 impl_arrow_deserialize_primitive!(f32, datatypes::Float32Type);
 impl_arrow_deserialize_primitive!(f64, datatypes::Float64Type);
 
+// Macro to implement `ArrowDeserialize` for the `std::num` integer wrappers by delegating to the wrapped type.
+macro_rules! impl_numeric_wrapper {
+    ($wrapper:ident) => {
+        impl<T> ArrowDeserialize for std::num::$wrapper<T>
+        where
+            T: ArrowDeserialize,
+            <T as ArrowDeserialize>::ArrayType: ArrowArray,
+        {
+            type ArrayType = <T as ArrowDeserialize>::ArrayType;
+
+            #[inline]
+            fn arrow_deserialize(
+                v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>,
+            ) -> Option<<Self as ArrowField>::Type> {
+                <T as ArrowDeserialize>::arrow_deserialize(v).map(std::num::$wrapper)
+            }
+
+            #[inline]
+            fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+                <T as ArrowDeserialize>::validate(&v.0)
+            }
+
+            #[inline]
+            fn validate_array(array: &dyn Array) -> Result<(), String> {
+                <T as ArrowDeserialize>::validate_array(array)
+            }
+        }
+    };
+}
+
+impl_numeric_wrapper!(Wrapping);
+impl_numeric_wrapper!(Saturating);
+
 impl<const PRECISION: u8, const SCALE: i8> ArrowDeserialize for I128<PRECISION, SCALE> {
     type ArrayType = PrimitiveArray<Decimal128Type>;
 
@@ -133,6 +527,57 @@ impl ArrowDeserialize for String {
     }
 }
 
+impl ArrowDeserialize for std::ffi::OsString {
+    type ArrayType = BinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        v.map(|t| {
+            #[cfg(unix)]
+            {
+                <Self as std::os::unix::ffi::OsStringExt>::from_vec(t.to_vec())
+            }
+            // the encoding of non-unix platforms is unspecified, so only unicode can be read back
+            #[cfg(not(unix))]
+            {
+                std::str::from_utf8(t)
+                    .unwrap_or_else(|_| panic!("Expected valid unicode deserializing `std::ffi::OsString`"))
+                    .into()
+            }
+        })
+    }
+}
+
+impl ArrowDeserialize for OsStringUtf8 {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<std::ffi::OsString> {
+        v.map(|t| t.into())
+    }
+}
+
+impl ArrowDeserialize for OsStringLossy {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<std::ffi::OsString> {
+        v.map(|t| t.into())
+    }
+}
+
+impl ArrowDeserialize for std::ffi::CString {
+    type ArrayType = BinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        v.map(|t| {
+            Self::new(t)
+                .unwrap_or_else(|e| panic!("Expected no interior nul byte deserializing `std::ffi::CString`: {e}"))
+        })
+    }
+}
+
 impl ArrowDeserialize for LargeString {
     type ArrayType = LargeStringArray;
 
@@ -142,6 +587,15 @@ impl ArrowDeserialize for LargeString {
     }
 }
 
+impl ArrowDeserialize for Utf8View {
+    type ArrayType = StringViewArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<String> {
+        v.map(|t| t.to_string())
+    }
+}
+
 impl ArrowDeserialize for bool {
     type ArrayType = BooleanArray;
 
@@ -151,6 +605,86 @@ impl ArrowDeserialize for bool {
     }
 }
 
+impl ArrowDeserialize for () {
+    type ArrayType = NullArray;
+
+    // every slot of a `NullArray` is null, so it always deserializes to the unit value
+    #[inline]
+    fn arrow_deserialize(_v: Option<()>) -> Option<Self> {
+        Some(())
+    }
+}
+
+impl<T: ?Sized> ArrowDeserialize for std::marker::PhantomData<T> {
+    type ArrayType = NullArray;
+
+    #[inline]
+    fn arrow_deserialize(_v: Option<()>) -> Option<Self> {
+        Some(std::marker::PhantomData)
+    }
+}
+
+// Macro to implement [`ArrowDeserialize`] for the duration placeholder types.
+macro_rules! impl_duration_deserialize {
+    ($placeholder:ident, $unit:ident, $array:ty) => {
+        impl<T> ArrowDeserialize for $placeholder<T>
+        where
+            T: ArrowDurationValue,
+        {
+            type ArrayType = $array;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<i64>) -> Option<T> {
+                v.map(|v| {
+                    T::from_duration(v, datatypes::TimeUnit::$unit).unwrap_or_else(|| {
+                        panic!(
+                            "Expected a duration representable as `{}`, got {v}",
+                            std::any::type_name::<T>()
+                        )
+                    })
+                })
+            }
+        }
+
+        impl_arrow_array!($array);
+    };
+}
+
+impl_duration_deserialize!(DurationSeconds, Second, DurationSecondArray);
+impl_duration_deserialize!(DurationMilliseconds, Millisecond, DurationMillisecondArray);
+impl_duration_deserialize!(DurationMicroseconds, Microsecond, DurationMicrosecondArray);
+impl_duration_deserialize!(DurationNanoseconds, Nanosecond, DurationNanosecondArray);
+
+// Macro to implement [`ArrowDeserialize`] for the timestamp placeholder types.
+macro_rules! impl_timestamp_deserialize {
+    ($placeholder:ident, $unit:ident, $array:ty) => {
+        impl<T, Tz> ArrowDeserialize for $placeholder<T, Tz>
+        where
+            T: ArrowTimestampValue,
+            Tz: ArrowTimeZone,
+        {
+            type ArrayType = $array;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<i64>) -> Option<T> {
+                v.map(|v| {
+                    T::from_timestamp(v, datatypes::TimeUnit::$unit).unwrap_or_else(|| {
+                        panic!(
+                            "Expected a timestamp representable as `{}`, got {v}",
+                            std::any::type_name::<T>()
+                        )
+                    })
+                })
+            }
+        }
+    };
+}
+
+impl_timestamp_deserialize!(TimestampSeconds, Second, TimestampSecondArray);
+impl_timestamp_deserialize!(TimestampMilliseconds, Millisecond, TimestampMillisecondArray);
+impl_timestamp_deserialize!(TimestampMicroseconds, Microsecond, TimestampMicrosecondArray);
+impl_timestamp_deserialize!(TimestampNanoseconds, Nanosecond, TimestampNanosecondArray);
+
 impl ArrowDeserialize for NaiveDateTime {
     type ArrayType = TimestampNanosecondArray;
 
@@ -206,9 +740,58 @@ impl ArrowArray for BufferBinaryArray {
     }
 }
 
-// Treat both Buffer and ScalarBuffer<u8> the same
-impl ArrowDeserialize for Buffer {
-    type ArrayType = BufferBinaryArray;
+/// Iterator for for [`LargeBufferBinaryArray`]
+pub struct LargeBufferBinaryArrayIter<'a> {
+    index: usize,
+    array: &'a LargeBinaryArray,
+}
+
+impl Iterator for LargeBufferBinaryArrayIter<'_> {
+    type Item = Option<Buffer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        if self.array.is_valid(index) {
+            // slice the values buffer instead of copying the value
+            let offsets = self.array.value_offsets();
+            let start = offsets[index] as usize;
+            let end = offsets[index + 1] as usize;
+            Some(Some(self.array.values().slice_with_length(start, end - start)))
+        } else {
+            Some(None)
+        }
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over a `LargeBinaryArray` while exposing zero-copy Buffer slices
+pub struct LargeBufferBinaryArray;
+
+impl ArrowArray for LargeBufferBinaryArray {
+    type BaseArrayType = LargeBinaryArray;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+
+        LargeBufferBinaryArrayIter { index: 0, array: b }
+    }
+}
+
+impl ArrowDeserialize for LargeBuffer {
+    type ArrayType = LargeBufferBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<Buffer>) -> Option<Buffer> {
+        v
+    }
+}
+
+// Treat both Buffer and ScalarBuffer<u8> the same
+impl ArrowDeserialize for Buffer {
+    type ArrayType = BufferBinaryArray;
 
     #[inline]
     fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
@@ -242,6 +825,24 @@ impl ArrowDeserialize for LargeBinary {
     }
 }
 
+impl ArrowDeserialize for ByteList {
+    type ArrayType = ListArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<Vec<u8>> {
+        v.map(|t| t.as_primitive::<UInt8Type>().values().to_vec())
+    }
+}
+
+impl ArrowDeserialize for BinaryView {
+    type ArrayType = BinaryViewArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Vec<u8>> {
+        v.map(|t| t.to_vec())
+    }
+}
+
 impl<const SIZE: i32> ArrowDeserialize for FixedSizeBinary<SIZE> {
     type ArrayType = FixedSizeBinaryArray;
 
@@ -256,7 +857,154 @@ impl<const SIZE: usize> ArrowDeserialize for [u8; SIZE] {
 
     #[inline]
     fn arrow_deserialize(v: Option<&[u8]>) -> Option<[u8; SIZE]> {
-        v.map(|t| t.to_vec().try_into().unwrap())
+        v.map(|t| {
+            <[u8; SIZE]>::try_from(t).unwrap_or_else(|_| {
+                panic!(
+                    "Expected size of {} deserializing array of type `u8`, got {}",
+                    SIZE,
+                    t.len()
+                )
+            })
+        })
+    }
+}
+
+/// Validates the elements of a list, prefixing the description of a violation with the index of the element.
+pub(crate) fn validate_elements<'a, T>(
+    values: impl IntoIterator<Item = &'a <T as ArrowField>::Type>,
+) -> Result<(), String>
+where
+    T: ArrowDeserialize,
+    <T as ArrowField>::Type: 'a,
+{
+    values
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(idx, v)| <T as ArrowDeserialize>::validate(v).map_err(|e| format!("[{idx}]: {e}")))
+}
+
+/// Validates the keys and values of a map, prefixing the description of a violation with the index of the entry
+/// and `keys` or `values`.
+pub(crate) fn validate_entries<'a, K, V>(
+    entries: impl IntoIterator<Item = (&'a <K as ArrowField>::Type, &'a <V as ArrowField>::Type)>,
+) -> Result<(), String>
+where
+    K: ArrowDeserialize,
+    V: ArrowDeserialize,
+    <K as ArrowField>::Type: 'a,
+    <V as ArrowField>::Type: 'a,
+{
+    entries.into_iter().enumerate().try_for_each(|(idx, (k, v))| {
+        <K as ArrowDeserialize>::validate(k).map_err(|e| format!("[{idx}]: {MAP_KEYS_FIELD_NAME}: {e}"))?;
+        <V as ArrowDeserialize>::validate(v).map_err(|e| format!("[{idx}]: {MAP_VALUES_FIELD_NAME}: {e}"))
+    })
+}
+
+/// Checks the elements of a list array of any kind with `T`.
+pub(crate) fn validate_element_array<T: ArrowDeserialize>(array: &dyn Array) -> Result<(), String> {
+    use datatypes::DataType::*;
+    let values = match array.data_type() {
+        List(_) => offset_values(
+            array.as_list::<i32>().values(),
+            array.as_list::<i32>().value_offsets(),
+        ),
+        LargeList(_) => offset_values(
+            array.as_list::<i64>().values(),
+            array.as_list::<i64>().value_offsets(),
+        ),
+        // list views may reference the values in any order
+        ListView(_) => array.as_list_view::<i32>().values().clone(),
+        LargeListView(_) => array.as_list_view::<i64>().values().clone(),
+        // the values of fixed size lists are sliced along with the list
+        FixedSizeList(_, _) => array.as_fixed_size_list().values().clone(),
+        _ => return Ok(()),
+    };
+    <T as ArrowDeserialize>::validate_array(values.as_ref())
+}
+
+/// Checks the keys and values of a map array with `K` and `V`, prefixing the description of a violation with
+/// `keys` or `values`.
+pub(crate) fn validate_entry_arrays<K, V>(array: &dyn Array) -> Result<(), String>
+where
+    K: ArrowDeserialize,
+    V: ArrowDeserialize,
+{
+    let Some(array) = array.as_map_opt() else {
+        return Ok(());
+    };
+    let keys = offset_values(array.keys(), array.value_offsets());
+    let values = offset_values(array.values(), array.value_offsets());
+    <K as ArrowDeserialize>::validate_array(keys.as_ref()).map_err(|e| format!("{MAP_KEYS_FIELD_NAME}: {e}"))?;
+    <V as ArrowDeserialize>::validate_array(values.as_ref()).map_err(|e| format!("{MAP_VALUES_FIELD_NAME}: {e}"))
+}
+
+/// Returns the values referenced by the offsets of a list or map array, which may be sliced.
+fn offset_values<O: ArrowNativeType>(values: &ArrayRef, offsets: &[O]) -> ArrayRef {
+    let start = offsets.first().map_or(0, |o| o.as_usize());
+    let end = offsets.last().map_or(0, |o| o.as_usize());
+    values.slice(start, end - start)
+}
+
+/// Returns whether the values of `field`, a child of a list, map or tuple type, can be deserialized to `T`.
+pub(crate) fn is_child_deserializable_from<T: ArrowDeserialize>(field: &datatypes::Field) -> bool {
+    field.is_nullable() == <T as ArrowField>::is_nullable()
+        && <T as ArrowDeserialize>::is_deserializable_from(field.data_type())
+}
+
+/// Returns whether a map type can be deserialized to a map of `K` and `V` whose keys are sorted if `keys_sorted`.
+pub(crate) fn is_map_deserializable_from<K, V>(data_type: &datatypes::DataType, keys_sorted: bool) -> bool
+where
+    K: ArrowDeserialize,
+    V: ArrowDeserialize,
+{
+    let datatypes::DataType::Map(entries, sorted) = data_type else {
+        return false;
+    };
+    match entries.data_type() {
+        datatypes::DataType::Struct(fields) if fields.len() == 2 => {
+            *sorted == keys_sorted
+                && is_child_deserializable_from::<K>(&fields[0])
+                && is_child_deserializable_from::<V>(&fields[1])
+        }
+        _ => false,
+    }
+}
+
+/// Returns the unknown fields of the elements of a list type.
+pub(crate) fn element_unknown_fields<T: ArrowDeserialize>(data_type: &datatypes::DataType) -> Vec<String> {
+    use datatypes::DataType::*;
+    match data_type {
+        List(f) | LargeList(f) | ListView(f) | LargeListView(f) | FixedSizeList(f, _) => {
+            <T as ArrowDeserialize>::unknown_fields(f.data_type())
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the unknown fields of a child of a struct type, prefixed by the name of the child, such as `0.extra`.
+pub(crate) fn child_unknown_fields<T: ArrowDeserialize>(field: &datatypes::Field) -> Vec<String> {
+    <T as ArrowDeserialize>::unknown_fields(field.data_type())
+        .into_iter()
+        .map(|name| format!("{}.{}", field.name(), name))
+        .collect()
+}
+
+/// Returns the unknown fields of the keys and values of a map type, such as `values.extra`.
+pub(crate) fn map_unknown_fields<K, V>(data_type: &datatypes::DataType) -> Vec<String>
+where
+    K: ArrowDeserialize,
+    V: ArrowDeserialize,
+{
+    let datatypes::DataType::Map(entries, _) = data_type else {
+        return vec![];
+    };
+    match entries.data_type() {
+        datatypes::DataType::Struct(fields) if fields.len() == 2 => {
+            let mut unknown = child_unknown_fields::<K>(&fields[0]);
+            unknown.extend(child_unknown_fields::<V>(&fields[1]));
+            unknown
+        }
+        _ => vec![],
     }
 }
 
@@ -301,6 +1049,131 @@ where
     fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
         arrow_deserialize_vec_helper::<T>(v)
     }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::List(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+// Blanket implementation for VecDeque
+impl<T> ArrowDeserialize for VecDeque<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_vec_helper::<T>(v).map(VecDeque::from)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::List(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+// Blanket implementation for HashSet. Duplicate values are removed on deserialization.
+impl<T, S> ArrowDeserialize for HashSet<T, S>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    <T as ArrowField>::Type: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_vec_helper::<T>(v).map(|t| t.into_iter().collect())
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::List(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+// Blanket implementation for BTreeSet. Duplicate values are removed on deserialization.
+impl<T> ArrowDeserialize for BTreeSet<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    <T as ArrowField>::Type: Ord,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_vec_helper::<T>(v).map(|t| t.into_iter().collect())
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::List(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
 }
 
 impl<T> ArrowDeserialize for LargeVec<T>
@@ -314,6 +1187,92 @@ where
     fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
         arrow_deserialize_vec_helper::<T>(v)
     }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::LargeList(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+impl<T> ArrowDeserialize for ListView<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListViewArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_vec_helper::<T>(v)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::ListView(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+impl<T> ArrowDeserialize for LargeListView<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = LargeListViewArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_vec_helper::<T>(v)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::LargeListView(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
 }
 
 impl<T, const SIZE: i32> ArrowDeserialize for FixedSizeVec<T, SIZE>
@@ -327,7 +1286,69 @@ where
     fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
         arrow_deserialize_vec_helper::<T>(v)
     }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::FixedSizeList(f, size) if *size == SIZE && is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
 }
+impl<A> ArrowDeserialize for FixedShapeTensor<A>
+where
+    A: ArrowTensorShape + 'static,
+    <A as ArrowTensorShape>::Element: ArrowDeserialize + 'static,
+    <<A as ArrowTensorShape>::Element as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = FixedSizeListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        use std::ops::Deref;
+        let v = v?;
+        if v.len() != Self::num_elements() {
+            panic!(
+                "Expected {} elements deserializing tensor of shape {:?}, got {}",
+                Self::num_elements(),
+                <A as ArrowTensorShape>::shape(),
+                v.len()
+            );
+        }
+        let mut elements = arrow_array_deserialize_iterator_internal::<
+            <A as ArrowTensorShape>::Element,
+            <A as ArrowTensorShape>::Element,
+        >(v.deref());
+        <A as ArrowTensorShape>::from_elements(&mut elements)
+    }
+
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        let mut idx = 0;
+        v.try_for_each_element(&mut |element| {
+            let result = <<A as ArrowTensorShape>::Element as ArrowDeserialize>::validate(element)
+                .map_err(|e| format!("[{idx}]: {e}"));
+            idx += 1;
+            result
+        })
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<<A as ArrowTensorShape>::Element>(array)
+    }
+}
+
 impl<T, const SIZE: usize> ArrowDeserialize for [T; SIZE]
 where
     T: ArrowDeserialize + ArrowEnableVecForType + 'static,
@@ -343,30 +1364,157 @@ where
         match <[<T as ArrowField>::Type; SIZE]>::try_from(result).ok() {
             None => panic!(
                 "Expected size of {} deserializing array of type `{}`, got {}",
-                std::any::type_name::<T>(),
                 SIZE,
+                std::any::type_name::<T>(),
                 length
             ),
             array => array,
         }
     }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        matches!(data_type, datatypes::DataType::FixedSizeList(f, size) if *size as usize == SIZE && is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+pub(crate) fn arrow_deserialize_map_helper<K, V, C>(v: Option<StructArray>) -> Option<C>
+where
+    K: ArrowDeserialize + 'static,
+    V: ArrowDeserialize + 'static,
+    K::ArrayType: ArrowArrayIterable,
+    V::ArrayType: ArrowArrayIterable,
+    C: FromIterator<(<K as ArrowField>::Type, <V as ArrowField>::Type)>,
+{
+    use std::ops::Deref;
+    v.map(|t| {
+        let keys = arrow_array_deserialize_iterator_internal::<<K as ArrowField>::Type, K>(t.column(0).deref());
+        let values = arrow_array_deserialize_iterator_internal::<<V as ArrowField>::Type, V>(t.column(1).deref());
+        keys.zip(values).collect()
+    })
+}
+
+// Blanket implementation for HashMap
+impl<K, V, S> ArrowDeserialize for HashMap<K, V, S>
+where
+    K: ArrowDeserialize + 'static,
+    V: ArrowDeserialize + 'static,
+    K::ArrayType: ArrowArrayIterable,
+    V::ArrayType: ArrowArrayIterable,
+    <K as ArrowField>::Type: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type ArrayType = MapArray;
+
+    fn arrow_deserialize(v: Option<StructArray>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_map_helper::<K, V, _>(v)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        is_map_deserializable_from::<K, V>(data_type, false)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        map_unknown_fields::<K, V>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_entries::<K, V>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_entry_arrays::<K, V>(array)
+    }
+}
+
+// Blanket implementation for BTreeMap
+impl<K, V> ArrowDeserialize for BTreeMap<K, V>
+where
+    K: ArrowDeserialize + 'static,
+    V: ArrowDeserialize + 'static,
+    K::ArrayType: ArrowArrayIterable,
+    V::ArrayType: ArrowArrayIterable,
+    <K as ArrowField>::Type: Ord,
+{
+    type ArrayType = MapArray;
+
+    fn arrow_deserialize(v: Option<StructArray>) -> Option<<Self as ArrowField>::Type> {
+        let entries = arrow_deserialize_map_helper::<K, V, Vec<_>>(v)?;
+        if !entries.windows(2).all(|w| w[0].0 < w[1].0) {
+            panic!(
+                "Expected sorted unique keys deserializing map with keys of type `{}`",
+                std::any::type_name::<K>()
+            );
+        }
+        Some(entries.into_iter().collect())
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &datatypes::DataType) -> bool {
+        is_map_deserializable_from::<K, V>(data_type, true)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &datatypes::DataType) -> Vec<String> {
+        map_unknown_fields::<K, V>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_entries::<K, V>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_entry_arrays::<K, V>(array)
+    }
 }
 
 impl_arrow_array!(BooleanArray);
+impl_arrow_array!(NullArray);
 impl_arrow_array!(StringArray);
 impl_arrow_array!(LargeStringArray);
+impl_arrow_array!(StringViewArray);
 impl_arrow_array!(BinaryArray);
 impl_arrow_array!(LargeBinaryArray);
+impl_arrow_array!(BinaryViewArray);
 impl_arrow_array!(FixedSizeBinaryArray);
 impl_arrow_array!(ListArray);
 impl_arrow_array!(LargeListArray);
+impl_arrow_array!(ListViewArray);
+impl_arrow_array!(LargeListViewArray);
 impl_arrow_array!(FixedSizeListArray);
+impl_arrow_array!(MapArray);
 impl_arrow_array!(Date32Array);
 impl_arrow_array!(Date64Array);
 impl_arrow_array!(TimestampSecondArray);
 impl_arrow_array!(TimestampMillisecondArray);
 impl_arrow_array!(TimestampMicrosecondArray);
 impl_arrow_array!(TimestampNanosecondArray);
+impl_arrow_array!(Time32SecondArray);
+impl_arrow_array!(Time32MillisecondArray);
+impl_arrow_array!(Time64MicrosecondArray);
+impl_arrow_array!(Time64NanosecondArray);
+impl_arrow_array!(IntervalMonthDayNanoArray);
 
 /// Top-level API to deserialize from Arrow
 pub trait TryIntoCollection<Collection, Element>
@@ -396,16 +1544,19 @@ where
         .map(<Field as ArrowDeserialize>::arrow_deserialize_internal)
 }
 
-/// Returns a typed iterator to a target type from an `arrow::Array`
-pub fn arrow_array_deserialize_iterator_as_type<Element, ArrowType>(
-    arr: &dyn Array,
-) -> arrow::error::Result<impl Iterator<Item = Element> + '_>
+/// Returns an error if `arr` has unknown fields, a data type or values that can't be deserialized to `ArrowType`.
+fn check_deserializable<ArrowType>(arr: &dyn Array) -> arrow::error::Result<()>
 where
-    Element: 'static,
-    ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
-    <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    ArrowType: ArrowDeserialize + 'static,
 {
-    if &<ArrowType as ArrowField>::data_type() != arr.data_type() {
+    let unknown_fields = <ArrowType as ArrowDeserialize>::unknown_fields(arr.data_type());
+    if !unknown_fields.is_empty() {
+        Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+            "Unknown fields deserializing {}: {}",
+            std::any::type_name::<ArrowType>(),
+            unknown_fields.join(", ")
+        )))
+    } else if !<ArrowType as ArrowDeserialize>::is_deserializable_from(arr.data_type()) {
         Err(arrow::error::ArrowError::InvalidArgumentError(format!(
             "Data type mismatch. Expected type={:#?} is_nullable={}, but was type={:#?} is_nullable={}",
             &<ArrowType as ArrowField>::data_type(),
@@ -414,12 +1565,31 @@ where
             arr.is_nullable()
         )))
     } else {
-        Ok(arrow_array_deserialize_iterator_internal::<Element, ArrowType>(
-            arr,
-        ))
+        <ArrowType as ArrowDeserialize>::validate_array(arr).map_err(|e| {
+            arrow::error::ArrowError::InvalidArgumentError(format!(
+                "Invalid values deserializing {}: {}",
+                std::any::type_name::<ArrowType>(),
+                e
+            ))
+        })
     }
 }
 
+/// Returns a typed iterator to a target type from an `arrow::Array`
+pub fn arrow_array_deserialize_iterator_as_type<Element, ArrowType>(
+    arr: &dyn Array,
+) -> arrow::error::Result<impl Iterator<Item = Element> + '_>
+where
+    Element: 'static,
+    ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+    <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    check_deserializable::<ArrowType>(arr)?;
+    Ok(arrow_array_deserialize_iterator_internal::<Element, ArrowType>(
+        arr,
+    ))
+}
+
 /// Return an iterator that deserializes an [`Array`] to an element of type T
 pub fn arrow_array_deserialize_iterator<T>(arr: &dyn Array) -> arrow::error::Result<impl Iterator<Item = T> + '_>
 where
@@ -440,7 +1610,64 @@ where
         Element: ArrowDeserialize + ArrowField<Type = Element> + 'static,
         <Element as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
     {
-        Ok(arrow_array_deserialize_iterator::<Element>(self.borrow())?.collect())
+        self.try_into_collection_as_type::<Element>()
+    }
+
+    fn try_into_collection_as_type<ArrowType>(self) -> arrow::error::Result<Collection>
+    where
+        ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+        <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    {
+        arrow_array_deserialize_iterator_as_type::<Element, ArrowType>(self.borrow())?
+            .enumerate()
+            .map(|(row, v)| {
+                <ArrowType as ArrowDeserialize>::validate(&v).map_err(|e| {
+                    arrow::error::ArrowError::InvalidArgumentError(format!(
+                        "Invalid row {} deserializing {}: {}",
+                        row,
+                        std::any::type_name::<ArrowType>(),
+                        e
+                    ))
+                })?;
+                Ok(v)
+            })
+            .collect()
+    }
+}
+
+/// Top-level API to deserialize the rows of a `RecordBatch`, whose columns are matched to the fields of the struct
+/// `Element` by name, like a `StructArray` of the columns.
+///
+/// This is implemented by `RecordBatch` and `&RecordBatch`, which can't implement [`TryIntoCollection`].
+pub trait RecordBatchTryIntoCollection<Collection, Element>
+where
+    Element: 'static,
+    Collection: FromIterator<Element>,
+{
+    /// Convert the rows of a `RecordBatch` to any collection that implements the `FromIterator` trait
+    fn try_into_collection(self) -> arrow::error::Result<Collection>
+    where
+        Element: ArrowDeserialize + ArrowField<Type = Element> + 'static;
+
+    /// Same as `try_into_collection` except can coerce the conversion to a specific Arrow type, such as a struct
+    /// whose `Type` is a remote type.
+    fn try_into_collection_as_type<ArrowType>(self) -> arrow::error::Result<Collection>
+    where
+        ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static;
+}
+
+impl<Collection, Element, Batch> RecordBatchTryIntoCollection<Collection, Element> for Batch
+where
+    Element: 'static,
+    Batch: std::borrow::Borrow<RecordBatch>,
+    Collection: FromIterator<Element>,
+{
+    fn try_into_collection(self) -> arrow::error::Result<Collection>
+    where
+        Element: ArrowDeserialize + ArrowField<Type = Element> + 'static,
+        <Element as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    {
+        self.try_into_collection_as_type::<Element>()
     }
 
     fn try_into_collection_as_type<ArrowType>(self) -> arrow::error::Result<Collection>
@@ -448,6 +1675,7 @@ where
         ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
         <ArrowType as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
     {
-        Ok(arrow_array_deserialize_iterator_as_type::<Element, ArrowType>(self.borrow())?.collect())
+        let array = StructArray::from(self.borrow().clone());
+        TryIntoCollection::try_into_collection_as_type::<ArrowType>(&array as &dyn Array)
     }
 }
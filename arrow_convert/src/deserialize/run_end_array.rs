@@ -0,0 +1,116 @@
+use std::marker::PhantomData;
+
+use arrow::array::{Array, RunArray};
+use arrow::datatypes::{ArrowNativeType, RunEndIndexType};
+
+use super::{ArrowArray, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::{ArrowField, ArrowRunEndIndex, RunEnd};
+
+/// Internal `ArrowArray` helper to iterate over a `RunArray` as its expanded values.
+///
+/// `R` is the Arrow run end type and `V` the value type being deserialized.
+pub struct DecodedRunArray<R, V>(PhantomData<(R, V)>);
+
+/// Iterator for for [`DecodedRunArray`]
+pub struct DecodedRunArrayIter<'a, R: RunEndIndexType, V: ArrowField> {
+    run_ends: &'a [R::Native],
+    index: usize,
+    end: usize,
+    physical_index: usize,
+    values: Vec<Option<<V as ArrowField>::Type>>,
+}
+
+impl<R, V> Iterator for DecodedRunArrayIter<'_, R, V>
+where
+    R: RunEndIndexType,
+    V: ArrowField,
+    <V as ArrowField>::Type: Clone,
+{
+    type Item = Option<<V as ArrowField>::Type>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        while self.run_ends[self.physical_index].as_usize() <= self.index {
+            self.physical_index += 1;
+        }
+        self.index += 1;
+        Some(self.values[self.physical_index].clone())
+    }
+}
+
+impl<R, V> ArrowArrayIterable for DecodedRunArray<R, V>
+where
+    R: RunEndIndexType,
+    V: ArrowField + 'static,
+    <V as ArrowField>::Type: Clone,
+{
+    type Item<'a> = Option<<V as ArrowField>::Type>;
+
+    type Iter<'a> = DecodedRunArrayIter<'a, R, V>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl<R, V> ArrowArray for DecodedRunArray<R, V>
+where
+    R: RunEndIndexType,
+    V: ArrowDeserialize + 'static,
+    <V as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <V as ArrowField>::Type: Clone,
+{
+    type BaseArrayType = RunArray<R>;
+
+    #[inline]
+    fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let arr = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        // the run values are decoded once and cloned for each logical index of the run
+        let values = <<V as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(arr.values().as_ref())
+            .map(<V as ArrowDeserialize>::arrow_deserialize)
+            .collect();
+        let run_ends = arr.run_ends();
+        DecodedRunArrayIter {
+            run_ends: run_ends.values(),
+            index: run_ends.offset(),
+            end: run_ends.offset() + run_ends.len(),
+            physical_index: arr.get_start_physical_index(),
+            values,
+        }
+    }
+}
+
+impl<R, V> ArrowDeserialize for RunEnd<R, V>
+where
+    R: ArrowRunEndIndex,
+    V: ArrowDeserialize + 'static,
+    <V as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <V as ArrowField>::Type: Clone,
+{
+    type ArrayType = DecodedRunArray<<R as ArrowRunEndIndex>::RunEndType, V>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<<V as ArrowField>::Type>) -> Option<<V as ArrowField>::Type> {
+        v
+    }
+
+    #[inline]
+    fn validate(v: &<V as ArrowField>::Type) -> Result<(), String> {
+        <V as ArrowDeserialize>::validate(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        // all the run values are decoded, including the ones outside of a slice
+        match array
+            .as_any()
+            .downcast_ref::<RunArray<<R as ArrowRunEndIndex>::RunEndType>>()
+        {
+            Some(array) => <V as ArrowDeserialize>::validate_array(array.values().as_ref()),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use arrow::array::{Array, StructArray};
+use arrow::datatypes::DataType;
+use arrow::util::bit_iterator::BitIterator;
+
+use super::{
+    child_unknown_fields, is_child_deserializable_from, validate_column_array, ArrowArray, ArrowArrayIterable,
+    ArrowDeserialize,
+};
+use crate::field::ArrowField;
+
+/// Internal `ArrowArray` helper to iterate over a `StructArray` as rust tuples.
+///
+/// `T` is the tuple type being deserialized.
+pub struct TupleArray<T>(PhantomData<T>);
+
+/// Iterator for for [`TupleArray`]
+///
+/// `I` is a tuple of the iterators of each struct field.
+pub struct TupleArrayIter<'a, T, I> {
+    iters: I,
+    validity_iter: Option<BitIterator<'a>>,
+    _tuple: PhantomData<T>,
+}
+
+// Macro to implement the array and deserialization traits for each tuple arity.
+macro_rules! impl_tuple_array {
+    ($($name:ident $index:tt),+) => {
+        impl<'a, $($name),+> TupleArrayIter<'a, ($($name,)+), ($(<<$name as ArrowDeserialize>::ArrayType as ArrowArrayIterable>::Iter<'a>,)+)>
+        where
+            $(
+                $name: ArrowDeserialize + 'static,
+                <$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+            )+
+        {
+            #[inline]
+            fn return_next(&mut self) -> Option<($(<$name as ArrowField>::Type,)+)> {
+                Some(($(<$name as ArrowDeserialize>::arrow_deserialize_internal(self.iters.$index.next()?),)+))
+            }
+
+            #[inline]
+            fn consume_next(&mut self) {
+                $(let _ = self.iters.$index.next();)+
+            }
+        }
+
+        impl<'a, $($name),+> Iterator for TupleArrayIter<'a, ($($name,)+), ($(<<$name as ArrowDeserialize>::ArrayType as ArrowArrayIterable>::Iter<'a>,)+)>
+        where
+            $(
+                $name: ArrowDeserialize + 'static,
+                <$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+            )+
+        {
+            type Item = Option<($(<$name as ArrowField>::Type,)+)>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                match self.validity_iter.as_mut() {
+                    None => self.return_next().map(Some),
+                    Some(validity_iter) => {
+                        let is_valid = validity_iter.next()?;
+                        if is_valid {
+                            self.return_next().map(Some)
+                        } else {
+                            self.consume_next();
+                            Some(None)
+                        }
+                    }
+                }
+            }
+        }
+
+        impl<$($name),+> ArrowArrayIterable for TupleArray<($($name,)+)>
+        where
+            $(
+                $name: ArrowDeserialize + 'static,
+                <$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+            )+
+        {
+            type Item<'a> = Option<($(<$name as ArrowField>::Type,)+)>;
+
+            type Iter<'a> = TupleArrayIter<'a, ($($name,)+), ($(<<$name as ArrowDeserialize>::ArrayType as ArrowArrayIterable>::Iter<'a>,)+)>;
+
+            fn iter(&self) -> Self::Iter<'_> {
+                unimplemented!("Use iter_from_array_ref");
+            }
+        }
+
+        impl<$($name),+> ArrowArray for TupleArray<($($name,)+)>
+        where
+            $(
+                $name: ArrowDeserialize + 'static,
+                <$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+            )+
+        {
+            type BaseArrayType = StructArray;
+
+            #[inline]
+            fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+                let arr = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+                let values = arr.columns();
+                TupleArrayIter {
+                    iters: ($(
+                        <<$name as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(values[$index].deref()),
+                    )+),
+                    validity_iter: arr.nulls().map(|x| x.iter()),
+                    _tuple: PhantomData,
+                }
+            }
+        }
+
+        impl<$($name),+> ArrowDeserialize for ($($name,)+)
+        where
+            $(
+                $name: ArrowDeserialize + 'static,
+                <$name as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+            )+
+        {
+            type ArrayType = TupleArray<($($name,)+)>;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<<Self as ArrowField>::Type>) -> Option<<Self as ArrowField>::Type> {
+                v
+            }
+
+            fn is_deserializable_from(data_type: &DataType) -> bool {
+                // the fields are read positionally, so their names aren't checked
+                let DataType::Struct(fields) = data_type else {
+                    return false;
+                };
+                fields.len() == [$($index),+].len() $(&& is_child_deserializable_from::<$name>(&fields[$index]))+
+            }
+
+            fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+                $(
+                    <$name as ArrowDeserialize>::validate(&v.$index)
+                        .map_err(|e| format!("{}: {}", stringify!($index), e))?;
+                )+
+                Ok(())
+            }
+
+            fn validate_array(array: &dyn Array) -> Result<(), String> {
+                $(
+                    validate_column_array::<$name>(array, $index, stringify!($index))?;
+                )+
+                Ok(())
+            }
+
+            fn unknown_fields(data_type: &DataType) -> Vec<String> {
+                let DataType::Struct(fields) = data_type else {
+                    return vec![];
+                };
+                let mut unknown = vec![];
+                $(
+                    if let Some(f) = fields.get($index) {
+                        unknown.extend(child_unknown_fields::<$name>(f));
+                    }
+                )+
+                unknown
+            }
+        }
+    };
+}
+
+impl_tuple_array!(A 0);
+impl_tuple_array!(A 0, B 1);
+impl_tuple_array!(A 0, B 1, C 2);
+impl_tuple_array!(A 0, B 1, C 2, D 3);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_tuple_array!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
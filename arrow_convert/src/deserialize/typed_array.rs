@@ -0,0 +1,105 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use arrow::array::{Array, ArrayBuilder, ArrayRef};
+
+use super::{arrow_array_deserialize_iterator_internal, check_deserializable, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::ArrowField;
+use crate::serialize::{arrow_serialize_extend, ArrowSerialize};
+
+/// An `ArrayRef` holding values of the Arrow type `T`, with typed accessors deserializing its values.
+///
+/// A `TypedArray` is collected from an iterator of values or references to values, such as
+/// `rows.iter().collect::<TypedArray<Foo>>()`, or checked with [`TypedArray::try_new`]. The untyped array is returned
+/// by [`TypedArray::as_array`] and [`TypedArray::into_inner`].
+pub struct TypedArray<T>(ArrayRef, PhantomData<fn() -> T>);
+
+impl<T> TypedArray<T> {
+    /// The untyped array
+    pub fn as_array(&self) -> &ArrayRef {
+        &self.0
+    }
+
+    /// Consumes the `TypedArray`, returning the untyped array
+    pub fn into_inner(self) -> ArrayRef {
+        self.0
+    }
+
+    /// The number of values
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the array has no values
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> TypedArray<T>
+where
+    T: ArrowDeserialize + 'static,
+    <T as ArrowField>::Type: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    /// Wraps `array`, returning an error if it can't be deserialized to `T`
+    pub fn try_new(array: ArrayRef) -> arrow::error::Result<Self> {
+        check_deserializable::<T>(array.as_ref())?;
+        Ok(Self(array, PhantomData))
+    }
+
+    /// Returns an iterator deserializing the values
+    pub fn iter(&self) -> impl Iterator<Item = <T as ArrowField>::Type> + '_ {
+        arrow_array_deserialize_iterator_internal::<<T as ArrowField>::Type, T>(self.0.as_ref())
+    }
+
+    /// Deserializes the value at `index`, or returns `None` if `index` is out of bounds
+    pub fn get(&self, index: usize) -> Option<<T as ArrowField>::Type> {
+        if index >= self.0.len() {
+            return None;
+        }
+        let value = self.0.slice(index, 1);
+        let mut iter = arrow_array_deserialize_iterator_internal::<<T as ArrowField>::Type, T>(value.as_ref());
+        iter.next()
+    }
+}
+
+/// Serializes the values of an iterator of values or references to values.
+///
+/// # Panics
+///
+/// Panics if a value fails to serialize. Use [`crate::serialize::TryIntoArrow`] to handle the error instead.
+impl<T, R> FromIterator<R> for TypedArray<T>
+where
+    T: ArrowSerialize,
+    R: Borrow<<T as ArrowField>::Type>,
+{
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut builder = <T as ArrowSerialize>::new_array();
+        for value in iter {
+            if let Err(e) = arrow_serialize_extend::<_, T, _>(std::iter::once(value.borrow()), &mut builder) {
+                panic!("{e}");
+            }
+        }
+        Self(ArrayBuilder::finish(&mut builder), PhantomData)
+    }
+}
+
+impl<T> From<TypedArray<T>> for ArrayRef {
+    fn from(array: TypedArray<T>) -> Self {
+        array.0
+    }
+}
+
+impl<T> Clone for TypedArray<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T> fmt::Debug for TypedArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedArray").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,89 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use arrow::array::{Array, UnionArray};
+
+use super::{ArrowArray, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::ArrowField;
+
+/// Internal `ArrowArray` helper to iterate over a `UnionArray` with exactly two variants, with the type
+/// ids `0` and `1`.
+///
+/// Values of the first variant are yielded as `Ok` and values of the second variant as `Err`.
+pub struct UnionPairArray<L, R>(PhantomData<(L, R)>);
+
+/// Iterator for for [`UnionPairArray`]
+pub struct UnionPairArrayIter<'a, L, R> {
+    arr: &'a UnionArray,
+    index_iter: std::ops::Range<usize>,
+    _variants: PhantomData<(L, R)>,
+}
+
+impl<L, R> Iterator for UnionPairArrayIter<'_, L, R>
+where
+    L: ArrowDeserialize + 'static,
+    R: ArrowDeserialize + 'static,
+    <L as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <R as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    type Item = Option<Result<<L as ArrowField>::Type, <R as ArrowField>::Type>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index_iter.next()?;
+        let type_id = self.arr.type_id(index);
+        let offset = self.arr.value_offset(index);
+        let slice = self.arr.child(type_id).slice(offset, 1);
+        match type_id {
+            0 => {
+                let v = <<L as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(slice.deref())
+                    .next()
+                    .unwrap_or_else(|| panic!("Invalid offset for type id {type_id}"));
+                Some(<L as ArrowDeserialize>::arrow_deserialize(v).map(Ok))
+            }
+            1 => {
+                let v = <<R as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(slice.deref())
+                    .next()
+                    .unwrap_or_else(|| panic!("Invalid offset for type id {type_id}"));
+                Some(<R as ArrowDeserialize>::arrow_deserialize(v).map(Err))
+            }
+            _ => panic!("Invalid type id {type_id} for a union with two variants"),
+        }
+    }
+}
+
+impl<L, R> ArrowArrayIterable for UnionPairArray<L, R>
+where
+    L: ArrowDeserialize + 'static,
+    R: ArrowDeserialize + 'static,
+    <L as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <R as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    type Item<'a> = Option<Result<<L as ArrowField>::Type, <R as ArrowField>::Type>>;
+
+    type Iter<'a> = UnionPairArrayIter<'a, L, R>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl<L, R> ArrowArray for UnionPairArray<L, R>
+where
+    L: ArrowDeserialize + 'static,
+    R: ArrowDeserialize + 'static,
+    <L as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+    <R as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+{
+    type BaseArrayType = UnionArray;
+
+    #[inline]
+    fn iter_from_array_ref(b: &dyn Array) -> <Self as ArrowArrayIterable>::Iter<'_> {
+        let arr = b.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+        UnionPairArrayIter {
+            arr,
+            index_iter: 0..arr.len(),
+            _variants: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use arrow::array::Array;
+
+use super::{ArrowArray, ArrowArrayIterable, ArrowDeserialize};
+use crate::field::*;
+
+/// Implemented by [`ArrowDeserialize`] types that can be read without copying, as values borrowing the array.
+///
+/// Strings are viewed as `&str`, binaries as `&[u8]` and primitives as copies. The derive macro implements it for
+/// structs with the `view` attribute, viewing their rows as the generated view struct.
+pub trait ArrowView: ArrowDeserialize
+where
+    Self::ArrayType: ArrowArray,
+{
+    /// The borrowed value
+    type View<'a>;
+
+    /// The iterator over the views of an array, yielding `None` for null slots
+    type ViewIter<'a>: Iterator<Item = Option<Self::View<'a>>>;
+
+    /// Returns an iterator over the views of an array.
+    fn iter_views(b: &dyn Array) -> Self::ViewIter<'_>;
+}
+
+// the arrays of these types already yield borrowed values
+macro_rules! impl_arrow_view {
+    ($t:ty, $view:ty) => {
+        impl ArrowView for $t {
+            type View<'a> = $view;
+            type ViewIter<'a> = <<Self as ArrowDeserialize>::ArrayType as ArrowArrayIterable>::Iter<'a>;
+
+            #[inline]
+            fn iter_views(b: &dyn Array) -> Self::ViewIter<'_> {
+                <<Self as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(b)
+            }
+        }
+    };
+}
+
+impl_arrow_view!(u8, u8);
+impl_arrow_view!(u16, u16);
+impl_arrow_view!(u32, u32);
+impl_arrow_view!(u64, u64);
+impl_arrow_view!(i8, i8);
+impl_arrow_view!(i16, i16);
+impl_arrow_view!(i32, i32);
+impl_arrow_view!(i64, i64);
+impl_arrow_view!(half::f16, half::f16);
+impl_arrow_view!(f32, f32);
+impl_arrow_view!(f64, f64);
+impl_arrow_view!(bool, bool);
+impl_arrow_view!(String, &'a str);
+impl_arrow_view!(LargeString, &'a str);
+impl_arrow_view!(Utf8View, &'a str);
+impl_arrow_view!(Vec<u8>, &'a [u8]);
+impl_arrow_view!(LargeBinary, &'a [u8]);
+impl_arrow_view!(BinaryView, &'a [u8]);
+
+impl<T> ArrowView for Option<T>
+where
+    T: ArrowView,
+    T::ArrayType: 'static + ArrowArray,
+    T::ArrayType: ArrowArrayIterable,
+{
+    type View<'a> = Option<T::View<'a>>;
+    type ViewIter<'a> = std::iter::Map<T::ViewIter<'a>, fn(Option<T::View<'a>>) -> Option<Option<T::View<'a>>>>;
+
+    #[inline]
+    fn iter_views(b: &dyn Array) -> Self::ViewIter<'_> {
+        T::iter_views(b).map(Some)
+    }
+}
+
+/// Returns an iterator over the views of an [`Array`], after checking that it can be deserialized to `T`.
+///
+/// Like [`super::arrow_array_deserialize_iterator`], null slots are only supported when `T` is an `Option`.
+pub fn arrow_array_view_iterator<T>(arr: &dyn Array) -> arrow::error::Result<impl Iterator<Item = T::View<'_>> + '_>
+where
+    T: ArrowView + 'static,
+{
+    super::check_deserializable::<T>(arr)?;
+    Ok(T::iter_views(arr).map(|v| v.unwrap()))
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use arrayvec::{ArrayString, ArrayVec};
+use arrow::array::{Array, ArrayRef, AsArray, ListArray, ListBuilder, StringArray, StringBuilder};
+use arrow::datatypes::DataType;
+
+use crate::deserialize::{
+    arrow_deserialize_vec_helper, element_unknown_fields, is_child_deserializable_from, validate_element_array,
+    validate_elements, ArrowArrayIterable, ArrowDeserialize,
+};
+use crate::field::{ArrowEnableVecForType, ArrowField, DEFAULT_FIELD_NAME};
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+impl<T, const CAP: usize> ArrowField for ArrayVec<T, CAP>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = ArrayVec<<T as ArrowField>::Type, CAP>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
+impl<T, const CAP: usize> ArrowEnableVecForType for ArrayVec<T, CAP> where T: ArrowField + ArrowEnableVecForType {}
+
+impl<T, const CAP: usize> ArrowSerialize for ArrayVec<T, CAP>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T, const CAP: usize> ArrowDeserialize for ArrayVec<T, CAP>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        let result = arrow_deserialize_vec_helper::<T>(v)?;
+        let length = result.len();
+        if length > CAP {
+            panic!(
+                "Expected at most {} elements deserializing array of type `{}`, got {}",
+                CAP,
+                std::any::type_name::<T>(),
+                length
+            );
+        }
+        Some(result.into_iter().collect())
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &DataType) -> bool {
+        matches!(data_type, DataType::List(f) if is_child_deserializable_from::<T>(f))
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &DataType) -> Vec<String> {
+        element_unknown_fields::<T>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        if let Some(array) = array.as_list_opt::<i32>() {
+            let lengths = (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .map(|i| array.value_length(i) as usize);
+            if let Some(length) = lengths.into_iter().find(|length| *length > CAP) {
+                return Err(format!(
+                    "Expected at most {} elements deserializing array of type `{}`, got {}",
+                    CAP,
+                    std::any::type_name::<T>(),
+                    length
+                ));
+            }
+        }
+        validate_element_array::<T>(array)
+    }
+}
+
+impl<const CAP: usize> ArrowField for ArrayString<CAP> {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+impl<const CAP: usize> ArrowEnableVecForType for ArrayString<CAP> {}
+
+impl<const CAP: usize> ArrowSerialize for ArrayString<CAP> {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_str().len())?;
+        array.append_value(v.as_str());
+        Ok(())
+    }
+}
+
+impl<const CAP: usize> ArrowDeserialize for ArrayString<CAP> {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(|t| {
+            ArrayString::from(t).unwrap_or_else(|_| {
+                panic!(
+                    "Expected at most {} bytes deserializing `ArrayString<{}>`, got {}",
+                    CAP,
+                    CAP,
+                    t.len()
+                )
+            })
+        })
+    }
+
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        let Some(array) = array.as_string_opt::<i32>() else {
+            return Ok(());
+        };
+        match array.iter().flatten().find(|t| t.len() > CAP) {
+            Some(t) => Err(format!(
+                "Expected at most {} bytes deserializing `ArrayString<{}>`, got {}",
+                CAP,
+                CAP,
+                t.len()
+            )),
+            None => Ok(()),
+        }
+    }
+}
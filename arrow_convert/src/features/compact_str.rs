@@ -0,0 +1,44 @@
+use arrow::array::{StringArray, StringBuilder};
+use arrow::datatypes::DataType;
+use compact_str::CompactString;
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::ArrowField;
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+impl ArrowField for CompactString {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+arrow_enable_vec_for_type!(CompactString);
+
+impl ArrowSerialize for CompactString {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_str().len())?;
+        array.append_value(v.as_str());
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for CompactString {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(CompactString::from)
+    }
+}
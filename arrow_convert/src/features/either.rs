@@ -0,0 +1,143 @@
+use arrow::array::Array;
+use arrow::datatypes::{DataType, UnionFields, UnionMode};
+use either::Either;
+
+use crate::deserialize::{
+    is_union_pair_deserializable_from, union_pair_unknown_fields, validate_union_pair_array, ArrowArray,
+    ArrowDeserialize, UnionPairArray,
+};
+use crate::field::{ArrowEnableVecForType, ArrowField, SparseEither};
+use crate::serialize::{ArrowSerialize, PushNull, UnionPairBuilder};
+
+const LEFT_FIELD_NAME: &str = "left";
+const RIGHT_FIELD_NAME: &str = "right";
+
+fn either_data_type<L: ArrowField, R: ArrowField>(mode: UnionMode) -> DataType {
+    DataType::Union(
+        UnionFields::new(
+            0..2,
+            vec![
+                <L as ArrowField>::field(LEFT_FIELD_NAME),
+                <R as ArrowField>::field(RIGHT_FIELD_NAME),
+            ],
+        ),
+        mode,
+    )
+}
+
+// Macro to implement the traits for `Either` and its sparse placeholder.
+macro_rules! impl_either {
+    ($type:ident, $mode:expr) => {
+        impl<L, R> ArrowField for $type<L, R>
+        where
+            L: ArrowField,
+            R: ArrowField,
+        {
+            type Type = Either<<L as ArrowField>::Type, <R as ArrowField>::Type>;
+
+            #[inline]
+            fn data_type() -> DataType {
+                either_data_type::<L, R>($mode)
+            }
+        }
+
+        impl<L, R> ArrowEnableVecForType for $type<L, R>
+        where
+            L: ArrowField,
+            R: ArrowField,
+        {
+        }
+
+        impl<L, R> ArrowSerialize for $type<L, R>
+        where
+            L: ArrowSerialize,
+            R: ArrowSerialize,
+            <L as ArrowSerialize>::ArrayBuilderType: PushNull,
+            <R as ArrowSerialize>::ArrayBuilderType: PushNull,
+        {
+            type ArrayBuilderType =
+                UnionPairBuilder<<L as ArrowSerialize>::ArrayBuilderType, <R as ArrowSerialize>::ArrayBuilderType>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                let DataType::Union(fields, mode) = <Self as ArrowField>::data_type() else {
+                    panic!("datatype is not a union")
+                };
+                UnionPairBuilder::new(
+                    fields,
+                    mode,
+                    <L as ArrowSerialize>::new_array(),
+                    <R as ArrowSerialize>::new_array(),
+                )
+            }
+
+            #[inline]
+            fn arrow_serialize(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::ArrayBuilderType,
+            ) -> arrow::error::Result<()> {
+                match v {
+                    Either::Left(l) => {
+                        <L as ArrowSerialize>::arrow_serialize(l, array.left())?;
+                        array.append_left();
+                    }
+                    Either::Right(r) => {
+                        <R as ArrowSerialize>::arrow_serialize(r, array.right())?;
+                        array.append_right();
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl<L, R> ArrowDeserialize for $type<L, R>
+        where
+            L: ArrowDeserialize + 'static,
+            R: ArrowDeserialize + 'static,
+            <L as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+            <R as ArrowDeserialize>::ArrayType: ArrowArray + 'static,
+        {
+            type ArrayType = UnionPairArray<L, R>;
+
+            #[inline]
+            fn arrow_deserialize(
+                v: Option<Result<<L as ArrowField>::Type, <R as ArrowField>::Type>>,
+            ) -> Option<<Self as ArrowField>::Type> {
+                v.map(|t| match t {
+                    Ok(l) => Either::Left(l),
+                    Err(r) => Either::Right(r),
+                })
+            }
+
+            #[inline]
+            fn is_deserializable_from(data_type: &DataType) -> bool {
+                is_union_pair_deserializable_from::<L, R>(data_type, $mode)
+            }
+
+            #[inline]
+            fn unknown_fields(data_type: &DataType) -> Vec<String> {
+                union_pair_unknown_fields::<L, R>(data_type)
+            }
+
+            #[inline]
+            fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+                match v {
+                    Either::Left(l) => {
+                        <L as ArrowDeserialize>::validate(l).map_err(|e| format!("{LEFT_FIELD_NAME}: {e}"))
+                    }
+                    Either::Right(r) => {
+                        <R as ArrowDeserialize>::validate(r).map_err(|e| format!("{RIGHT_FIELD_NAME}: {e}"))
+                    }
+                }
+            }
+
+            #[inline]
+            fn validate_array(array: &dyn Array) -> Result<(), String> {
+                validate_union_pair_array::<L, R>(array, LEFT_FIELD_NAME, RIGHT_FIELD_NAME)
+            }
+        }
+    };
+}
+
+impl_either!(Either, UnionMode::Dense);
+impl_either!(SparseEither, UnionMode::Sparse);
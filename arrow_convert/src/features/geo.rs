@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use arrow::array::{BinaryArray, BinaryBuilder};
+use arrow::datatypes::{DataType, Field};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::{ArrowField, EXTENSION_NAME_KEY};
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+const WKB_EXTENSION_NAME: &str = "geoarrow.wkb";
+
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+
+/// Writes a geometry as little-endian 2D well-known binary.
+trait WriteWkb {
+    fn write_wkb(&self, buf: &mut Vec<u8>);
+}
+
+fn write_header(buf: &mut Vec<u8>, geometry_type: u32) {
+    buf.push(1);
+    buf.extend_from_slice(&geometry_type.to_le_bytes());
+}
+
+fn write_len(buf: &mut Vec<u8>, len: usize) {
+    buf.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_coord(buf: &mut Vec<u8>, c: &Coord<f64>) {
+    buf.extend_from_slice(&c.x.to_le_bytes());
+    buf.extend_from_slice(&c.y.to_le_bytes());
+}
+
+fn write_coords(buf: &mut Vec<u8>, l: &LineString<f64>) {
+    write_len(buf, l.0.len());
+    l.0.iter().for_each(|c| write_coord(buf, c));
+}
+
+impl WriteWkb for Point<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_POINT);
+        write_coord(buf, &self.0);
+    }
+}
+
+impl WriteWkb for LineString<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_LINE_STRING);
+        write_coords(buf, self);
+    }
+}
+
+impl WriteWkb for Polygon<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_POLYGON);
+        // an empty polygon has no rings
+        if self.exterior().0.is_empty() {
+            write_len(buf, 0);
+            return;
+        }
+        write_len(buf, 1 + self.interiors().len());
+        write_coords(buf, self.exterior());
+        self.interiors().iter().for_each(|r| write_coords(buf, r));
+    }
+}
+
+impl WriteWkb for MultiPoint<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_MULTI_POINT);
+        write_len(buf, self.0.len());
+        self.0.iter().for_each(|g| g.write_wkb(buf));
+    }
+}
+
+impl WriteWkb for MultiLineString<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_MULTI_LINE_STRING);
+        write_len(buf, self.0.len());
+        self.0.iter().for_each(|g| g.write_wkb(buf));
+    }
+}
+
+impl WriteWkb for MultiPolygon<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_MULTI_POLYGON);
+        write_len(buf, self.0.len());
+        self.0.iter().for_each(|g| g.write_wkb(buf));
+    }
+}
+
+impl WriteWkb for GeometryCollection<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        write_header(buf, WKB_GEOMETRY_COLLECTION);
+        write_len(buf, self.0.len());
+        self.0.iter().for_each(|g| g.write_wkb(buf));
+    }
+}
+
+impl WriteWkb for Geometry<f64> {
+    fn write_wkb(&self, buf: &mut Vec<u8>) {
+        // `Line`, `Rect` and `Triangle` have no WKB representation and are written as their
+        // `LineString` and `Polygon` equivalents
+        match self {
+            Geometry::Point(g) => g.write_wkb(buf),
+            Geometry::Line(g) => LineString::from(*g).write_wkb(buf),
+            Geometry::LineString(g) => g.write_wkb(buf),
+            Geometry::Polygon(g) => g.write_wkb(buf),
+            Geometry::MultiPoint(g) => g.write_wkb(buf),
+            Geometry::MultiLineString(g) => g.write_wkb(buf),
+            Geometry::MultiPolygon(g) => g.write_wkb(buf),
+            Geometry::GeometryCollection(g) => g.write_wkb(buf),
+            Geometry::Rect(g) => g.to_polygon().write_wkb(buf),
+            Geometry::Triangle(g) => g.to_polygon().write_wkb(buf),
+        }
+    }
+}
+
+/// Reads 2D well-known binary in either byte order.
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        if self.bytes.len() < N {
+            return Err("unexpected end of input".to_string());
+        }
+        let (head, tail) = self.bytes.split_at(N);
+        self.bytes = tail;
+        Ok(head.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let b = self.take::<4>()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        })
+    }
+
+    fn read_f64(&mut self) -> Result<f64, String> {
+        let b = self.take::<8>()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(b)
+        } else {
+            f64::from_be_bytes(b)
+        })
+    }
+
+    fn read_coord(&mut self) -> Result<Coord<f64>, String> {
+        Ok(Coord {
+            x: self.read_f64()?,
+            y: self.read_f64()?,
+        })
+    }
+
+    fn read_line_string(&mut self) -> Result<LineString<f64>, String> {
+        let len = self.read_u32()?;
+        (0..len)
+            .map(|_| self.read_coord())
+            .collect::<Result<_, _>>()
+            .map(LineString)
+    }
+
+    fn read_polygon(&mut self) -> Result<Polygon<f64>, String> {
+        let len = self.read_u32()?;
+        let mut rings = (0..len)
+            .map(|_| self.read_line_string())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn read_geometries<T>(&mut self, f: impl Fn(Geometry<f64>) -> Option<T>) -> Result<Vec<T>, String> {
+        let len = self.read_u32()?;
+        (0..len)
+            .map(|_| {
+                let g = self.read_geometry()?;
+                f(g).ok_or_else(|| "unexpected geometry type in multi geometry".to_string())
+            })
+            .collect()
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>, String> {
+        self.little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => return Err(format!("invalid byte order {b}")),
+        };
+        match self.read_u32()? {
+            WKB_POINT => self.read_coord().map(|c| Point(c).into()),
+            WKB_LINE_STRING => self.read_line_string().map(Geometry::LineString),
+            WKB_POLYGON => self.read_polygon().map(Geometry::Polygon),
+            WKB_MULTI_POINT => self
+                .read_geometries(|g| Point::try_from(g).ok())
+                .map(|g| MultiPoint(g).into()),
+            WKB_MULTI_LINE_STRING => self
+                .read_geometries(|g| LineString::try_from(g).ok())
+                .map(|g| MultiLineString(g).into()),
+            WKB_MULTI_POLYGON => self
+                .read_geometries(|g| Polygon::try_from(g).ok())
+                .map(|g| MultiPolygon(g).into()),
+            WKB_GEOMETRY_COLLECTION => self
+                .read_geometries(Some)
+                .map(|g| Geometry::GeometryCollection(GeometryCollection(g))),
+            t => Err(format!("unsupported geometry type {t}")),
+        }
+    }
+}
+
+fn parse_wkb<T>(v: &[u8]) -> T
+where
+    T: TryFrom<Geometry<f64>>,
+{
+    let mut reader = WkbReader {
+        bytes: v,
+        little_endian: true,
+    };
+    let geometry = reader.read_geometry().unwrap_or_else(|e| {
+        panic!(
+            "Expected valid WKB deserializing `{}`: {e}",
+            std::any::type_name::<T>()
+        )
+    });
+    T::try_from(geometry).unwrap_or_else(|_| {
+        panic!(
+            "Expected a WKB geometry of type `{}`",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+// Macro to implement the traits for a geometry type stored as WKB.
+macro_rules! impl_geometry {
+    ($physical_type:ty) => {
+        impl ArrowField for $physical_type {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Binary
+            }
+
+            #[inline]
+            fn field(name: &str) -> Field {
+                Field::new(name, Self::data_type(), Self::is_nullable()).with_metadata(HashMap::from([(
+                    EXTENSION_NAME_KEY.to_string(),
+                    WKB_EXTENSION_NAME.to_string(),
+                )]))
+            }
+        }
+
+        arrow_enable_vec_for_type!($physical_type);
+
+        impl ArrowSerialize for $physical_type {
+            type ArrayBuilderType = BinaryBuilder;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                let mut buf = Vec::new();
+                v.write_wkb(&mut buf);
+                array.check_offsets(buf.len())?;
+                array.append_value(buf);
+                Ok(())
+            }
+        }
+
+        impl ArrowDeserialize for $physical_type {
+            type ArrayType = BinaryArray;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+                v.map(parse_wkb)
+            }
+        }
+    };
+}
+
+impl_geometry!(Point<f64>);
+impl_geometry!(LineString<f64>);
+impl_geometry!(Polygon<f64>);
+impl_geometry!(MultiPoint<f64>);
+impl_geometry!(MultiLineString<f64>);
+impl_geometry!(MultiPolygon<f64>);
+impl_geometry!(GeometryCollection<f64>);
+impl_geometry!(Geometry<f64>);
@@ -0,0 +1,86 @@
+use std::hash::{BuildHasher, Hash};
+
+use arrow::array::{Array, MapArray, MapBuilder, StructArray};
+use arrow::datatypes::DataType;
+use indexmap::IndexMap;
+
+use crate::deserialize::{
+    arrow_deserialize_map_helper, is_map_deserializable_from, map_unknown_fields, validate_entries,
+    validate_entry_arrays, ArrowArrayIterable, ArrowDeserialize,
+};
+use crate::field::{map_data_type, ArrowEnableVecForType, ArrowField};
+use crate::serialize::{arrow_serialize_map_helper, new_map_array, ArrowSerialize};
+
+impl<K, V, S> ArrowField for IndexMap<K, V, S>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = IndexMap<<K as ArrowField>::Type, <V as ArrowField>::Type, S>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        map_data_type::<K, V>(false)
+    }
+}
+
+impl<K, V, S> ArrowEnableVecForType for IndexMap<K, V, S>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
+
+impl<K, V, S> ArrowSerialize for IndexMap<K, V, S>
+where
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
+{
+    type ArrayBuilderType =
+        MapBuilder<<K as ArrowSerialize>::ArrayBuilderType, <V as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        new_map_array::<K, V>()
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        arrow_serialize_map_helper::<K, V>(v.iter(), array)
+    }
+}
+
+impl<K, V, S> ArrowDeserialize for IndexMap<K, V, S>
+where
+    K: ArrowDeserialize + 'static,
+    V: ArrowDeserialize + 'static,
+    K::ArrayType: ArrowArrayIterable,
+    V::ArrayType: ArrowArrayIterable,
+    <K as ArrowField>::Type: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type ArrayType = MapArray;
+
+    fn arrow_deserialize(v: Option<StructArray>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_map_helper::<K, V, _>(v)
+    }
+
+    #[inline]
+    fn is_deserializable_from(data_type: &DataType) -> bool {
+        is_map_deserializable_from::<K, V>(data_type, false)
+    }
+
+    #[inline]
+    fn unknown_fields(data_type: &DataType) -> Vec<String> {
+        map_unknown_fields::<K, V>(data_type)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_entries::<K, V>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_entry_arrays::<K, V>(array)
+    }
+}
@@ -0,0 +1,197 @@
+use arrow::array::{
+    Date32Array, Date32Builder, IntervalMonthDayNanoArray, IntervalMonthDayNanoBuilder, Time64NanosecondArray,
+    Time64NanosecondBuilder, TimestampNanosecondArray, TimestampNanosecondBuilder,
+};
+use arrow::datatypes::{DataType, IntervalMonthDayNano, IntervalUnit, TimeUnit};
+use jiff::civil::{Date, Time};
+use jiff::{SignedDuration, Span, Timestamp};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::ArrowField;
+use crate::serialize::ArrowSerialize;
+
+const UNIX_EPOCH_DATE: Date = Date::constant(1970, 1, 1);
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The timezone of the `Timestamp` values, which are always in UTC.
+const UTC_TIMEZONE: &str = "UTC";
+
+impl ArrowField for Timestamp {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(UTC_TIMEZONE.into()))
+    }
+}
+
+arrow_enable_vec_for_type!(Timestamp);
+
+impl ArrowSerialize for Timestamp {
+    type ArrayBuilderType = TimestampNanosecondBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let v = i64::try_from(v.as_nanosecond()).map_err(|_| overflow_error(v, &<Self as ArrowField>::data_type()))?;
+        array.append_value(v);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Timestamp {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|t| Timestamp::from_nanosecond(t as i128).ok())
+    }
+}
+
+impl ArrowField for Date {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Date32
+    }
+}
+
+arrow_enable_vec_for_type!(Date);
+
+impl ArrowSerialize for Date {
+    type ArrayBuilderType = Date32Builder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let days = v.duration_since(UNIX_EPOCH_DATE).as_secs() / SECONDS_PER_DAY;
+        array.append_value(days as i32);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Date {
+    type ArrayType = Date32Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i32>) -> Option<Self> {
+        v.and_then(|t| UNIX_EPOCH_DATE.checked_add(Span::new().try_days(t).ok()?).ok())
+    }
+}
+
+impl ArrowField for Time {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Time64(TimeUnit::Nanosecond)
+    }
+}
+
+arrow_enable_vec_for_type!(Time);
+
+impl ArrowSerialize for Time {
+    type ArrayBuilderType = Time64NanosecondBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v.duration_since(Time::midnight()).as_nanos() as i64);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Time {
+    type ArrayType = Time64NanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|t| Time::midnight().checked_add(SignedDuration::from_nanos(t)).ok())
+    }
+}
+
+impl ArrowField for Span {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Interval(IntervalUnit::MonthDayNano)
+    }
+}
+
+arrow_enable_vec_for_type!(Span);
+
+/// Converts a `Span` to an interval, folding years into months, weeks into days and all time units
+/// into nanoseconds. Returns `None` if a component overflows.
+fn span_to_interval(v: &Span) -> Option<IntervalMonthDayNano> {
+    let months = i32::from(v.get_years()).checked_mul(12)?.checked_add(v.get_months())?;
+    let days = v.get_weeks().checked_mul(7)?.checked_add(v.get_days())?;
+    let nanoseconds = [
+        (i64::from(v.get_hours()), 3_600_000_000_000),
+        (v.get_minutes(), 60_000_000_000),
+        (v.get_seconds(), 1_000_000_000),
+        (v.get_milliseconds(), 1_000_000),
+        (v.get_microseconds(), 1_000),
+        (v.get_nanoseconds(), 1),
+    ]
+    .into_iter()
+    .try_fold(0i64, |acc, (value, scale)| {
+        acc.checked_add(value.checked_mul(scale)?)
+    })?;
+    Some(IntervalMonthDayNano::new(months, days, nanoseconds))
+}
+
+impl ArrowSerialize for Span {
+    type ArrayBuilderType = IntervalMonthDayNanoBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let interval = span_to_interval(v).ok_or_else(|| overflow_error(v, &<Self as ArrowField>::data_type()))?;
+        array.append_value(interval);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Span {
+    type ArrayType = IntervalMonthDayNanoArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<IntervalMonthDayNano>) -> Option<Self> {
+        v.map(|t| {
+            // a `Span` has a single sign, so an interval whose components have different signs has no equivalent
+            let signs = [t.months.signum(), t.days.signum(), t.nanoseconds.signum() as i32];
+            if signs.contains(&1) && signs.contains(&-1) {
+                panic!("Expected an interval whose components have the same sign to deserialize a `Span`, got {t:?}");
+            }
+            Span::new()
+                .try_months(t.months)
+                .and_then(|s| s.try_days(t.days))
+                .and_then(|s| s.try_nanoseconds(t.nanoseconds))
+                .unwrap_or_else(|e| panic!("Expected an interval representable as a `Span`, got {t:?}: {e}"))
+        })
+    }
+}
+
+fn overflow_error(v: &impl std::fmt::Display, data_type: &DataType) -> arrow::error::ArrowError {
+    arrow::error::ArrowError::InvalidArgumentError(format!("{v} overflows an Arrow `{data_type}`"))
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use arrow::array::{LargeStringArray, LargeStringBuilder, StringArray, StringBuilder};
+use arrow::datatypes::{DataType, Field};
+use serde_json::Value;
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::{ArrowEnableVecForType, ArrowField, LargeJson, EXTENSION_NAME_KEY};
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+const JSON_EXTENSION_NAME: &str = "arrow.json";
+
+fn json_field(name: &str, data_type: DataType) -> Field {
+    Field::new(name, data_type, false).with_metadata(HashMap::from([(
+        EXTENSION_NAME_KEY.to_string(),
+        JSON_EXTENSION_NAME.to_string(),
+    )]))
+}
+
+fn parse_json(v: &str) -> Value {
+    serde_json::from_str(v).unwrap_or_else(|e| panic!("Expected valid JSON deserializing `serde_json::Value`: {e}"))
+}
+
+impl ArrowField for Value {
+    type Type = Value;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        json_field(name, Self::data_type())
+    }
+}
+
+arrow_enable_vec_for_type!(Value);
+
+impl ArrowSerialize for Value {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Value, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let v = v.to_string();
+        array.check_offsets(v.len())?;
+        array.append_value(v);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Value {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Value> {
+        v.map(parse_json)
+    }
+}
+
+impl ArrowField for LargeJson {
+    type Type = Value;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::LargeUtf8
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        json_field(name, Self::data_type())
+    }
+}
+
+impl ArrowEnableVecForType for LargeJson {}
+
+impl ArrowSerialize for LargeJson {
+    type ArrayBuilderType = LargeStringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Value, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v.to_string());
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for LargeJson {
+    type ArrayType = LargeStringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Value> {
+        v.map(parse_json)
+    }
+}
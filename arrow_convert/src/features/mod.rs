@@ -6,3 +6,45 @@ mod rust_decimal;
 
 #[cfg(feature = "glam")]
 mod glam;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+
+#[cfg(feature = "indexmap")]
+mod indexmap;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "jiff")]
+mod jiff;
+
+#[cfg(feature = "ndarray")]
+mod ndarray;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "geo")]
+mod geo;
+
+#[cfg(feature = "url")]
+mod url;
+
+#[cfg(feature = "semver")]
+mod semver;
+
+#[cfg(feature = "either")]
+mod either;
+
+#[cfg(feature = "compact_str")]
+mod compact_str;
+
+#[cfg(feature = "smartstring")]
+mod smartstring;
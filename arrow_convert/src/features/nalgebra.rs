@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, FixedSizeListArray, FixedSizeListBuilder};
+use arrow::datatypes::DataType;
+use nalgebra::{SMatrix, Scalar};
+
+use crate::deserialize::{
+    arrow_deserialize_vec_helper, validate_element_array, validate_elements, ArrowArrayIterable, ArrowDeserialize,
+};
+use crate::field::{ArrowEnableVecForType, ArrowField, DEFAULT_FIELD_NAME};
+use crate::serialize::ArrowSerialize;
+
+// `SVector<T, N>` is an alias of `SMatrix<T, N, 1>`, so the implementations below cover both.
+// The elements are stored in nalgebra's column-major order.
+
+impl<T, const R: usize, const C: usize> ArrowField for SMatrix<T, R, C>
+where
+    T: ArrowField + ArrowEnableVecForType,
+    <T as ArrowField>::Type: Scalar,
+{
+    type Type = SMatrix<<T as ArrowField>::Type, R, C>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        let field = <T as ArrowField>::field(DEFAULT_FIELD_NAME);
+        DataType::FixedSizeList(Arc::new(field), (R * C) as i32)
+    }
+}
+
+impl<T, const R: usize, const C: usize> ArrowEnableVecForType for SMatrix<T, R, C>
+where
+    T: ArrowField + ArrowEnableVecForType,
+    <T as ArrowField>::Type: Scalar,
+{
+}
+
+impl<T, const R: usize, const C: usize> ArrowSerialize for SMatrix<T, R, C>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+    <T as ArrowField>::Type: Scalar,
+{
+    type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(<T as ArrowSerialize>::new_array(), (R * C) as i32)
+            .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T, const R: usize, const C: usize> ArrowDeserialize for SMatrix<T, R, C>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+    <T as ArrowField>::Type: Scalar,
+{
+    type ArrayType = FixedSizeListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        let result = arrow_deserialize_vec_helper::<T>(v)?;
+        if result.len() != R * C {
+            panic!(
+                "Expected {} elements deserializing `SMatrix<{}, {}, {}>`, got {}",
+                R * C,
+                std::any::type_name::<T>(),
+                R,
+                C,
+                result.len()
+            );
+        }
+        Some(SMatrix::from_iterator(result))
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, FixedSizeListArray, FixedSizeListBuilder, ListArray, ListBuilder};
+use arrow::datatypes::{DataType, Field};
+use ndarray::{Array1, Array2};
+
+use crate::deserialize::{
+    arrow_deserialize_vec_helper, validate_element_array, validate_elements, ArrowArrayIterable, ArrowDeserialize,
+};
+use crate::field::{
+    fixed_shape_tensor_metadata, ArrowEnableVecForType, ArrowField, FixedArray1, FixedArray2, DEFAULT_FIELD_NAME,
+};
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+impl<T> ArrowField for Array1<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = Array1<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
+impl<T> ArrowEnableVecForType for Array1<T> where T: ArrowField + ArrowEnableVecForType {}
+
+impl<T> ArrowSerialize for Array1<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T> ArrowDeserialize for Array1<T>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = ListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        arrow_deserialize_vec_helper::<T>(v).map(Array1::from_vec)
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+impl<T, const SIZE: usize> ArrowField for FixedArray1<T, SIZE>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = Array1<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        let field = <T as ArrowField>::field(DEFAULT_FIELD_NAME);
+        DataType::FixedSizeList(Arc::new(field), SIZE as i32)
+    }
+}
+
+impl<T, const SIZE: usize> ArrowEnableVecForType for FixedArray1<T, SIZE> where T: ArrowField + ArrowEnableVecForType {}
+
+impl<T, const SIZE: usize> ArrowSerialize for FixedArray1<T, SIZE>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(<T as ArrowSerialize>::new_array(), SIZE as i32)
+            .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        if v.len() != SIZE {
+            return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                "Expected {} elements serializing `Array1`, got {}",
+                SIZE,
+                v.len()
+            )));
+        }
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T, const SIZE: usize> ArrowDeserialize for FixedArray1<T, SIZE>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = FixedSizeListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        let result = arrow_deserialize_vec_helper::<T>(v)?;
+        if result.len() != SIZE {
+            panic!(
+                "Expected {} elements deserializing `Array1` of type `{}`, got {}",
+                SIZE,
+                std::any::type_name::<T>(),
+                result.len()
+            );
+        }
+        Some(Array1::from_vec(result))
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> ArrowField for FixedArray2<T, ROWS, COLS>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = Array2<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        let field = <T as ArrowField>::field(DEFAULT_FIELD_NAME);
+        DataType::FixedSizeList(Arc::new(field), (ROWS * COLS) as i32)
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        Field::new(name, Self::data_type(), Self::is_nullable())
+            .with_metadata(fixed_shape_tensor_metadata(&[ROWS, COLS]))
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> ArrowEnableVecForType for FixedArray2<T, ROWS, COLS> where
+    T: ArrowField + ArrowEnableVecForType
+{
+}
+
+impl<T, const ROWS: usize, const COLS: usize> ArrowSerialize for FixedArray2<T, ROWS, COLS>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(<T as ArrowSerialize>::new_array(), (ROWS * COLS) as i32)
+            .with_field(<T as ArrowField>::field(DEFAULT_FIELD_NAME))
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        if v.dim() != (ROWS, COLS) {
+            return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                "Expected shape {:?} serializing `Array2`, got {:?}",
+                [ROWS, COLS],
+                v.shape()
+            )));
+        }
+        let values = array.values();
+        // `iter` visits the elements in logical row-major order regardless of the memory layout
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> ArrowDeserialize for FixedArray2<T, ROWS, COLS>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    <T as ArrowDeserialize>::ArrayType: 'static,
+    <T as ArrowDeserialize>::ArrayType: ArrowArrayIterable,
+{
+    type ArrayType = FixedSizeListArray;
+
+    fn arrow_deserialize(v: Option<ArrayRef>) -> Option<<Self as ArrowField>::Type> {
+        let result = arrow_deserialize_vec_helper::<T>(v)?;
+        let length = result.len();
+        match Array2::from_shape_vec((ROWS, COLS), result) {
+            Ok(array) => Some(array),
+            Err(_) => panic!(
+                "Expected {} elements deserializing `Array2` of shape {:?}, got {}",
+                ROWS * COLS,
+                [ROWS, COLS],
+                length
+            ),
+        }
+    }
+
+    #[inline]
+    fn validate(v: &<Self as ArrowField>::Type) -> Result<(), String> {
+        validate_elements::<T>(v)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<(), String> {
+        validate_element_array::<T>(array)
+    }
+}
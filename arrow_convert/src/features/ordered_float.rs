@@ -0,0 +1,103 @@
+use arrow::array::{Array, AsArray, PrimitiveArray, PrimitiveBuilder};
+use arrow::datatypes::{DataType, Float32Type, Float64Type};
+use ordered_float::{NotNan, OrderedFloat};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::ArrowField;
+use crate::serialize::ArrowSerialize;
+
+// Macro to implement the traits for the ordered float wrappers of a native float type.
+macro_rules! impl_ordered_float {
+    ($physical_type:ty, $primitive_type:ty, $logical_type:ident) => {
+        impl ArrowField for OrderedFloat<$physical_type> {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::$logical_type
+            }
+        }
+
+        arrow_enable_vec_for_type!(OrderedFloat<$physical_type>);
+
+        impl ArrowSerialize for OrderedFloat<$physical_type> {
+            type ArrayBuilderType = PrimitiveBuilder<$primitive_type>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                array.append_value(v.into_inner());
+                Ok(())
+            }
+        }
+
+        impl ArrowDeserialize for OrderedFloat<$physical_type> {
+            type ArrayType = PrimitiveArray<$primitive_type>;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<$physical_type>) -> Option<Self> {
+                v.map(OrderedFloat)
+            }
+        }
+
+        impl ArrowField for NotNan<$physical_type> {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::$logical_type
+            }
+        }
+
+        arrow_enable_vec_for_type!(NotNan<$physical_type>);
+
+        impl ArrowSerialize for NotNan<$physical_type> {
+            type ArrayBuilderType = PrimitiveBuilder<$primitive_type>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                array.append_value(v.into_inner());
+                Ok(())
+            }
+        }
+
+        impl ArrowDeserialize for NotNan<$physical_type> {
+            type ArrayType = PrimitiveArray<$primitive_type>;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<$physical_type>) -> Option<Self> {
+                v.map(|t| {
+                    NotNan::new(t).unwrap_or_else(|_| {
+                        panic!(
+                            "Expected a non-NaN value deserializing `NotNan<{}>`",
+                            stringify!($physical_type)
+                        )
+                    })
+                })
+            }
+
+            fn validate_array(array: &dyn Array) -> Result<(), String> {
+                match array.as_primitive_opt::<$primitive_type>() {
+                    Some(array) if array.iter().flatten().any(|t| t.is_nan()) => Err(format!(
+                        "Expected a non-NaN value deserializing `NotNan<{}>`",
+                        stringify!($physical_type)
+                    )),
+                    _ => Ok(()),
+                }
+            }
+        }
+    };
+}
+
+impl_ordered_float!(f32, Float32Type, Float32);
+impl_ordered_float!(f64, Float64Type, Float64);
@@ -1,9 +1,9 @@
 use crate::arrow_enable_vec_for_type;
 use crate::deserialize::ArrowDeserialize;
-use crate::field::ArrowField;
+use crate::field::{ArrowEnableVecForType, ArrowField, ScaledDecimal};
 use crate::serialize::ArrowSerialize;
 
-use arrow::datatypes::{DataType, DECIMAL128_MAX_PRECISION, DECIMAL_DEFAULT_SCALE};
+use arrow::datatypes::{DataType, Decimal128Type, DecimalType, DECIMAL128_MAX_PRECISION, DECIMAL_DEFAULT_SCALE};
 use rust_decimal::Decimal;
 
 use arrow::array::{Decimal128Array, Decimal128Builder};
@@ -27,7 +27,9 @@ impl ArrowSerialize for Decimal {
     }
 
     fn arrow_serialize(v: &Self::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_value(decimal_to_scaled_i128(*v));
+        let value =
+            decimal_to_scaled_i128(*v, DECIMAL_DEFAULT_SCALE).ok_or_else(|| overflow_error(v, &Self::data_type()))?;
+        array.append_value(value);
         Ok(())
     }
 }
@@ -40,16 +42,61 @@ impl ArrowDeserialize for Decimal {
     }
 }
 
-/// Converts a `Decimal` value to an `i128` representation, adjusting the scale to match the default scale.
-fn decimal_to_scaled_i128(decimal: Decimal) -> i128 {
+impl<const PRECISION: u8, const SCALE: i8> ArrowField for ScaledDecimal<PRECISION, SCALE> {
+    type Type = Decimal;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Decimal128(PRECISION, SCALE)
+    }
+}
+
+impl<const PRECISION: u8, const SCALE: i8> ArrowEnableVecForType for ScaledDecimal<PRECISION, SCALE> {}
+
+impl<const PRECISION: u8, const SCALE: i8> ArrowSerialize for ScaledDecimal<PRECISION, SCALE> {
+    type ArrayBuilderType = Decimal128Builder;
+
+    fn new_array() -> Self::ArrayBuilderType {
+        Decimal128Builder::new().with_data_type(Self::data_type())
+    }
+
+    fn arrow_serialize(v: &Decimal, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let value = decimal_to_scaled_i128(*v, SCALE).ok_or_else(|| overflow_error(v, &Self::data_type()))?;
+        Decimal128Type::validate_decimal_precision(value, PRECISION)?;
+        array.append_value(value);
+        Ok(())
+    }
+}
+
+impl<const PRECISION: u8, const SCALE: i8> ArrowDeserialize for ScaledDecimal<PRECISION, SCALE> {
+    type ArrayType = Decimal128Array;
+
+    fn arrow_deserialize(v: Option<i128>) -> Option<Decimal> {
+        v.map(|d| {
+            let (d, scale) = if SCALE < 0 {
+                (d * 10_i128.pow(SCALE.unsigned_abs() as u32), 0)
+            } else {
+                (d, SCALE as u32)
+            };
+            Decimal::try_from_i128_with_scale(d, scale)
+                .unwrap_or_else(|e| panic!("Expected a value representable as a `Decimal`, got {d}: {e}"))
+        })
+    }
+}
+
+/// Converts a `Decimal` value to an `i128` representation with the given scale, truncating extra digits. Returns
+/// `None` on overflow.
+fn decimal_to_scaled_i128(decimal: Decimal, scale: i8) -> Option<i128> {
     let m = decimal.mantissa();
-    let scale_diff = DECIMAL_DEFAULT_SCALE as i32 - decimal.scale() as i32;
+    let scale_diff = scale as i32 - decimal.scale() as i32;
 
-    if scale_diff == 0 {
-        m
-    } else if scale_diff < 0 {
-        m / 10_i128.pow(scale_diff.unsigned_abs())
-    } else {
-        m * 10_i128.pow(scale_diff as u32)
+    match scale_diff.cmp(&0) {
+        std::cmp::Ordering::Equal => Some(m),
+        std::cmp::Ordering::Less => Some(m / 10_i128.checked_pow(scale_diff.unsigned_abs())?),
+        std::cmp::Ordering::Greater => m.checked_mul(10_i128.checked_pow(scale_diff as u32)?),
     }
 }
+
+fn overflow_error(v: &Decimal, data_type: &DataType) -> arrow::error::ArrowError {
+    arrow::error::ArrowError::InvalidArgumentError(format!("{v} overflows an Arrow `{data_type}`"))
+}
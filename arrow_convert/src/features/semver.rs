@@ -0,0 +1,124 @@
+use arrow::array::{StringArray, StringBuilder, UInt64Builder};
+use arrow::datatypes::{DataType, Field, Fields};
+use semver::{BuildMetadata, Prerelease, Version};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::{ArrowDeserialize, TupleArray};
+use crate::field::{ArrowEnableVecForType, ArrowField, VersionStruct};
+use crate::serialize::{ArrowSerialize, CheckOffsets, TupleBuilder};
+
+fn parse_version(v: &str) -> Version {
+    Version::parse(v).unwrap_or_else(|e| panic!("Expected a valid version deserializing `semver::Version`: {e}"))
+}
+
+impl ArrowField for Version {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+arrow_enable_vec_for_type!(Version);
+
+impl ArrowSerialize for Version {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let v = v.to_string();
+        array.check_offsets(v.len())?;
+        array.append_value(v);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Version {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(parse_version)
+    }
+}
+
+fn version_struct_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("major", DataType::UInt64, false),
+        Field::new("minor", DataType::UInt64, false),
+        Field::new("patch", DataType::UInt64, false),
+        Field::new("pre", DataType::Utf8, false),
+        Field::new("build", DataType::Utf8, false),
+    ])
+}
+
+impl ArrowField for VersionStruct {
+    type Type = Version;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Struct(version_struct_fields())
+    }
+}
+
+impl ArrowEnableVecForType for VersionStruct {}
+
+impl ArrowSerialize for VersionStruct {
+    type ArrayBuilderType = TupleBuilder<(
+        UInt64Builder,
+        UInt64Builder,
+        UInt64Builder,
+        StringBuilder,
+        StringBuilder,
+    )>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        TupleBuilder::new(
+            version_struct_fields(),
+            (
+                UInt64Builder::new(),
+                UInt64Builder::new(),
+                UInt64Builder::new(),
+                StringBuilder::new(),
+                StringBuilder::new(),
+            ),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Version, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        values.0.append_value(v.major);
+        values.1.append_value(v.minor);
+        values.2.append_value(v.patch);
+        values.3.append_value(v.pre.as_str());
+        values.4.append_value(v.build.as_str());
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for VersionStruct {
+    // the struct fields are read positionally
+    type ArrayType = TupleArray<(u64, u64, u64, String, String)>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<(u64, u64, u64, String, String)>) -> Option<Version> {
+        v.map(|(major, minor, patch, pre, build)| Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::new(&pre)
+                .unwrap_or_else(|e| panic!("Expected a valid pre-release deserializing `semver::Version`: {e}")),
+            build: BuildMetadata::new(&build)
+                .unwrap_or_else(|e| panic!("Expected valid build metadata deserializing `semver::Version`: {e}")),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use arrow::array::{StringArray, StringBuilder};
+use arrow::datatypes::DataType;
+use smartstring::{SmartString, SmartStringMode};
+
+use crate::deserialize::ArrowDeserialize;
+use crate::field::{ArrowEnableVecForType, ArrowField};
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+impl<Mode> ArrowField for SmartString<Mode>
+where
+    Mode: SmartStringMode,
+{
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+impl<Mode> ArrowEnableVecForType for SmartString<Mode> where Mode: SmartStringMode {}
+
+impl<Mode> ArrowSerialize for SmartString<Mode>
+where
+    Mode: SmartStringMode,
+{
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_str().len())?;
+        array.append_value(v.as_str());
+        Ok(())
+    }
+}
+
+impl<Mode> ArrowDeserialize for SmartString<Mode>
+where
+    Mode: SmartStringMode,
+{
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(SmartString::from)
+    }
+}
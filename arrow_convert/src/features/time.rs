@@ -0,0 +1,182 @@
+use arrow::array::{
+    Date32Array, Date32Builder, Time64NanosecondArray, Time64NanosecondBuilder, TimestampNanosecondArray,
+    TimestampNanosecondBuilder,
+};
+use arrow::datatypes::{DataType, TimeUnit};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::ArrowDeserialize;
+use crate::field::ArrowField;
+use crate::serialize::ArrowSerialize;
+
+/// The julian day number of the unix epoch (1970-01-01)
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+
+/// The timezone of the `OffsetDateTime` timestamps, which are normalized to UTC.
+const UTC_TIMEZONE: &str = "UTC";
+
+/// Returns the nanoseconds since the unix epoch of `v`, or an error naming the type `T` if they overflow an `i64`.
+fn timestamp_nanos<T>(v: OffsetDateTime) -> arrow::error::Result<i64> {
+    i64::try_from(v.unix_timestamp_nanos()).map_err(|_| {
+        arrow::error::ArrowError::InvalidArgumentError(format!(
+            "Value of `{}` overflows an Arrow `Timestamp(Nanosecond)`",
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
+impl ArrowField for Date {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Date32
+    }
+}
+
+arrow_enable_vec_for_type!(Date);
+
+impl ArrowSerialize for Date {
+    type ArrayBuilderType = Date32Builder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v.to_julian_day() - UNIX_EPOCH_JULIAN_DAY);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Date {
+    type ArrayType = Date32Array;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i32>) -> Option<Self> {
+        v.and_then(|t| Date::from_julian_day(t.checked_add(UNIX_EPOCH_JULIAN_DAY)?).ok())
+    }
+}
+
+impl ArrowField for Time {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Time64(TimeUnit::Nanosecond)
+    }
+}
+
+arrow_enable_vec_for_type!(Time);
+
+impl ArrowSerialize for Time {
+    type ArrayBuilderType = Time64NanosecondBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let (hour, minute, second, nanosecond) = v.as_hms_nano();
+        let seconds = hour as i64 * 3600 + minute as i64 * 60 + second as i64;
+        array.append_value(seconds * NANOSECONDS_PER_SECOND + nanosecond as i64);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Time {
+    type ArrayType = Time64NanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|t| {
+            let seconds = t.div_euclid(NANOSECONDS_PER_SECOND);
+            let nanosecond = t.rem_euclid(NANOSECONDS_PER_SECOND) as u32;
+            Time::from_hms_nano(
+                u8::try_from(seconds / 3600).ok()?,
+                (seconds / 60 % 60) as u8,
+                (seconds % 60) as u8,
+                nanosecond,
+            )
+            .ok()
+        })
+    }
+}
+
+impl ArrowField for PrimitiveDateTime {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    }
+}
+
+arrow_enable_vec_for_type!(PrimitiveDateTime);
+
+impl ArrowSerialize for PrimitiveDateTime {
+    type ArrayBuilderType = TimestampNanosecondBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(timestamp_nanos::<Self>(v.assume_utc())?);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for PrimitiveDateTime {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|t| OffsetDateTime::from_unix_timestamp_nanos(t as i128).ok())
+            .map(|t| PrimitiveDateTime::new(t.date(), t.time()))
+    }
+}
+
+impl ArrowField for OffsetDateTime {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(UTC_TIMEZONE.into()))
+    }
+}
+
+arrow_enable_vec_for_type!(OffsetDateTime);
+
+impl ArrowSerialize for OffsetDateTime {
+    type ArrayBuilderType = TimestampNanosecondBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(timestamp_nanos::<Self>(*v)?);
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for OffsetDateTime {
+    type ArrayType = TimestampNanosecondArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<i64>) -> Option<Self> {
+        v.and_then(|t| OffsetDateTime::from_unix_timestamp_nanos(t as i128).ok())
+    }
+}
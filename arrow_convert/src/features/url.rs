@@ -0,0 +1,90 @@
+use arrow::array::{StringArray, StringBuilder};
+use arrow::datatypes::DataType;
+use url::Url;
+
+use crate::arrow_enable_vec_for_type;
+use crate::deserialize::{ArrowArrayIterable, ArrowDeserialize};
+use crate::field::{ArrowEnableVecForType, ArrowField, LenientUrl};
+use crate::serialize::{ArrowSerialize, CheckOffsets};
+
+impl ArrowField for Url {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+arrow_enable_vec_for_type!(Url);
+
+impl ArrowSerialize for Url {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_str().len())?;
+        array.append_value(v.as_str());
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for Url {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(|t| Url::parse(t).unwrap_or_else(|e| panic!("Expected a valid URL deserializing `url::Url`: {e}")))
+    }
+}
+
+impl ArrowField for LenientUrl {
+    type Type = Option<Url>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
+impl ArrowEnableVecForType for LenientUrl {}
+
+impl ArrowSerialize for LenientUrl {
+    type ArrayBuilderType = StringBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Option<Url>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_ref().map_or(0, |v| v.as_str().len()))?;
+        array.append_option(v.as_ref().map(Url::as_str));
+        Ok(())
+    }
+}
+
+impl ArrowDeserialize for LenientUrl {
+    type ArrayType = StringArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Option<Url>> {
+        Some(v.and_then(|t| Url::parse(t).ok()))
+    }
+
+    #[inline]
+    fn arrow_deserialize_internal(v: <Self::ArrayType as ArrowArrayIterable>::Item<'_>) -> Option<Url> {
+        v.and_then(|t| Url::parse(t).ok())
+    }
+}
@@ -1,16 +1,30 @@
 //! Implementation and traits for mapping rust types to Arrow types
 
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 use arrow::{
     buffer::{Buffer, ScalarBuffer},
-    datatypes::{ArrowNativeType, DataType, Field},
+    datatypes::{
+        ArrowDictionaryKeyType, ArrowNativeType, ArrowPrimitiveType, DataType, Field, Fields, Int16Type, Int32Type,
+        Int64Type, Int8Type, RunEndIndexType, TimeUnit, UInt16Type, UInt32Type, UInt64Type, UInt8Type, UnionFields,
+        UnionMode,
+    },
 };
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 
 /// The default field name used when a specific name is not provided.
 pub const DEFAULT_FIELD_NAME: &str = "item";
 
+/// The field name of the entries struct of an Arrow `Map`.
+pub const MAP_ENTRIES_FIELD_NAME: &str = "entries";
+
+/// The field name of the keys of an Arrow `Map`.
+pub const MAP_KEYS_FIELD_NAME: &str = "keys";
+
+/// The field name of the values of an Arrow `Map`.
+pub const MAP_VALUES_FIELD_NAME: &str = "values";
+
 /// Trait implemented by all types that can be used as an Arrow field.
 ///
 /// Implementations are provided for types already supported by the arrow crate:
@@ -49,6 +63,36 @@ pub trait ArrowField {
     fn is_nullable() -> bool {
         false
     }
+
+    #[inline]
+    /// The metadata of the schema of a `RecordBatch` of this type. This is set by the `schema_metadata`
+    /// attribute of the derive macros.
+    fn schema_metadata() -> HashMap<String, String> {
+        HashMap::new()
+    }
+
+    #[inline]
+    /// The Parquet writer settings of the columns of this type, keyed by the path of their field. This is set by the
+    /// `parquet` attribute of the fields of derived structs.
+    fn parquet_hints() -> Vec<ParquetHint> {
+        vec![]
+    }
+}
+
+/// The Parquet writer settings of a field, set by the `parquet` attribute of the fields of derived structs. They
+/// apply to every Parquet column of the field, such as the columns of the fields of a nested struct.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParquetHint {
+    /// The names of the field and of the struct fields it's nested in, from the outermost one
+    pub path: Vec<String>,
+    /// The compression codec, such as `zstd` or `zstd(3)`
+    pub compression: Option<&'static str>,
+    /// The encoding of the values that aren't dictionary encoded, such as `delta_binary_packed`
+    pub encoding: Option<&'static str>,
+    /// Whether the values are dictionary encoded
+    pub dictionary: Option<bool>,
+    /// Whether a bloom filter of the values is written
+    pub bloom_filter: Option<bool>,
 }
 
 /// Enables the blanket implementations of [`Vec<T>`] as an Arrow field
@@ -87,6 +131,39 @@ macro_rules! impl_numeric_type_full {
     };
 }
 
+// Boxed fields are stored as the boxed type
+impl<T> ArrowField for Box<T>
+where
+    T: ArrowField,
+{
+    type Type = Box<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow::datatypes::DataType {
+        <T as ArrowField>::data_type()
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        <T as ArrowField>::field(name)
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        <T as ArrowField>::is_nullable()
+    }
+
+    #[inline]
+    fn schema_metadata() -> HashMap<String, String> {
+        <T as ArrowField>::schema_metadata()
+    }
+
+    #[inline]
+    fn parquet_hints() -> Vec<ParquetHint> {
+        <T as ArrowField>::parquet_hints()
+    }
+}
+
 // blanket implementation for optional fields
 impl<T> ArrowField for Option<T>
 where
@@ -99,12 +176,137 @@ where
         <T as ArrowField>::data_type()
     }
 
+    #[inline]
+    fn field(name: &str) -> Field {
+        <T as ArrowField>::field(name).with_nullable(true)
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+
+    #[inline]
+    fn schema_metadata() -> HashMap<String, String> {
+        <T as ArrowField>::schema_metadata()
+    }
+
+    #[inline]
+    fn parquet_hints() -> Vec<ParquetHint> {
+        <T as ArrowField>::parquet_hints()
+    }
+}
+
+/// The field name of the inner value of a [`NestedOption`].
+pub const NESTED_OPTION_FIELD_NAME: &str = "value";
+
+/// Represents an `Option<Option<T>>` as a nullable `Struct` with a single nullable `value` field, so that
+/// `None` and `Some(None)` round-trip losslessly.
+///
+/// The blanket `Option<T>` implementation maps `Option<Option<T>>` to the data type of `T`, which collapses
+/// `Some(None)` to `None`.
+pub struct NestedOption<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+impl<T> ArrowField for NestedOption<T>
+where
+    T: ArrowField,
+{
+    type Type = Option<Option<<T as ArrowField>::Type>>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Struct(Fields::from(vec![<Option<T> as ArrowField>::field(
+            NESTED_OPTION_FIELD_NAME,
+        )]))
+    }
+
     #[inline]
     fn is_nullable() -> bool {
         true
     }
 }
 
+impl<T> ArrowEnableVecForType for NestedOption<T> where T: ArrowField {}
+
+/// The field name of the `Ok` variant of a `Result<T, E>` union.
+pub const RESULT_OK_FIELD_NAME: &str = "ok";
+
+/// The field name of the `Err` variant of a `Result<T, E>` union.
+pub const RESULT_ERR_FIELD_NAME: &str = "err";
+
+impl<T, E> ArrowField for Result<T, E>
+where
+    T: ArrowField,
+    E: ArrowField,
+{
+    type Type = Result<<T as ArrowField>::Type, <E as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Union(
+            UnionFields::new(
+                0..2,
+                vec![
+                    <T as ArrowField>::field(RESULT_OK_FIELD_NAME),
+                    <E as ArrowField>::field(RESULT_ERR_FIELD_NAME),
+                ],
+            ),
+            UnionMode::Dense,
+        )
+    }
+}
+
+impl<T, E> ArrowEnableVecForType for Result<T, E>
+where
+    T: ArrowField,
+    E: ArrowField,
+{
+}
+
+/// The field name of the start of a `Range<T>` or `RangeInclusive<T>` struct.
+pub const RANGE_START_FIELD_NAME: &str = "start";
+
+/// The field name of the end of a `Range<T>` or `RangeInclusive<T>` struct.
+pub const RANGE_END_FIELD_NAME: &str = "end";
+
+fn range_data_type<T: ArrowField>() -> DataType {
+    DataType::Struct(Fields::from(vec![
+        <T as ArrowField>::field(RANGE_START_FIELD_NAME),
+        <T as ArrowField>::field(RANGE_END_FIELD_NAME),
+    ]))
+}
+
+impl<T> ArrowField for std::ops::Range<T>
+where
+    T: ArrowField,
+{
+    type Type = std::ops::Range<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        range_data_type::<T>()
+    }
+}
+
+impl<T> ArrowEnableVecForType for std::ops::Range<T> where T: ArrowField {}
+
+// the `end` field of a `RangeInclusive<T>` holds the inclusive upper bound
+impl<T> ArrowField for std::ops::RangeInclusive<T>
+where
+    T: ArrowField,
+{
+    type Type = std::ops::RangeInclusive<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        range_data_type::<T>()
+    }
+}
+
+impl<T> ArrowEnableVecForType for std::ops::RangeInclusive<T> where T: ArrowField {}
+
 // u8 does not get the full implementation since Vec<u8> and [u8] are considered binary.
 impl_numeric_type!(u8, UInt8);
 impl_numeric_type_full!(u16, UInt16);
@@ -118,6 +320,28 @@ impl_numeric_type_full!(half::f16, Float16);
 impl_numeric_type_full!(f32, Float32);
 impl_numeric_type_full!(f64, Float64);
 
+// Macro to implement `ArrowField` for the `std::num` integer wrappers by delegating to the wrapped type.
+macro_rules! impl_numeric_wrapper {
+    ($wrapper:ident) => {
+        impl<T> ArrowField for std::num::$wrapper<T>
+        where
+            T: ArrowField,
+        {
+            type Type = std::num::$wrapper<<T as ArrowField>::Type>;
+
+            #[inline]
+            fn data_type() -> DataType {
+                <T as ArrowField>::data_type()
+            }
+        }
+
+        impl<T> ArrowEnableVecForType for std::num::$wrapper<T> where T: ArrowField + ArrowEnableVecForType {}
+    };
+}
+
+impl_numeric_wrapper!(Wrapping);
+impl_numeric_wrapper!(Saturating);
+
 /// Maps a rust i128 to an Arrow Decimal where precision and scale are required.
 pub struct I128<const PRECISION: u8, const SCALE: i8> {}
 
@@ -130,6 +354,232 @@ impl<const PRECISION: u8, const SCALE: i8> ArrowField for I128<PRECISION, SCALE>
     }
 }
 
+/// A rust type that can be stored as a number of time units in an Arrow `Duration`.
+///
+/// Implemented for `i64`, which is stored as is, and `std::time::Duration`, which is truncated to whole
+/// units.
+pub trait ArrowDurationValue: Sized {
+    /// Converts the value to a number of `unit`s, returning `None` if it does not fit in an `i64`.
+    fn to_duration(&self, unit: TimeUnit) -> Option<i64>;
+
+    /// Converts a number of `unit`s to a value, returning `None` if it cannot be represented.
+    fn from_duration(v: i64, unit: TimeUnit) -> Option<Self>;
+}
+
+impl ArrowDurationValue for i64 {
+    #[inline]
+    fn to_duration(&self, _unit: TimeUnit) -> Option<i64> {
+        Some(*self)
+    }
+
+    #[inline]
+    fn from_duration(v: i64, _unit: TimeUnit) -> Option<Self> {
+        Some(v)
+    }
+}
+
+impl ArrowDurationValue for std::time::Duration {
+    #[inline]
+    fn to_duration(&self, unit: TimeUnit) -> Option<i64> {
+        match unit {
+            TimeUnit::Second => self.as_secs().try_into().ok(),
+            TimeUnit::Millisecond => self.as_millis().try_into().ok(),
+            TimeUnit::Microsecond => self.as_micros().try_into().ok(),
+            TimeUnit::Nanosecond => self.as_nanos().try_into().ok(),
+        }
+    }
+
+    #[inline]
+    fn from_duration(v: i64, unit: TimeUnit) -> Option<Self> {
+        let v = u64::try_from(v).ok()?;
+        Some(match unit {
+            TimeUnit::Second => Self::from_secs(v),
+            TimeUnit::Millisecond => Self::from_millis(v),
+            TimeUnit::Microsecond => Self::from_micros(v),
+            TimeUnit::Nanosecond => Self::from_nanos(v),
+        })
+    }
+}
+
+/// Maps `T` to an Arrow `Duration(Second)`, where `T` is `std::time::Duration` (the default) or `i64`.
+pub struct DurationSeconds<T = std::time::Duration> {
+    d: std::marker::PhantomData<T>,
+}
+
+/// Maps `T` to an Arrow `Duration(Millisecond)`, where `T` is `std::time::Duration` (the default) or `i64`.
+pub struct DurationMilliseconds<T = std::time::Duration> {
+    d: std::marker::PhantomData<T>,
+}
+
+/// Maps `T` to an Arrow `Duration(Microsecond)`, where `T` is `std::time::Duration` (the default) or `i64`.
+pub struct DurationMicroseconds<T = std::time::Duration> {
+    d: std::marker::PhantomData<T>,
+}
+
+/// Maps `T` to an Arrow `Duration(Nanosecond)`, where `T` is `std::time::Duration` (the default) or `i64`.
+pub struct DurationNanoseconds<T = std::time::Duration> {
+    d: std::marker::PhantomData<T>,
+}
+
+// Macro to implement [`ArrowField`] for the duration placeholder types.
+macro_rules! impl_duration_type {
+    ($placeholder:ident, $unit:ident) => {
+        impl<T> ArrowField for $placeholder<T>
+        where
+            T: ArrowDurationValue,
+        {
+            type Type = T;
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Duration(TimeUnit::$unit)
+            }
+        }
+
+        impl<T> ArrowEnableVecForType for $placeholder<T> where T: ArrowDurationValue {}
+    };
+}
+
+impl_duration_type!(DurationSeconds, Second);
+impl_duration_type!(DurationMilliseconds, Millisecond);
+impl_duration_type!(DurationMicroseconds, Microsecond);
+impl_duration_type!(DurationNanoseconds, Nanosecond);
+
+/// A rust type that can be stored as a number of time units since the Unix epoch in an Arrow `Timestamp`.
+///
+/// Implemented for `i64`, which is stored as is, and `chrono::NaiveDateTime` and `chrono::DateTime<Utc>`, which are
+/// truncated to whole units.
+pub trait ArrowTimestampValue: Sized {
+    /// Converts the value to a number of `unit`s since the epoch, returning `None` if it does not fit in an `i64`.
+    fn to_timestamp(&self, unit: TimeUnit) -> Option<i64>;
+
+    /// Converts a number of `unit`s since the epoch to a value, returning `None` if it cannot be represented.
+    fn from_timestamp(v: i64, unit: TimeUnit) -> Option<Self>;
+}
+
+impl ArrowTimestampValue for i64 {
+    #[inline]
+    fn to_timestamp(&self, _unit: TimeUnit) -> Option<i64> {
+        Some(*self)
+    }
+
+    #[inline]
+    fn from_timestamp(v: i64, _unit: TimeUnit) -> Option<Self> {
+        Some(v)
+    }
+}
+
+impl ArrowTimestampValue for DateTime<Utc> {
+    #[inline]
+    fn to_timestamp(&self, unit: TimeUnit) -> Option<i64> {
+        match unit {
+            TimeUnit::Second => Some(self.timestamp()),
+            TimeUnit::Millisecond => Some(self.timestamp_millis()),
+            TimeUnit::Microsecond => Some(self.timestamp_micros()),
+            TimeUnit::Nanosecond => self.timestamp_nanos_opt(),
+        }
+    }
+
+    #[inline]
+    fn from_timestamp(v: i64, unit: TimeUnit) -> Option<Self> {
+        match unit {
+            TimeUnit::Second => DateTime::from_timestamp(v, 0),
+            TimeUnit::Millisecond => DateTime::from_timestamp_millis(v),
+            TimeUnit::Microsecond => DateTime::from_timestamp_micros(v),
+            TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(v)),
+        }
+    }
+}
+
+impl ArrowTimestampValue for NaiveDateTime {
+    #[inline]
+    fn to_timestamp(&self, unit: TimeUnit) -> Option<i64> {
+        self.and_utc().to_timestamp(unit)
+    }
+
+    #[inline]
+    fn from_timestamp(v: i64, unit: TimeUnit) -> Option<Self> {
+        <DateTime<Utc> as ArrowTimestampValue>::from_timestamp(v, unit).map(|v| v.naive_utc())
+    }
+}
+
+/// The timezone of the timestamp placeholder types.
+pub trait ArrowTimeZone {
+    /// The timezone of the Arrow `Timestamp`, such as `Europe/Paris` or `+02:00`, or `None` for timestamps without
+    /// timezone.
+    fn time_zone() -> Option<Arc<str>>;
+}
+
+/// Timestamps without timezone, which represent a local date and time.
+pub struct NoTimeZone;
+
+impl ArrowTimeZone for NoTimeZone {
+    #[inline]
+    fn time_zone() -> Option<Arc<str>> {
+        None
+    }
+}
+
+/// Timestamps in the `UTC` timezone.
+pub struct UtcTimeZone;
+
+impl ArrowTimeZone for UtcTimeZone {
+    #[inline]
+    fn time_zone() -> Option<Arc<str>> {
+        Some("UTC".into())
+    }
+}
+
+/// Maps `T` to an Arrow `Timestamp(Second)` in the timezone `Tz`, where `T` implements [`ArrowTimestampValue`].
+pub struct TimestampSeconds<T = NaiveDateTime, Tz = NoTimeZone> {
+    d: std::marker::PhantomData<(T, Tz)>,
+}
+
+/// Maps `T` to an Arrow `Timestamp(Millisecond)` in the timezone `Tz`, where `T` implements [`ArrowTimestampValue`].
+pub struct TimestampMilliseconds<T = NaiveDateTime, Tz = NoTimeZone> {
+    d: std::marker::PhantomData<(T, Tz)>,
+}
+
+/// Maps `T` to an Arrow `Timestamp(Microsecond)` in the timezone `Tz`, where `T` implements [`ArrowTimestampValue`].
+pub struct TimestampMicroseconds<T = NaiveDateTime, Tz = NoTimeZone> {
+    d: std::marker::PhantomData<(T, Tz)>,
+}
+
+/// Maps `T` to an Arrow `Timestamp(Nanosecond)` in the timezone `Tz`, where `T` implements [`ArrowTimestampValue`].
+pub struct TimestampNanoseconds<T = NaiveDateTime, Tz = NoTimeZone> {
+    d: std::marker::PhantomData<(T, Tz)>,
+}
+
+// Macro to implement [`ArrowField`] for the timestamp placeholder types.
+macro_rules! impl_timestamp_type {
+    ($placeholder:ident, $unit:ident) => {
+        impl<T, Tz> ArrowField for $placeholder<T, Tz>
+        where
+            T: ArrowTimestampValue,
+            Tz: ArrowTimeZone,
+        {
+            type Type = T;
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Timestamp(TimeUnit::$unit, Tz::time_zone())
+            }
+        }
+
+        impl<T, Tz> ArrowEnableVecForType for $placeholder<T, Tz>
+        where
+            T: ArrowTimestampValue,
+            Tz: ArrowTimeZone,
+        {
+        }
+    };
+}
+
+impl_timestamp_type!(TimestampSeconds, Second);
+impl_timestamp_type!(TimestampMilliseconds, Millisecond);
+impl_timestamp_type!(TimestampMicroseconds, Microsecond);
+impl_timestamp_type!(TimestampNanoseconds, Nanosecond);
+
 impl<'a> ArrowField for &'a str {
     type Type = &'a str;
 
@@ -160,6 +610,62 @@ impl ArrowField for LargeString {
     }
 }
 
+/// Represents the `Utf8View` Arrow type
+pub struct Utf8View {}
+
+impl ArrowField for Utf8View {
+    type Type = String;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8View
+    }
+}
+
+impl ArrowField for std::ffi::OsString {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+}
+
+/// Maps an `OsString` to the `Utf8` Arrow type. Serializing a value that is not valid unicode returns an
+/// error.
+pub struct OsStringUtf8 {}
+
+impl ArrowField for OsStringUtf8 {
+    type Type = std::ffi::OsString;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+/// Maps an `OsString` to the `Utf8` Arrow type. Invalid unicode sequences are replaced with
+/// `U+FFFD REPLACEMENT CHARACTER` when serializing.
+pub struct OsStringLossy {}
+
+impl ArrowField for OsStringLossy {
+    type Type = std::ffi::OsString;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+impl ArrowField for std::ffi::CString {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+}
+
 impl ArrowField for bool {
     type Type = Self;
 
@@ -169,6 +675,36 @@ impl ArrowField for bool {
     }
 }
 
+/// The unit type maps to the `Null` Arrow type, which is always nullable.
+impl ArrowField for () {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Null
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
+/// `PhantomData` is stored like the unit type, as the `Null` Arrow type.
+impl<T: ?Sized> ArrowField for std::marker::PhantomData<T> {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Null
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
 impl ArrowField for NaiveDateTime {
     type Type = Self;
 
@@ -214,6 +750,15 @@ impl ArrowField for Vec<u8> {
     }
 }
 
+impl<'a> ArrowField for &'a [u8] {
+    type Type = &'a [u8];
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+}
+
 /// Represents the `LargeString` Arrow type.
 pub struct LargeBinary {}
 
@@ -226,6 +771,82 @@ impl ArrowField for LargeBinary {
     }
 }
 
+/// Represents the `LargeBinary` Arrow type holding a [`Buffer`].
+///
+/// Deserialized buffers share the memory of the Arrow array instead of copying each value.
+pub struct LargeBuffer {}
+
+impl ArrowField for LargeBuffer {
+    type Type = Buffer;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::LargeBinary
+    }
+}
+
+/// Represents the `BinaryView` Arrow type.
+pub struct BinaryView {}
+
+impl ArrowField for BinaryView {
+    type Type = Vec<u8>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::BinaryView
+    }
+}
+
+/// Represents a `Vec<u8>` as a `List` Arrow type of `UInt8` values instead of `Binary`.
+pub struct ByteList {}
+
+impl ArrowField for ByteList {
+    type Type = Vec<u8>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<u8 as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
+/// Represents the `LargeUtf8` Arrow type holding a `serde_json::Value` with the `arrow.json` extension.
+#[cfg(feature = "json")]
+pub struct LargeJson {}
+
+/// Represents the `Utf8` Arrow type holding an optional `url::Url`, where strings that fail to parse
+/// deserialize to `None` instead of panicking.
+#[cfg(feature = "url")]
+pub struct LenientUrl {}
+
+/// Represents a `semver::Version` as a `Struct` Arrow type with the `major`, `minor`, `patch`, `pre` and
+/// `build` fields.
+#[cfg(feature = "semver")]
+pub struct VersionStruct {}
+
+/// Represents a `rust_decimal::Decimal` as a `Decimal128` Arrow type with the given precision and scale, instead of
+/// the maximum precision and default scale used by `Decimal`. Values are rescaled, truncating extra digits.
+#[cfg(feature = "rust_decimal")]
+pub struct ScaledDecimal<const PRECISION: u8, const SCALE: i8> {}
+
+/// Represents an `either::Either<L, R>` as a sparse `Union` Arrow type. `Either` itself maps to a dense union.
+#[cfg(feature = "either")]
+pub struct SparseEither<L, R> {
+    d: std::marker::PhantomData<(L, R)>,
+}
+
+/// Represents the `FixedSizeList` Arrow type holding an `ndarray::Array1` of exactly `SIZE` elements.
+#[cfg(feature = "ndarray")]
+pub struct FixedArray1<T, const SIZE: usize> {
+    d: std::marker::PhantomData<T>,
+}
+
+/// Represents an `ndarray::Array2` of shape `[ROWS, COLS]` stored as a `FixedSizeList` of `ROWS * COLS` elements
+/// in row-major order, tagged with the `arrow.fixed_shape_tensor` canonical extension type.
+#[cfg(feature = "ndarray")]
+pub struct FixedArray2<T, const ROWS: usize, const COLS: usize> {
+    d: std::marker::PhantomData<T>,
+}
+
 /// Represents the `FixedSizeBinary` Arrow type.
 pub struct FixedSizeBinary<const SIZE: i32> {}
 
@@ -273,6 +894,45 @@ where
     }
 }
 
+// Blanket implementation for VecDeque.
+impl<T> ArrowField for VecDeque<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = VecDeque<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
+// Blanket implementation for HashSet.
+impl<T, S> ArrowField for HashSet<T, S>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = HashSet<<T as ArrowField>::Type, S>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
+// Blanket implementation for BTreeSet.
+impl<T> ArrowField for BTreeSet<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = BTreeSet<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::List(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
 /// Represents the `LargeList` Arrow type.
 pub struct LargeVec<T> {
     d: std::marker::PhantomData<T>,
@@ -290,6 +950,40 @@ where
     }
 }
 
+/// Represents the `ListView` Arrow type.
+pub struct ListView<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+impl<T> ArrowField for ListView<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = Vec<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::ListView(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
+/// Represents the `LargeListView` Arrow type.
+pub struct LargeListView<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+impl<T> ArrowField for LargeListView<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = Vec<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::LargeListView(Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME)))
+    }
+}
+
 /// Represents the `FixedSizeList` Arrow type.
 pub struct FixedSizeVec<T, const SIZE: i32> {
     d: std::marker::PhantomData<T>,
@@ -308,6 +1002,250 @@ where
     }
 }
 
+/// Maps a rust integer type to the Arrow key type of a dictionary.
+pub trait ArrowDictionaryKey {
+    /// The Arrow type of the dictionary keys
+    type KeyType: ArrowDictionaryKeyType;
+}
+
+macro_rules! impl_dictionary_key {
+    ($physical_type:ty, $key_type:ty) => {
+        impl ArrowDictionaryKey for $physical_type {
+            type KeyType = $key_type;
+        }
+    };
+}
+
+impl_dictionary_key!(i8, Int8Type);
+impl_dictionary_key!(i16, Int16Type);
+impl_dictionary_key!(i32, Int32Type);
+impl_dictionary_key!(i64, Int64Type);
+impl_dictionary_key!(u8, UInt8Type);
+impl_dictionary_key!(u16, UInt16Type);
+impl_dictionary_key!(u32, UInt32Type);
+impl_dictionary_key!(u64, UInt64Type);
+
+/// Represents the `Dictionary` Arrow type, with keys of type `K` and values of type `V`.
+///
+/// For example `Dictionary<i32, String>` maps a `String` to a dictionary-encoded `Utf8` column with `Int32` keys.
+pub struct Dictionary<K, V> {
+    d: std::marker::PhantomData<(K, V)>,
+}
+
+impl<K, V> ArrowField for Dictionary<K, V>
+where
+    K: ArrowDictionaryKey,
+    V: ArrowField,
+{
+    type Type = <V as ArrowField>::Type;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::Dictionary(
+            Box::new(<K as ArrowDictionaryKey>::KeyType::DATA_TYPE),
+            Box::new(<V as ArrowField>::data_type()),
+        )
+    }
+}
+
+/// The field name of the run ends of an Arrow `RunEndEncoded` array.
+pub const RUN_ENDS_FIELD_NAME: &str = "run_ends";
+
+/// The field name of the values of an Arrow `RunEndEncoded` array.
+pub const RUN_VALUES_FIELD_NAME: &str = "values";
+
+/// Maps a rust integer type to the Arrow run end type of a run-end encoded array.
+pub trait ArrowRunEndIndex {
+    /// The Arrow type of the run ends
+    type RunEndType: RunEndIndexType;
+}
+
+impl ArrowRunEndIndex for i16 {
+    type RunEndType = Int16Type;
+}
+
+impl ArrowRunEndIndex for i32 {
+    type RunEndType = Int32Type;
+}
+
+impl ArrowRunEndIndex for i64 {
+    type RunEndType = Int64Type;
+}
+
+/// Represents the `RunEndEncoded` Arrow type, with run ends of type `R` and values of type `V`.
+///
+/// For example `RunEnd<i32, String>` maps a `String` to a run-end encoded `Utf8` column with `Int32` run ends.
+pub struct RunEnd<R, V> {
+    d: std::marker::PhantomData<(R, V)>,
+}
+
+impl<R, V> ArrowField for RunEnd<R, V>
+where
+    R: ArrowRunEndIndex,
+    V: ArrowField,
+{
+    type Type = <V as ArrowField>::Type;
+
+    #[inline]
+    fn data_type() -> DataType {
+        DataType::RunEndEncoded(
+            Arc::new(Field::new(
+                RUN_ENDS_FIELD_NAME,
+                <R as ArrowRunEndIndex>::RunEndType::DATA_TYPE,
+                false,
+            )),
+            Arc::new(Field::new(
+                RUN_VALUES_FIELD_NAME,
+                <V as ArrowField>::data_type(),
+                true,
+            )),
+        )
+    }
+}
+
+/// The name of the `arrow.fixed_shape_tensor` canonical extension type.
+pub const FIXED_SHAPE_TENSOR_EXTENSION_NAME: &str = "arrow.fixed_shape_tensor";
+
+/// The field metadata key holding the name of an extension type.
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The field metadata key holding the serialized metadata of an extension type.
+pub const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// Returns the field metadata of the `arrow.fixed_shape_tensor` extension type for a tensor of the given shape.
+pub(crate) fn fixed_shape_tensor_metadata(shape: &[usize]) -> HashMap<String, String> {
+    let shape = shape.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+    [
+        (
+            EXTENSION_NAME_KEY.to_string(),
+            FIXED_SHAPE_TENSOR_EXTENSION_NAME.to_string(),
+        ),
+        (
+            EXTENSION_METADATA_KEY.to_string(),
+            format!("{{\"shape\":[{shape}]}}"),
+        ),
+    ]
+    .into()
+}
+
+/// Implemented by the element types and the (nested) rust arrays of elements that can be stored in a [`FixedShapeTensor`].
+///
+/// For example `[[f32; 3]; 2]` is a tensor of shape `[2, 3]` with `f32` elements.
+pub trait ArrowTensorShape: Sized {
+    /// The scalar element type of the tensor
+    type Element: ArrowField<Type = Self::Element>;
+
+    /// The dimensions of the tensor, outermost first
+    fn shape() -> Vec<usize>;
+
+    /// Calls `f` on each element of the tensor in row-major order
+    fn try_for_each_element<E>(&self, f: &mut impl FnMut(&Self::Element) -> Result<(), E>) -> Result<(), E>;
+
+    /// Builds the tensor from its elements in row-major order
+    fn from_elements(elements: &mut impl Iterator<Item = Self::Element>) -> Option<Self>;
+}
+
+macro_rules! impl_tensor_element {
+    ($physical_type:ty) => {
+        impl ArrowTensorShape for $physical_type {
+            type Element = Self;
+
+            #[inline]
+            fn shape() -> Vec<usize> {
+                vec![]
+            }
+
+            #[inline]
+            fn try_for_each_element<E>(&self, f: &mut impl FnMut(&Self) -> Result<(), E>) -> Result<(), E> {
+                f(self)
+            }
+
+            #[inline]
+            fn from_elements(elements: &mut impl Iterator<Item = Self>) -> Option<Self> {
+                elements.next()
+            }
+        }
+    };
+}
+
+impl_tensor_element!(u8);
+impl_tensor_element!(u16);
+impl_tensor_element!(u32);
+impl_tensor_element!(u64);
+impl_tensor_element!(i8);
+impl_tensor_element!(i16);
+impl_tensor_element!(i32);
+impl_tensor_element!(i64);
+impl_tensor_element!(half::f16);
+impl_tensor_element!(f32);
+impl_tensor_element!(f64);
+impl_tensor_element!(bool);
+
+impl<T, const SIZE: usize> ArrowTensorShape for [T; SIZE]
+where
+    T: ArrowTensorShape,
+{
+    type Element = <T as ArrowTensorShape>::Element;
+
+    #[inline]
+    fn shape() -> Vec<usize> {
+        let mut shape = vec![SIZE];
+        shape.extend(<T as ArrowTensorShape>::shape());
+        shape
+    }
+
+    #[inline]
+    fn try_for_each_element<E>(&self, f: &mut impl FnMut(&Self::Element) -> Result<(), E>) -> Result<(), E> {
+        self.iter().try_for_each(|i| i.try_for_each_element(f))
+    }
+
+    #[inline]
+    fn from_elements(elements: &mut impl Iterator<Item = Self::Element>) -> Option<Self> {
+        let items = (0..SIZE)
+            .map(|_| <T as ArrowTensorShape>::from_elements(elements))
+            .collect::<Option<Vec<T>>>()?;
+        items.try_into().ok()
+    }
+}
+
+/// Represents a `FixedSizeList` Arrow type holding a tensor of fixed shape, tagged with the
+/// `arrow.fixed_shape_tensor` canonical extension type.
+///
+/// The shape is taken from the rust array type, for example `FixedShapeTensor<[[f32; 3]; 2]>` stores
+/// `[[f32; 3]; 2]` values as a `FixedSizeList` of 6 `f32` elements with shape `[2, 3]`.
+pub struct FixedShapeTensor<A> {
+    d: std::marker::PhantomData<A>,
+}
+
+impl<A> FixedShapeTensor<A>
+where
+    A: ArrowTensorShape,
+{
+    /// The number of elements in the tensor
+    pub fn num_elements() -> usize {
+        <A as ArrowTensorShape>::shape().iter().product()
+    }
+}
+
+impl<A> ArrowField for FixedShapeTensor<A>
+where
+    A: ArrowTensorShape,
+{
+    type Type = A;
+
+    #[inline]
+    fn data_type() -> DataType {
+        let field = <<A as ArrowTensorShape>::Element as ArrowField>::field(DEFAULT_FIELD_NAME);
+        DataType::FixedSizeList(Arc::new(field), Self::num_elements() as i32)
+    }
+
+    #[inline]
+    fn field(name: &str) -> Field {
+        Field::new(name, Self::data_type(), Self::is_nullable())
+            .with_metadata(fixed_shape_tensor_metadata(&<A as ArrowTensorShape>::shape()))
+    }
+}
+
 impl<T, const SIZE: usize> ArrowField for [T; SIZE]
 where
     T: ArrowField + ArrowEnableVecForType,
@@ -321,23 +1259,143 @@ where
     }
 }
 
+// Helper to build the `Map` data type for the given key and value fields.
+pub(crate) fn map_data_type<K: ArrowField, V: ArrowField>(keys_sorted: bool) -> DataType {
+    let entries = DataType::Struct(
+        vec![
+            <K as ArrowField>::field(MAP_KEYS_FIELD_NAME),
+            <V as ArrowField>::field(MAP_VALUES_FIELD_NAME),
+        ]
+        .into(),
+    );
+    DataType::Map(
+        Arc::new(Field::new(MAP_ENTRIES_FIELD_NAME, entries, false)),
+        keys_sorted,
+    )
+}
+
+// Blanket implementation for HashMap.
+impl<K, V, S> ArrowField for HashMap<K, V, S>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = HashMap<<K as ArrowField>::Type, <V as ArrowField>::Type, S>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        map_data_type::<K, V>(false)
+    }
+}
+
+// Blanket implementation for BTreeMap. Keys are emitted in order, so the map is flagged as sorted.
+impl<K, V> ArrowField for BTreeMap<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = BTreeMap<<K as ArrowField>::Type, <V as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> DataType {
+        map_data_type::<K, V>(true)
+    }
+}
+
+// Macro to implement tuples as a struct with positional field names ("0", "1", ...).
+macro_rules! impl_tuple_type {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name),+> ArrowField for ($($name,)+)
+        where
+            $($name: ArrowField,)+
+        {
+            type Type = ($(<$name as ArrowField>::Type,)+);
+
+            #[inline]
+            fn data_type() -> DataType {
+                DataType::Struct(Fields::from(vec![
+                    $(<$name as ArrowField>::field(stringify!($index)),)+
+                ]))
+            }
+        }
+
+        impl<$($name),+> ArrowEnableVecForType for ($($name,)+) where $($name: ArrowField,)+ {}
+    };
+}
+
+impl_tuple_type!(A 0);
+impl_tuple_type!(A 0, B 1);
+impl_tuple_type!(A 0, B 1, C 2);
+impl_tuple_type!(A 0, B 1, C 2, D 3);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_tuple_type!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
 arrow_enable_vec_for_type!(String);
 arrow_enable_vec_for_type!(LargeString);
+arrow_enable_vec_for_type!(Utf8View);
+arrow_enable_vec_for_type!(std::ffi::OsString);
+arrow_enable_vec_for_type!(OsStringUtf8);
+arrow_enable_vec_for_type!(OsStringLossy);
+arrow_enable_vec_for_type!(std::ffi::CString);
 arrow_enable_vec_for_type!(bool);
+arrow_enable_vec_for_type!(());
+impl<T: ?Sized> ArrowEnableVecForType for std::marker::PhantomData<T> {}
 arrow_enable_vec_for_type!(NaiveDateTime);
 arrow_enable_vec_for_type!(NaiveDate);
 arrow_enable_vec_for_type!(Vec<u8>);
 arrow_enable_vec_for_type!(Buffer);
 arrow_enable_vec_for_type!(ScalarBuffer<u8>);
 arrow_enable_vec_for_type!(LargeBinary);
+arrow_enable_vec_for_type!(BinaryView);
+arrow_enable_vec_for_type!(ByteList);
+arrow_enable_vec_for_type!(LargeBuffer);
+impl<const SIZE: usize> ArrowEnableVecForType for [u8; SIZE] {}
+impl<T, const SIZE: usize> ArrowEnableVecForType for [T; SIZE] where T: ArrowField + ArrowEnableVecForType {}
 impl<const SIZE: i32> ArrowEnableVecForType for FixedSizeBinary<SIZE> {}
 impl<const PRECISION: u8, const SCALE: i8> ArrowEnableVecForType for I128<PRECISION, SCALE> {}
 
 // Blanket implementation for Vec<Option<T>> if vectors are enabled for T
 impl<T> ArrowEnableVecForType for Option<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for Box<T> where T: ArrowField + ArrowEnableVecForType {}
 
 // Blanket implementation for Vec<Vec<T>> and Vec<Buffer<T>> if vectors or buffers are enabled for T
 impl<T> ArrowEnableVecForType for Vec<T> where T: ArrowField + ArrowEnableVecForType {}
 impl<T> ArrowEnableVecForType for ScalarBuffer<T> where T: ArrowField + ArrowEnableVecForType + ArrowNativeType {}
+impl<T> ArrowEnableVecForType for VecDeque<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T, S> ArrowEnableVecForType for HashSet<T, S> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for BTreeSet<T> where T: ArrowField + ArrowEnableVecForType {}
 impl<T> ArrowEnableVecForType for LargeVec<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for ListView<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for LargeListView<T> where T: ArrowField + ArrowEnableVecForType {}
 impl<T, const SIZE: i32> ArrowEnableVecForType for FixedSizeVec<T, SIZE> where T: ArrowField + ArrowEnableVecForType {}
+impl<K, V> ArrowEnableVecForType for Dictionary<K, V>
+where
+    K: ArrowDictionaryKey,
+    V: ArrowField,
+{
+}
+impl<A> ArrowEnableVecForType for FixedShapeTensor<A> where A: ArrowTensorShape {}
+impl<R, V> ArrowEnableVecForType for RunEnd<R, V>
+where
+    R: ArrowRunEndIndex,
+    V: ArrowField,
+{
+}
+impl<K, V, S> ArrowEnableVecForType for HashMap<K, V, S>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
+impl<K, V> ArrowEnableVecForType for BTreeMap<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
@@ -0,0 +1,132 @@
+use std::borrow::Borrow;
+use std::fmt;
+
+use arrow::array::ArrayBuilder;
+use arrow::record_batch::RecordBatch;
+
+use super::{row_error, struct_record_batch, ArrowSerialize, SerializeOptions};
+use crate::field::ArrowField;
+
+/// Serializes structs pushed one at a time into `RecordBatch`es with a column per field, for producers that receive
+/// rows incrementally rather than as a collection.
+///
+/// `T` is the Arrow type the rows are serialized as, which is usually the struct itself.
+pub struct TypedBatchSerializer<T: ArrowSerialize> {
+    array: T::ArrayBuilderType,
+    len: usize,
+    options: SerializeOptions,
+}
+
+impl<T: ArrowSerialize> TypedBatchSerializer<T> {
+    /// Creates a new `TypedBatchSerializer` without rows
+    pub fn new() -> Self {
+        Self::with_options(SerializeOptions::default())
+    }
+
+    /// Creates a new `TypedBatchSerializer` whose builders are pre-sized with the capacity hints of `options` for
+    /// every batch, usually with the number of rows of a full batch.
+    pub fn with_options(options: SerializeOptions) -> Self {
+        Self {
+            array: <T as ArrowSerialize>::new_array_with_capacity(&options),
+            len: 0,
+            options,
+        }
+    }
+
+    /// Appends a row to the current batch. Errors name the index of the row in the batch.
+    ///
+    /// A row that fails to serialize may have been partially appended, so the error also discards the rows pushed
+    /// since the last flush.
+    pub fn push(&mut self, value: &<T as ArrowField>::Type) -> arrow::error::Result<()> {
+        self.push_row(value, self.len)
+    }
+
+    /// Appends a row to the current batch, naming it `row` in errors.
+    pub(super) fn push_row(&mut self, value: &<T as ArrowField>::Type, row: usize) -> arrow::error::Result<()> {
+        if let Err(e) = <T as ArrowSerialize>::arrow_serialize(value, &mut self.array) {
+            self.reset();
+            return Err(row_error(row, e));
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// The number of rows pushed since the last flush
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no rows were pushed since the last flush
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discards the rows pushed since the last flush, so that a long-lived serializer can drop a partial batch
+    /// without being recreated. This also discards shared dictionaries.
+    pub fn reset(&mut self) {
+        // a row that failed to serialize may have left the columns of a struct with different lengths, which can't
+        // be finished
+        self.array = <T as ArrowSerialize>::new_array_with_capacity(&self.options);
+        self.len = 0;
+    }
+
+    /// Returns the pushed rows as a `RecordBatch` with a column per field, like
+    /// [`super::TryIntoRecordBatch::try_into_record_batch`], and starts a new batch. Returns an error if `T` isn't a
+    /// struct or if a struct is null.
+    pub fn flush(&mut self) -> arrow::error::Result<RecordBatch> {
+        self.len = 0;
+        if self.options.shared_dictionaries {
+            // the builders are finished in place to keep their dictionaries for the next batch
+            return struct_record_batch::<T>(self.array.finish());
+        }
+        // finished builders are left empty, so the next batch gets new pre-sized builders
+        let mut array = std::mem::replace(
+            &mut self.array,
+            <T as ArrowSerialize>::new_array_with_capacity(&self.options),
+        );
+        struct_record_batch::<T>(array.finish())
+    }
+}
+
+impl<T: ArrowSerialize> Default for TypedBatchSerializer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends the rows of an iterator of rows or references to rows with [`TypedBatchSerializer::push`].
+///
+/// # Panics
+///
+/// Panics if a row fails to serialize. Use [`TypedBatchSerializer::push`] to handle the error instead.
+impl<T: ArrowSerialize, R: Borrow<<T as ArrowField>::Type>> Extend<R> for TypedBatchSerializer<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for row in iter {
+            if let Err(e) = self.push(row.borrow()) {
+                panic!("{e}");
+            }
+        }
+    }
+}
+
+/// Collects the rows of an iterator into a new batch, which is returned by [`TypedBatchSerializer::flush`].
+///
+/// # Panics
+///
+/// Panics if a row fails to serialize, like [`Extend`].
+impl<T: ArrowSerialize, R: Borrow<<T as ArrowField>::Type>> FromIterator<R> for TypedBatchSerializer<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut serializer = Self::new();
+        serializer.extend(iter);
+        serializer
+    }
+}
+
+impl<T: ArrowSerialize> fmt::Debug for TypedBatchSerializer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedBatchSerializer")
+            .field("data_type", &<T as ArrowField>::data_type())
+            .field("len", &self.len)
+            .finish()
+    }
+}
@@ -0,0 +1,139 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use futures_sink::Sink;
+
+use super::{ArrowSerialize, TypedBatchSerializer, DEFAULT_MAX_ROWS_PER_BATCH};
+use crate::field::ArrowField;
+
+/// A [`Sink`] of rows that serializes them into `RecordBatch`es with a column per field, and sends a batch to the
+/// wrapped sink of batches every `max_rows_per_batch` rows, for async ingestion services.
+///
+/// The rows can be sent by value or by reference. The wrapped sink can be an async writer of batches, or the sender
+/// of a channel whose receiver yields the batches, with its errors mapped to `ArrowError` by `sink_map_err`.
+/// Flushing or closing the sink sends the pending rows as a smaller batch.
+pub struct BatchSink<T: ArrowSerialize, S> {
+    serializer: TypedBatchSerializer<T>,
+    sink: S,
+    max_rows_per_batch: usize,
+    rows: usize,
+    // a batch waiting for the wrapped sink to be ready
+    pending: Option<RecordBatch>,
+}
+
+// the fields are never pinned, so the sink can be moved when only the wrapped sink is `Unpin`
+impl<T: ArrowSerialize, S: Unpin> Unpin for BatchSink<T, S> {}
+
+impl<T: ArrowSerialize, S> BatchSink<T, S> {
+    /// Creates a new `BatchSink` sending batches of [`DEFAULT_MAX_ROWS_PER_BATCH`] rows to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            serializer: TypedBatchSerializer::new(),
+            sink,
+            max_rows_per_batch: DEFAULT_MAX_ROWS_PER_BATCH,
+            rows: 0,
+            pending: None,
+        }
+    }
+
+    /// Sets the number of rows after which a batch is sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rows_per_batch` is 0.
+    pub fn with_max_rows_per_batch(mut self, max_rows_per_batch: usize) -> Self {
+        assert!(
+            max_rows_per_batch > 0,
+            "max_rows_per_batch must be greater than 0"
+        );
+        self.max_rows_per_batch = max_rows_per_batch;
+        self
+    }
+
+    /// Returns the wrapped sink
+    pub fn get_ref(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consumes the `BatchSink`, returning the wrapped sink. The rows that weren't sent are lost.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<T, S> BatchSink<T, S>
+where
+    T: ArrowSerialize,
+    S: Sink<RecordBatch, Error = ArrowError> + Unpin,
+{
+    fn poll_send_pending(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), ArrowError>> {
+        if let Some(batch) = self.pending.take() {
+            match Pin::new(&mut self.sink).poll_ready(cx) {
+                Poll::Ready(Ok(())) => Pin::new(&mut self.sink).start_send(batch)?,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => {
+                    self.pending = Some(batch);
+                    return Poll::Pending;
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T, S, R> Sink<R> for BatchSink<T, S>
+where
+    T: ArrowSerialize,
+    S: Sink<RecordBatch, Error = ArrowError> + Unpin,
+    R: Borrow<<T as ArrowField>::Type>,
+{
+    type Error = ArrowError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_send_pending(cx)
+    }
+
+    /// Serializes a row. Errors name the index of the row among all the rows sent, and discard the rows sent since
+    /// the last batch.
+    fn start_send(self: Pin<&mut Self>, row: R) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        this.serializer.push_row(row.borrow(), this.rows)?;
+        this.rows += 1;
+        if this.serializer.len() >= this.max_rows_per_batch {
+            this.pending = Some(this.serializer.flush()?);
+        }
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        if this.pending.is_none() && !this.serializer.is_empty() {
+            this.pending = Some(this.serializer.flush()?);
+        }
+        match this.poll_send_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.sink).poll_flush(cx),
+            poll => poll,
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match <Self as Sink<R>>::poll_flush(self.as_mut(), cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut self.get_mut().sink).poll_close(cx),
+            poll => poll,
+        }
+    }
+}
+
+impl<T: ArrowSerialize, S> fmt::Debug for BatchSink<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchSink")
+            .field("serializer", &self.serializer)
+            .field("max_rows_per_batch", &self.max_rows_per_batch)
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
@@ -0,0 +1,55 @@
+use arrow::array::{ArrayBuilder, GenericByteBuilder, GenericListBuilder, MapBuilder, OffsetSizeTrait};
+use arrow::datatypes::{ArrowNativeType, ByteArrayType};
+use arrow::error::ArrowError;
+
+/// Implemented by the builders of arrays with offsets, to return an error rather than panic when the values of an
+/// array no longer fit in its offsets, such as more than 2GB of strings in a `Utf8` array.
+pub trait CheckOffsets {
+    /// Returns an error if appending `additional` bytes or values overflows the offsets of the array
+    fn check_offsets(&self, additional: usize) -> arrow::error::Result<()>;
+}
+
+impl<T: ByteArrayType> CheckOffsets for GenericByteBuilder<T> {
+    #[inline]
+    fn check_offsets(&self, additional: usize) -> arrow::error::Result<()> {
+        let len = self.values_slice().len();
+        match len.checked_add(additional).and_then(T::Offset::from_usize) {
+            Some(_) => Ok(()),
+            None => Err(ArrowError::InvalidArgumentError(format!(
+                "Appending {additional} bytes to the {len} bytes of a {} array overflows its offsets, use a large \
+                 type such as LargeString or LargeBinary",
+                T::DATA_TYPE
+            ))),
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait, B: ArrayBuilder> CheckOffsets for GenericListBuilder<O, B> {
+    #[inline]
+    fn check_offsets(&self, additional: usize) -> arrow::error::Result<()> {
+        let len = self.values_ref().len();
+        match len.checked_add(additional).and_then(O::from_usize) {
+            Some(_) => Ok(()),
+            None => Err(ArrowError::InvalidArgumentError(format!(
+                "Appending {additional} values to the {len} values of a {} array overflows its offsets, use a large \
+                 type such as LargeVec",
+                if O::IS_LARGE { "LargeList" } else { "List" }
+            ))),
+        }
+    }
+}
+
+/// Returns an error if appending `additional` entries overflows the offsets of a map array. Unlike lists, maps
+/// always have i32 offsets. This takes the builder mutably since `MapBuilder` only exposes its keys mutably.
+pub fn check_map_offsets<K: ArrayBuilder, V: ArrayBuilder>(
+    array: &mut MapBuilder<K, V>,
+    additional: usize,
+) -> arrow::error::Result<()> {
+    let len = array.keys().len();
+    match len.checked_add(additional).and_then(i32::from_usize) {
+        Some(_) => Ok(()),
+        None => Err(ArrowError::InvalidArgumentError(format!(
+            "Appending {additional} entries to the {len} entries of a Map array overflows its offsets"
+        ))),
+    }
+}
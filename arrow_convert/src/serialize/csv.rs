@@ -0,0 +1,23 @@
+use std::borrow::Borrow;
+use std::io::Write;
+
+use arrow::csv::WriterBuilder;
+
+use super::{ArrowSerialize, TypedWriter};
+use crate::field::ArrowField;
+
+/// Writes the structs of `rows` to `writer` as CSV, with a column per field, for spreadsheet users. The header,
+/// delimiter and formats of the values are set by `builder`, such as
+/// `WriterBuilder::new().with_header(false).with_delimiter(b';')`. Returns an error if a field is a nested type,
+/// which CSV can't represent. `T` is the Arrow type the rows are serialized as, which is usually the struct itself.
+pub fn write_csv<T, W, I>(writer: W, rows: I, builder: WriterBuilder) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let mut writer = TypedWriter::<T, _>::new(builder.build(writer));
+    writer.write_all(rows)?;
+    writer.close()
+}
@@ -0,0 +1,117 @@
+use std::any::Any;
+use std::fmt;
+
+use arrow::array::{Array, ArrayBuilder, ArrayRef, AsArray, GenericByteDictionaryBuilder, PrimitiveDictionaryBuilder};
+use arrow::datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType, ByteArrayType};
+
+use super::{PushNull, SerializeOptions};
+
+/// Builder for an Arrow `Dictionary` array.
+///
+/// This wraps one of the arrow dictionary builders, since not all of them implement `Debug`.
+pub struct DictionaryBuilder<B: ArrayBuilder> {
+    inner: B,
+    shared: bool,
+    // the number of keys appended to `inner` to seed it with the values of the previous dictionary
+    seeded: usize,
+}
+
+impl<B: ArrayBuilder> DictionaryBuilder<B> {
+    /// Creates a new `DictionaryBuilder` wrapping the provided dictionary builder
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            shared: false,
+            seeded: 0,
+        }
+    }
+
+    /// Creates a new `DictionaryBuilder` wrapping the provided dictionary builder, which keeps its dictionary when
+    /// finished if [`SerializeOptions::shared_dictionaries`] is set.
+    pub fn with_options(inner: B, options: &SerializeOptions) -> Self {
+        Self {
+            shared: options.shared_dictionaries,
+            ..Self::new(inner)
+        }
+    }
+
+    /// Returns the wrapped dictionary builder
+    pub fn inner(&mut self) -> &mut B {
+        &mut self.inner
+    }
+}
+
+impl<B: ArrayBuilder> fmt::Debug for DictionaryBuilder<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DictionaryBuilder")
+            .field("len", &(self.inner.len() - self.seeded))
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
+impl<B: SeedDictionary> ArrayBuilder for DictionaryBuilder<B> {
+    fn len(&self) -> usize {
+        self.inner.len() - self.seeded
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        let array = self.inner.finish();
+        let seeded = std::mem::take(&mut self.seeded);
+        if self.shared {
+            // the values of the finished dictionary are appended again in the same order, so that they keep their
+            // keys in the next array
+            self.seeded = self.inner.seed(array.as_any_dictionary().values().as_ref());
+        }
+        array.slice(seeded, array.len() - seeded)
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        let array = self.inner.finish_cloned();
+        array.slice(self.seeded, array.len() - self.seeded)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<B: ArrayBuilder + PushNull> PushNull for DictionaryBuilder<B> {
+    fn push_null(&mut self) {
+        self.inner.push_null();
+    }
+}
+
+/// Implemented by the arrow dictionary builders that can be seeded with the values of a finished dictionary.
+pub trait SeedDictionary: ArrayBuilder {
+    /// Appends each of the `values` of a dictionary finished by this builder, returning the number of keys appended
+    fn seed(&mut self, values: &dyn Array) -> usize;
+}
+
+impl<K: ArrowDictionaryKeyType, T: ByteArrayType> SeedDictionary for GenericByteDictionaryBuilder<K, T> {
+    fn seed(&mut self, values: &dyn Array) -> usize {
+        for v in values.as_bytes::<T>().iter().flatten() {
+            // the values had keys in the finished dictionary, so they can't overflow them
+            self.append(v).expect("dictionary values fit in their keys");
+        }
+        self.len()
+    }
+}
+
+impl<K: ArrowDictionaryKeyType, V: ArrowPrimitiveType> SeedDictionary for PrimitiveDictionaryBuilder<K, V> {
+    fn seed(&mut self, values: &dyn Array) -> usize {
+        for v in values.as_primitive::<V>().iter().flatten() {
+            // the values had keys in the finished dictionary, so they can't overflow them
+            self.append(v).expect("dictionary values fit in their keys");
+        }
+        self.len()
+    }
+}
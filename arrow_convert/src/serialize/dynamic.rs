@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use arrow::array::{ArrayBuilder, BooleanBuilder, LargeListBuilder, ListBuilder, PrimitiveBuilder, StructBuilder};
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, Date32Type, Date64Type, Field, SchemaRef, TimeUnit, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
+};
+use arrow::error::ArrowError;
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+
+use super::{row_error, ArrowSerialize, CheckOffsets, PushNull};
+use crate::field::{LargeBinary, LargeString};
+
+/// A value of a row whose schema is only known at runtime, serialized by a [`DynamicBatchSerializer`].
+///
+/// Values are converted to the type of their column: integers to any integer, float, date or timestamp column that
+/// can hold them, floats to float columns, strings and binaries to columns with small or large offsets, lists to
+/// list columns and structs, whose values are in the order of the fields, to struct columns.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrowValue {
+    /// A null, for nullable columns
+    Null,
+    /// A `Boolean` value
+    Boolean(bool),
+    /// A signed integer
+    Int(i64),
+    /// An unsigned integer
+    UInt(u64),
+    /// A floating point number
+    Float(f64),
+    /// A `Utf8` or `LargeUtf8` value
+    Utf8(String),
+    /// A `Binary` or `LargeBinary` value
+    Binary(Vec<u8>),
+    /// A `List` or `LargeList` value
+    List(Vec<ArrowValue>),
+    /// A `Struct` value, with a value per field
+    Struct(Vec<ArrowValue>),
+}
+
+/// A row whose values are looked up by the column of a runtime schema, such as a `Vec<ArrowValue>` with a value per
+/// column or a `HashMap<String, ArrowValue>` keyed by column name.
+pub trait DynamicRow {
+    /// The value of the column `field` at position `index` of the schema, or `None` if the row has no value for it
+    fn value(&self, index: usize, field: &Field) -> Option<&ArrowValue>;
+
+    /// The number of values of the row, to detect values that don't belong to any column
+    fn num_values(&self) -> usize;
+}
+
+impl DynamicRow for [ArrowValue] {
+    fn value(&self, index: usize, _field: &Field) -> Option<&ArrowValue> {
+        self.get(index)
+    }
+
+    fn num_values(&self) -> usize {
+        self.len()
+    }
+}
+
+impl DynamicRow for Vec<ArrowValue> {
+    fn value(&self, index: usize, _field: &Field) -> Option<&ArrowValue> {
+        self.get(index)
+    }
+
+    fn num_values(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<S: std::hash::BuildHasher> DynamicRow for HashMap<String, ArrowValue, S> {
+    fn value(&self, _index: usize, field: &Field) -> Option<&ArrowValue> {
+        self.get(field.name())
+    }
+
+    fn num_values(&self) -> usize {
+        self.len()
+    }
+}
+
+impl DynamicRow for BTreeMap<String, ArrowValue> {
+    fn value(&self, _index: usize, field: &Field) -> Option<&ArrowValue> {
+        self.get(field.name())
+    }
+
+    fn num_values(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Serializes rows whose schema is only known at runtime into `RecordBatch`es of that schema, such as the rows of
+/// user-defined tables, using the same builders as [`super::TypedBatchSerializer`].
+///
+/// Columns can be booleans, integers, floats, dates, timestamps, strings and binaries with small or large offsets,
+/// and lists and structs of those.
+pub struct DynamicBatchSerializer {
+    schema: SchemaRef,
+    columns: Vec<Box<dyn ArrayBuilder>>,
+    len: usize,
+}
+
+impl DynamicBatchSerializer {
+    /// Creates a new `DynamicBatchSerializer` for `schema`. Returns an error if a column has an unsupported type.
+    pub fn try_new(schema: SchemaRef) -> arrow::error::Result<Self> {
+        let columns = new_columns(&schema)?;
+        Ok(Self {
+            schema,
+            columns,
+            len: 0,
+        })
+    }
+
+    /// The schema of the batches
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Appends a row to the current batch. Missing values are null, and values that don't match their column are
+    /// errors naming the index of the row in the batch.
+    ///
+    /// A row that fails to serialize may have been partially appended, so the error also discards the rows pushed
+    /// since the last flush.
+    pub fn push<R: DynamicRow + ?Sized>(&mut self, row: &R) -> arrow::error::Result<()> {
+        if let Err(e) = self.append_row(row) {
+            let row = self.len;
+            self.reset();
+            return Err(row_error(row, e));
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn append_row<R: DynamicRow + ?Sized>(&mut self, row: &R) -> arrow::error::Result<()> {
+        let mut num_values = 0;
+        for (index, (field, column)) in self.schema.fields().iter().zip(&mut self.columns).enumerate() {
+            let value = row.value(index, field);
+            num_values += value.is_some() as usize;
+            append(column.as_mut(), field, value)?;
+        }
+        if num_values != row.num_values() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The row has {} values but only {num_values} of them match a column of the schema",
+                row.num_values()
+            )));
+        }
+        Ok(())
+    }
+
+    /// The number of rows pushed since the last flush
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no rows were pushed since the last flush
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discards the rows pushed since the last flush.
+    pub fn reset(&mut self) {
+        // the schema was already checked when the serializer was created
+        self.columns = new_columns(&self.schema).expect("the columns of the schema are supported");
+        self.len = 0;
+    }
+
+    /// Returns the pushed rows as a `RecordBatch` with the schema of the serializer, and starts a new batch.
+    pub fn flush(&mut self) -> arrow::error::Result<RecordBatch> {
+        let options = RecordBatchOptions::new().with_row_count(Some(self.len));
+        self.len = 0;
+        let columns = self.columns.iter_mut().map(|column| column.finish()).collect();
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)
+    }
+}
+
+impl fmt::Debug for DynamicBatchSerializer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicBatchSerializer")
+            .field("schema", &self.schema)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// Serializes `rows` into a `RecordBatch` with the runtime `schema`, like [`DynamicBatchSerializer`]. Errors name
+/// the failing row.
+pub fn serialize_dynamic<'a, R, I>(rows: I, schema: SchemaRef) -> arrow::error::Result<RecordBatch>
+where
+    R: DynamicRow + ?Sized + 'a,
+    I: IntoIterator<Item = &'a R>,
+{
+    let mut serializer = DynamicBatchSerializer::try_new(schema)?;
+    for row in rows {
+        serializer.push(row)?;
+    }
+    serializer.flush()
+}
+
+fn new_columns(schema: &SchemaRef) -> arrow::error::Result<Vec<Box<dyn ArrayBuilder>>> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| new_builder(field.data_type()))
+        .collect()
+}
+
+fn new_builder(data_type: &DataType) -> arrow::error::Result<Box<dyn ArrayBuilder>> {
+    Ok(match data_type {
+        DataType::Boolean => Box::new(<bool as ArrowSerialize>::new_array()),
+        DataType::Int8 => Box::new(<i8 as ArrowSerialize>::new_array()),
+        DataType::Int16 => Box::new(<i16 as ArrowSerialize>::new_array()),
+        DataType::Int32 => Box::new(<i32 as ArrowSerialize>::new_array()),
+        DataType::Int64 => Box::new(<i64 as ArrowSerialize>::new_array()),
+        DataType::UInt8 => Box::new(<u8 as ArrowSerialize>::new_array()),
+        DataType::UInt16 => Box::new(<u16 as ArrowSerialize>::new_array()),
+        DataType::UInt32 => Box::new(<u32 as ArrowSerialize>::new_array()),
+        DataType::UInt64 => Box::new(<u64 as ArrowSerialize>::new_array()),
+        DataType::Float32 => Box::new(<f32 as ArrowSerialize>::new_array()),
+        DataType::Float64 => Box::new(<f64 as ArrowSerialize>::new_array()),
+        DataType::Utf8 => Box::new(<String as ArrowSerialize>::new_array()),
+        DataType::LargeUtf8 => Box::new(<LargeString as ArrowSerialize>::new_array()),
+        DataType::Binary => Box::new(<Vec<u8> as ArrowSerialize>::new_array()),
+        DataType::LargeBinary => Box::new(<LargeBinary as ArrowSerialize>::new_array()),
+        DataType::Date32 => Box::new(PrimitiveBuilder::<Date32Type>::new()),
+        DataType::Date64 => Box::new(PrimitiveBuilder::<Date64Type>::new()),
+        DataType::Timestamp(unit, _) => match unit {
+            TimeUnit::Second => {
+                Box::new(PrimitiveBuilder::<TimestampSecondType>::new().with_data_type(data_type.clone()))
+            }
+            TimeUnit::Millisecond => {
+                Box::new(PrimitiveBuilder::<TimestampMillisecondType>::new().with_data_type(data_type.clone()))
+            }
+            TimeUnit::Microsecond => {
+                Box::new(PrimitiveBuilder::<TimestampMicrosecondType>::new().with_data_type(data_type.clone()))
+            }
+            TimeUnit::Nanosecond => {
+                Box::new(PrimitiveBuilder::<TimestampNanosecondType>::new().with_data_type(data_type.clone()))
+            }
+        },
+        DataType::List(field) => Box::new(ListBuilder::new(new_builder(field.data_type())?).with_field(field.clone())),
+        DataType::LargeList(field) => {
+            Box::new(LargeListBuilder::new(new_builder(field.data_type())?).with_field(field.clone()))
+        }
+        DataType::Struct(fields) => {
+            let children = fields
+                .iter()
+                .map(|field| new_builder(field.data_type()))
+                .collect::<arrow::error::Result<_>>()?;
+            Box::new(StructBuilder::new(fields.clone(), children))
+        }
+        data_type => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Dynamic serialization of {data_type} columns"
+            )))
+        }
+    })
+}
+
+// The builders are created from the same data types they are appended with.
+fn downcast<T: 'static>(builder: &mut dyn ArrayBuilder) -> &mut T {
+    builder
+        .as_any_mut()
+        .downcast_mut()
+        .expect("the builder matches the data type")
+}
+
+fn serialize<T: ArrowSerialize + 'static>(
+    builder: &mut dyn ArrayBuilder,
+    v: &<T as crate::field::ArrowField>::Type,
+) -> arrow::error::Result<()> {
+    <T as ArrowSerialize>::arrow_serialize(v, downcast(builder))
+}
+
+fn append_primitive<T: ArrowPrimitiveType>(builder: &mut dyn ArrayBuilder, v: T::Native) -> arrow::error::Result<()> {
+    downcast::<PrimitiveBuilder<T>>(builder).append_value(v);
+    Ok(())
+}
+
+fn mismatch(field: &Field, value: &ArrowValue) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Value {value:?} of column `{}` doesn't fit its type {}",
+        field.name(),
+        field.data_type()
+    ))
+}
+
+fn convert<T: TryFrom<V>, V: Copy>(field: &Field, value: &ArrowValue, v: V) -> arrow::error::Result<T> {
+    T::try_from(v).map_err(|_| mismatch(field, value))
+}
+
+fn append(builder: &mut dyn ArrayBuilder, field: &Field, value: Option<&ArrowValue>) -> arrow::error::Result<()> {
+    let value = match value {
+        None | Some(ArrowValue::Null) if field.is_nullable() => {
+            append_null(builder, field.data_type());
+            return Ok(());
+        }
+        None | Some(ArrowValue::Null) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Column `{}` isn't nullable but has no value",
+                field.name()
+            )))
+        }
+        Some(value) => value,
+    };
+    match (field.data_type(), value) {
+        (DataType::Boolean, ArrowValue::Boolean(v)) => serialize::<bool>(builder, v),
+        (DataType::Int8, ArrowValue::Int(v)) => serialize::<i8>(builder, &convert(field, value, *v)?),
+        (DataType::Int16, ArrowValue::Int(v)) => serialize::<i16>(builder, &convert(field, value, *v)?),
+        (DataType::Int32, ArrowValue::Int(v)) => serialize::<i32>(builder, &convert(field, value, *v)?),
+        (DataType::Int64, ArrowValue::Int(v)) => serialize::<i64>(builder, v),
+        (DataType::UInt8, ArrowValue::Int(v)) => serialize::<u8>(builder, &convert(field, value, *v)?),
+        (DataType::UInt16, ArrowValue::Int(v)) => serialize::<u16>(builder, &convert(field, value, *v)?),
+        (DataType::UInt32, ArrowValue::Int(v)) => serialize::<u32>(builder, &convert(field, value, *v)?),
+        (DataType::UInt64, ArrowValue::Int(v)) => serialize::<u64>(builder, &convert(field, value, *v)?),
+        (DataType::Int8, ArrowValue::UInt(v)) => serialize::<i8>(builder, &convert(field, value, *v)?),
+        (DataType::Int16, ArrowValue::UInt(v)) => serialize::<i16>(builder, &convert(field, value, *v)?),
+        (DataType::Int32, ArrowValue::UInt(v)) => serialize::<i32>(builder, &convert(field, value, *v)?),
+        (DataType::Int64, ArrowValue::UInt(v)) => serialize::<i64>(builder, &convert(field, value, *v)?),
+        (DataType::UInt8, ArrowValue::UInt(v)) => serialize::<u8>(builder, &convert(field, value, *v)?),
+        (DataType::UInt16, ArrowValue::UInt(v)) => serialize::<u16>(builder, &convert(field, value, *v)?),
+        (DataType::UInt32, ArrowValue::UInt(v)) => serialize::<u32>(builder, &convert(field, value, *v)?),
+        (DataType::UInt64, ArrowValue::UInt(v)) => serialize::<u64>(builder, v),
+        (DataType::Float32, ArrowValue::Float(v)) => serialize::<f32>(builder, &(*v as f32)),
+        (DataType::Float64, ArrowValue::Float(v)) => serialize::<f64>(builder, v),
+        (DataType::Float32, ArrowValue::Int(v)) => serialize::<f32>(builder, &(*v as f32)),
+        (DataType::Float64, ArrowValue::Int(v)) => serialize::<f64>(builder, &(*v as f64)),
+        (DataType::Float32, ArrowValue::UInt(v)) => serialize::<f32>(builder, &(*v as f32)),
+        (DataType::Float64, ArrowValue::UInt(v)) => serialize::<f64>(builder, &(*v as f64)),
+        (DataType::Utf8, ArrowValue::Utf8(v)) => serialize::<String>(builder, v),
+        (DataType::LargeUtf8, ArrowValue::Utf8(v)) => serialize::<LargeString>(builder, v),
+        (DataType::Binary, ArrowValue::Binary(v)) => serialize::<Vec<u8>>(builder, v),
+        (DataType::LargeBinary, ArrowValue::Binary(v)) => serialize::<LargeBinary>(builder, v),
+        (DataType::Date32, ArrowValue::Int(v)) => append_primitive::<Date32Type>(builder, convert(field, value, *v)?),
+        (DataType::Date64, ArrowValue::Int(v)) => append_primitive::<Date64Type>(builder, *v),
+        (DataType::Timestamp(unit, _), ArrowValue::Int(v)) => match unit {
+            TimeUnit::Second => append_primitive::<TimestampSecondType>(builder, *v),
+            TimeUnit::Millisecond => append_primitive::<TimestampMillisecondType>(builder, *v),
+            TimeUnit::Microsecond => append_primitive::<TimestampMicrosecondType>(builder, *v),
+            TimeUnit::Nanosecond => append_primitive::<TimestampNanosecondType>(builder, *v),
+        },
+        (DataType::List(child), ArrowValue::List(values)) => {
+            let list = downcast::<ListBuilder<Box<dyn ArrayBuilder>>>(builder);
+            list.check_offsets(values.len())?;
+            for v in values {
+                append(list.values().as_mut(), child, Some(v))?;
+            }
+            list.append(true);
+            Ok(())
+        }
+        (DataType::LargeList(child), ArrowValue::List(values)) => {
+            let list = downcast::<LargeListBuilder<Box<dyn ArrayBuilder>>>(builder);
+            for v in values {
+                append(list.values().as_mut(), child, Some(v))?;
+            }
+            list.append(true);
+            Ok(())
+        }
+        (DataType::Struct(fields), ArrowValue::Struct(values)) if fields.len() == values.len() => {
+            let array = downcast::<StructBuilder>(builder);
+            for ((field, child), v) in fields.iter().zip(array.field_builders_mut()).zip(values) {
+                append(child.as_mut(), field, Some(v))?;
+            }
+            array.append(true);
+            Ok(())
+        }
+        _ => Err(mismatch(field, value)),
+    }
+}
+
+fn append_null(builder: &mut dyn ArrayBuilder, data_type: &DataType) {
+    match data_type {
+        DataType::Boolean => downcast::<BooleanBuilder>(builder).push_null(),
+        DataType::Int8 => downcast::<<i8 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Int16 => downcast::<<i16 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Int32 => downcast::<<i32 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Int64 => downcast::<<i64 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::UInt8 => downcast::<<u8 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::UInt16 => downcast::<<u16 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::UInt32 => downcast::<<u32 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::UInt64 => downcast::<<u64 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Float32 => downcast::<<f32 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Float64 => downcast::<<f64 as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Utf8 => downcast::<<String as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::LargeUtf8 => downcast::<<LargeString as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Binary => downcast::<<Vec<u8> as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::LargeBinary => downcast::<<LargeBinary as ArrowSerialize>::ArrayBuilderType>(builder).push_null(),
+        DataType::Date32 => downcast::<PrimitiveBuilder<Date32Type>>(builder).push_null(),
+        DataType::Date64 => downcast::<PrimitiveBuilder<Date64Type>>(builder).push_null(),
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            downcast::<PrimitiveBuilder<TimestampSecondType>>(builder).push_null()
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            downcast::<PrimitiveBuilder<TimestampMillisecondType>>(builder).push_null()
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            downcast::<PrimitiveBuilder<TimestampMicrosecondType>>(builder).push_null()
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            downcast::<PrimitiveBuilder<TimestampNanosecondType>>(builder).push_null()
+        }
+        DataType::List(_) => downcast::<ListBuilder<Box<dyn ArrayBuilder>>>(builder).push_null(),
+        DataType::LargeList(_) => downcast::<LargeListBuilder<Box<dyn ArrayBuilder>>>(builder).push_null(),
+        DataType::Struct(fields) => {
+            // the children of a null struct also get a slot
+            let array = downcast::<StructBuilder>(builder);
+            for (field, child) in fields.iter().zip(array.field_builders_mut()) {
+                append_null(child.as_mut(), field.data_type());
+            }
+            array.append_null();
+        }
+        // the builders are only created for the data types above
+        data_type => unreachable!("no builder for {data_type}"),
+    }
+}
@@ -0,0 +1,70 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayBuilder, ArrayRef, NullBufferBuilder, StructArray};
+
+use super::PushNull;
+
+/// Builder for an Arrow `Struct` array without fields, which only holds a length and a validity.
+///
+/// This is the builder of unit structs derived with `#[arrow_field(mode = "struct")]`.
+#[derive(Debug)]
+pub struct EmptyStructBuilder {
+    nulls: NullBufferBuilder,
+}
+
+impl EmptyStructBuilder {
+    /// Creates a new `EmptyStructBuilder`
+    pub fn new() -> Self {
+        Self {
+            nulls: NullBufferBuilder::new(0),
+        }
+    }
+
+    /// Appends a slot, which is null if `is_valid` is false
+    pub fn append(&mut self, is_valid: bool) {
+        self.nulls.append(is_valid);
+    }
+}
+
+impl Default for EmptyStructBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PushNull for EmptyStructBuilder {
+    fn push_null(&mut self) {
+        self.append(false);
+    }
+}
+
+impl ArrayBuilder for EmptyStructBuilder {
+    fn len(&self) -> usize {
+        self.nulls.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        let len = self.nulls.len();
+        Arc::new(StructArray::new_empty_fields(len, self.nulls.finish()))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(StructArray::new_empty_fields(
+            self.nulls.len(),
+            self.nulls.finish_cloned(),
+        ))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
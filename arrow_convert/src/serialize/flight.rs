@@ -0,0 +1,28 @@
+use std::borrow::Borrow;
+use std::sync::Arc;
+
+use arrow_flight::encode::{FlightDataEncoder, FlightDataEncoderBuilder};
+use arrow_flight::error::FlightError;
+
+use super::{serialize_chunked, struct_schema, ArrowSerialize, DEFAULT_MAX_ROWS_PER_BATCH};
+use crate::field::ArrowField;
+
+/// Encodes the structs of `rows` as a stream of `FlightData` messages, such as the response of a Flight `DoGet`
+/// endpoint. The stream starts with the schema of `T`, even without rows, followed by the batches of
+/// [`DEFAULT_MAX_ROWS_PER_BATCH`] rows serialized as they are polled. `T` is the Arrow type the rows are serialized
+/// as, which is usually the struct itself.
+///
+/// The IPC options and the maximum size of the messages follow `builder`, whose schema is replaced by the schema of
+/// `T`. The stream ends after the first row that fails to serialize.
+pub fn flight_data_stream<T, I>(rows: I, builder: FlightDataEncoderBuilder) -> arrow::error::Result<FlightDataEncoder>
+where
+    T: ArrowSerialize + 'static,
+    I: IntoIterator + 'static,
+    I::IntoIter: Send,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let schema = Arc::new(struct_schema::<T>()?);
+    let batches =
+        serialize_chunked::<T, _>(rows, DEFAULT_MAX_ROWS_PER_BATCH).map(|batch| batch.map_err(FlightError::from));
+    Ok(builder.with_schema(schema).build(futures::stream::iter(batches)))
+}
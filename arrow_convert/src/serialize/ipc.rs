@@ -0,0 +1,50 @@
+use std::borrow::Borrow;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use arrow::ipc::writer::{FileWriter, StreamWriter};
+use arrow::record_batch::RecordBatchWriter;
+
+use super::{struct_schema, ArrowSerialize, TypedWriter};
+use crate::field::ArrowField;
+
+/// Writes the structs of `rows` to a new Arrow IPC file at `path`, with a column per field and the schema of `T`,
+/// in batches of [`super::DEFAULT_MAX_ROWS_PER_BATCH`] rows. `T` is the Arrow type the rows are serialized as,
+/// which is usually the struct itself.
+pub fn write_ipc_file<T, I>(path: impl AsRef<Path>, rows: I) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let schema = struct_schema::<T>()?;
+    let writer = FileWriter::try_new(File::create(path)?, &schema)?;
+    write_rows::<T, _, _>(writer, rows)
+}
+
+/// Writes the structs of `rows` to `writer` as an Arrow IPC stream, with a column per field and the schema of `T`,
+/// in batches of [`super::DEFAULT_MAX_ROWS_PER_BATCH`] rows. `T` is the Arrow type the rows are serialized as,
+/// which is usually the struct itself.
+pub fn write_ipc_stream<T, W, I>(writer: W, rows: I) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let schema = struct_schema::<T>()?;
+    write_rows::<T, _, _>(StreamWriter::try_new(writer, &schema)?, rows)
+}
+
+fn write_rows<T, W, I>(writer: W, rows: I) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    W: RecordBatchWriter,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let mut writer = TypedWriter::<T, W>::new(writer);
+    writer.write_all(rows)?;
+    writer.close()
+}
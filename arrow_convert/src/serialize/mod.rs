@@ -1,16 +1,128 @@
 //! Implementation and traits for serializing to Arrow.
 
 use arrow::buffer::{Buffer, ScalarBuffer};
-use arrow::datatypes::ArrowNativeType;
+use arrow::datatypes::{
+    ArrowDictionaryKeyType, ArrowNativeType, GenericBinaryType, GenericStringType, RunEndIndexType,
+};
 use arrow::{array::*, datatypes};
 use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 mod push_null;
 pub use push_null::*;
 
+mod sorted_map_builder;
+pub use sorted_map_builder::*;
+
+mod tuple_builder;
+pub use tuple_builder::*;
+
+mod dictionary_builder;
+pub use dictionary_builder::*;
+
+mod run_end_builder;
+pub use run_end_builder::*;
+
+mod union_pair_builder;
+pub use union_pair_builder::*;
+
+mod empty_struct_builder;
+pub use empty_struct_builder::*;
+
+mod batch_serializer;
+pub use batch_serializer::*;
+
+mod typed_writer;
+pub use typed_writer::*;
+
+mod schema_cast;
+
+mod checked_offsets;
+pub use checked_offsets::*;
+
+mod dynamic;
+pub use dynamic::*;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+
+#[cfg(feature = "ipc")]
+mod ipc;
+#[cfg(feature = "ipc")]
+pub use ipc::*;
+
+#[cfg(feature = "parquet")]
+mod parquet;
+#[cfg(feature = "parquet")]
+pub use self::parquet::*;
+
+#[cfg(feature = "ndjson")]
+mod ndjson;
+#[cfg(feature = "ndjson")]
+pub use ndjson::*;
+
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "csv")]
+pub use self::csv::*;
+
+#[cfg(feature = "async")]
+mod batch_sink;
+#[cfg(feature = "async")]
+pub use batch_sink::*;
+
+#[cfg(feature = "flight")]
+mod flight;
+#[cfg(feature = "flight")]
+pub use flight::*;
+
 use crate::field::*;
 
+/// Capacity hints for pre-sizing the builders of a serialization, to avoid growing and copying the buffers of large
+/// arrays as values are appended, and options for serializing into several arrays with the same builders.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// The number of values that will be serialized
+    pub rows: usize,
+    /// The approximate number of bytes of each string or binary value
+    pub approx_bytes_per_string: usize,
+    /// Whether the dictionary builders keep their values when finished, so that the arrays finished by the same
+    /// builders, like the batches of a [`TypedBatchSerializer`], share a dictionary whose keys don't change. Each
+    /// array holds all the values seen so far.
+    pub shared_dictionaries: bool,
+}
+
+impl SerializeOptions {
+    /// Creates the hints for serializing `rows` values
+    pub fn new(rows: usize) -> Self {
+        Self {
+            rows,
+            approx_bytes_per_string: 0,
+            shared_dictionaries: false,
+        }
+    }
+
+    /// Sets the approximate number of bytes of each string or binary value
+    pub fn with_approx_bytes_per_string(mut self, bytes: usize) -> Self {
+        self.approx_bytes_per_string = bytes;
+        self
+    }
+
+    /// Sets whether the dictionary builders keep their values when finished
+    pub fn with_shared_dictionaries(mut self, shared: bool) -> Self {
+        self.shared_dictionaries = shared;
+        self
+    }
+
+    /// The approximate number of bytes of all the string or binary values
+    fn data_capacity(&self) -> usize {
+        self.rows.saturating_mul(self.approx_bytes_per_string)
+    }
+}
+
 /// Trait that is implemented by all types that are serializable to Arrow.
 ///
 /// Implementations are provided for all built-in arrow types as well as Vec<T>, and Option<T>
@@ -24,6 +136,14 @@ pub trait ArrowSerialize: ArrowField {
     /// Create a new mutable array
     fn new_array() -> Self::ArrayBuilderType;
 
+    /// Create a new mutable array pre-sized with the capacity hints of `options`. Types whose builders can't be
+    /// pre-sized return [`Self::new_array`].
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        let _ = options;
+        Self::new_array()
+    }
+
     /// Serialize this field to arrow
     fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()>;
 }
@@ -39,6 +159,11 @@ macro_rules! impl_numeric_type {
                 Self::ArrayBuilderType::default()
             }
 
+            #[inline]
+            fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::with_capacity(options.rows)
+            }
+
             #[inline]
             fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
                 array.append_option(Some(*v));
@@ -61,6 +186,11 @@ where
         <T as ArrowSerialize>::new_array()
     }
 
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        <T as ArrowSerialize>::new_array_with_capacity(options)
+    }
+
     #[inline]
     fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
         match v.as_ref() {
@@ -73,6 +203,164 @@ where
     }
 }
 
+impl<T> ArrowSerialize for Box<T>
+where
+    T: ArrowSerialize,
+{
+    type ArrayBuilderType = <T as ArrowSerialize>::ArrayBuilderType;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        <T as ArrowSerialize>::new_array()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        <T as ArrowSerialize>::new_array_with_capacity(options)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        <T as ArrowSerialize>::arrow_serialize(v, array)
+    }
+}
+
+impl<T> ArrowSerialize for NestedOption<T>
+where
+    T: ArrowSerialize,
+    T::ArrayBuilderType: PushNull,
+{
+    type ArrayBuilderType = TupleBuilder<(<T as ArrowSerialize>::ArrayBuilderType,)>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let datatypes::DataType::Struct(fields) = <Self as ArrowField>::data_type() else {
+            panic!("datatype is not struct")
+        };
+        TupleBuilder::new(fields, (<T as ArrowSerialize>::new_array(),))
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        match v.as_ref() {
+            Some(t) => {
+                <Option<T> as ArrowSerialize>::arrow_serialize(t, &mut array.values().0)?;
+                array.append(true);
+                Ok(())
+            }
+            None => {
+                array.push_null();
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<T, E> ArrowSerialize for Result<T, E>
+where
+    T: ArrowSerialize,
+    E: ArrowSerialize,
+    <T as ArrowSerialize>::ArrayBuilderType: PushNull,
+    <E as ArrowSerialize>::ArrayBuilderType: PushNull,
+{
+    type ArrayBuilderType =
+        UnionPairBuilder<<T as ArrowSerialize>::ArrayBuilderType, <E as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let datatypes::DataType::Union(fields, mode) = <Self as ArrowField>::data_type() else {
+            panic!("datatype is not a union")
+        };
+        UnionPairBuilder::new(
+            fields,
+            mode,
+            <T as ArrowSerialize>::new_array(),
+            <E as ArrowSerialize>::new_array(),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        match v {
+            Ok(t) => {
+                <T as ArrowSerialize>::arrow_serialize(t, array.left())?;
+                array.append_left();
+            }
+            Err(e) => {
+                <E as ArrowSerialize>::arrow_serialize(e, array.right())?;
+                array.append_right();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> ArrowSerialize for std::ops::Range<T>
+where
+    T: ArrowSerialize,
+{
+    type ArrayBuilderType = TupleBuilder<(
+        <T as ArrowSerialize>::ArrayBuilderType,
+        <T as ArrowSerialize>::ArrayBuilderType,
+    )>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let datatypes::DataType::Struct(fields) = <Self as ArrowField>::data_type() else {
+            panic!("datatype is not struct")
+        };
+        TupleBuilder::new(
+            fields,
+            (
+                <T as ArrowSerialize>::new_array(),
+                <T as ArrowSerialize>::new_array(),
+            ),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        <T as ArrowSerialize>::arrow_serialize(&v.start, &mut values.0)?;
+        <T as ArrowSerialize>::arrow_serialize(&v.end, &mut values.1)?;
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T> ArrowSerialize for std::ops::RangeInclusive<T>
+where
+    T: ArrowSerialize,
+{
+    type ArrayBuilderType = TupleBuilder<(
+        <T as ArrowSerialize>::ArrayBuilderType,
+        <T as ArrowSerialize>::ArrayBuilderType,
+    )>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let datatypes::DataType::Struct(fields) = <Self as ArrowField>::data_type() else {
+            panic!("datatype is not struct")
+        };
+        TupleBuilder::new(
+            fields,
+            (
+                <T as ArrowSerialize>::new_array(),
+                <T as ArrowSerialize>::new_array(),
+            ),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        <T as ArrowSerialize>::arrow_serialize(v.start(), &mut values.0)?;
+        <T as ArrowSerialize>::arrow_serialize(v.end(), &mut values.1)?;
+        array.append(true);
+        Ok(())
+    }
+}
+
 impl_numeric_type!(u8, datatypes::UInt8Type);
 impl_numeric_type!(u16, datatypes::UInt16Type);
 impl_numeric_type!(u32, datatypes::UInt32Type);
@@ -85,6 +373,34 @@ impl_numeric_type!(half::f16, datatypes::Float16Type);
 impl_numeric_type!(f32, datatypes::Float32Type);
 impl_numeric_type!(f64, datatypes::Float64Type);
 
+// Macro to implement `ArrowSerialize` for the `std::num` integer wrappers by delegating to the wrapped type.
+macro_rules! impl_numeric_wrapper {
+    ($wrapper:ident) => {
+        impl<T> ArrowSerialize for std::num::$wrapper<T>
+        where
+            T: ArrowSerialize,
+        {
+            type ArrayBuilderType = <T as ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                <T as ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn arrow_serialize(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::ArrayBuilderType,
+            ) -> arrow::error::Result<()> {
+                <T as ArrowSerialize>::arrow_serialize(&v.0, array)
+            }
+        }
+    };
+}
+
+impl_numeric_wrapper!(Wrapping);
+impl_numeric_wrapper!(Saturating);
+
 impl<const PRECISION: u8, const SCALE: i8> ArrowSerialize for I128<PRECISION, SCALE> {
     type ArrayBuilderType = PrimitiveBuilder<datatypes::Decimal128Type>;
 
@@ -95,11 +411,88 @@ impl<const PRECISION: u8, const SCALE: i8> ArrowSerialize for I128<PRECISION, SC
 
     #[inline]
     fn arrow_serialize(v: &i128, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        <datatypes::Decimal128Type as datatypes::DecimalType>::validate_decimal_precision(*v, PRECISION)?;
         array.append_option(Some(*v));
         Ok(())
     }
 }
 
+// Macro to implement [`ArrowSerialize`] for the duration placeholder types.
+macro_rules! impl_duration_serialize {
+    ($placeholder:ident, $unit:ident, $builder:ty) => {
+        impl<T> ArrowSerialize for $placeholder<T>
+        where
+            T: ArrowDurationValue,
+        {
+            type ArrayBuilderType = $builder;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &T, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                let v = v.to_duration(datatypes::TimeUnit::$unit).ok_or_else(|| {
+                    arrow::error::ArrowError::InvalidArgumentError(format!(
+                        "Value of `{}` overflows an Arrow `Duration({:?})`",
+                        std::any::type_name::<T>(),
+                        datatypes::TimeUnit::$unit
+                    ))
+                })?;
+                array.append_value(v);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_duration_serialize!(DurationSeconds, Second, DurationSecondBuilder);
+impl_duration_serialize!(DurationMilliseconds, Millisecond, DurationMillisecondBuilder);
+impl_duration_serialize!(DurationMicroseconds, Microsecond, DurationMicrosecondBuilder);
+impl_duration_serialize!(DurationNanoseconds, Nanosecond, DurationNanosecondBuilder);
+
+/// Returns the error of a value of `T` that doesn't fit in an Arrow `Timestamp` of `unit`.
+fn timestamp_overflow_error<T>(unit: datatypes::TimeUnit) -> arrow::error::ArrowError {
+    arrow::error::ArrowError::InvalidArgumentError(format!(
+        "Value of `{}` overflows an Arrow `Timestamp({:?})`",
+        std::any::type_name::<T>(),
+        unit
+    ))
+}
+
+// Macro to implement [`ArrowSerialize`] for the timestamp placeholder types.
+macro_rules! impl_timestamp_serialize {
+    ($placeholder:ident, $unit:ident, $builder:ty) => {
+        impl<T, Tz> ArrowSerialize for $placeholder<T, Tz>
+        where
+            T: ArrowTimestampValue,
+            Tz: ArrowTimeZone,
+        {
+            type ArrayBuilderType = $builder;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default().with_timezone_opt(Tz::time_zone())
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &T, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                let v = v
+                    .to_timestamp(datatypes::TimeUnit::$unit)
+                    .ok_or_else(|| timestamp_overflow_error::<T>(datatypes::TimeUnit::$unit))?;
+                array.append_value(v);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_timestamp_serialize!(TimestampSeconds, Second, TimestampSecondBuilder);
+impl_timestamp_serialize!(TimestampMilliseconds, Millisecond, TimestampMillisecondBuilder);
+impl_timestamp_serialize!(TimestampMicroseconds, Microsecond, TimestampMicrosecondBuilder);
+impl_timestamp_serialize!(TimestampNanoseconds, Nanosecond, TimestampNanosecondBuilder);
+
 impl<'a> ArrowSerialize for &'a str {
     type ArrayBuilderType = StringBuilder;
 
@@ -108,8 +501,14 @@ impl<'a> ArrowSerialize for &'a str {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows, options.data_capacity())
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
         array.append_option(Some(v));
         Ok(())
     }
@@ -123,15 +522,21 @@ impl ArrowSerialize for String {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows, options.data_capacity())
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
         array.append_option(Some(v));
         Ok(())
     }
 }
 
-impl ArrowSerialize for LargeString {
-    type ArrayBuilderType = LargeStringBuilder;
+impl ArrowSerialize for std::ffi::OsString {
+    type ArrayBuilderType = BinaryBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
@@ -139,14 +544,15 @@ impl ArrowSerialize for LargeString {
     }
 
     #[inline]
-    fn arrow_serialize(v: &String, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(Some(v));
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_encoded_bytes().len())?;
+        array.append_value(v.as_encoded_bytes());
         Ok(())
     }
 }
 
-impl ArrowSerialize for bool {
-    type ArrayBuilderType = BooleanBuilder;
+impl ArrowSerialize for OsStringUtf8 {
+    type ArrayBuilderType = StringBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
@@ -154,47 +560,50 @@ impl ArrowSerialize for bool {
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_value(*v);
+    fn arrow_serialize(v: &std::ffi::OsString, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let v = v.to_str().ok_or_else(|| {
+            arrow::error::ArrowError::InvalidArgumentError(format!("OsString {v:?} is not valid unicode"))
+        })?;
+        array.check_offsets(v.len())?;
+        array.append_value(v);
         Ok(())
     }
 }
 
-impl ArrowSerialize for NaiveDateTime {
-    type ArrayBuilderType = TimestampNanosecondBuilder;
+impl ArrowSerialize for OsStringLossy {
+    type ArrayBuilderType = StringBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
-        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+        Self::ArrayBuilderType::default()
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(v.and_utc().timestamp_nanos_opt());
+    fn arrow_serialize(v: &std::ffi::OsString, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.to_string_lossy().len())?;
+        array.append_value(v.to_string_lossy());
         Ok(())
     }
 }
 
-impl ArrowSerialize for NaiveDate {
-    type ArrayBuilderType = Date32Builder;
+impl ArrowSerialize for std::ffi::CString {
+    type ArrayBuilderType = BinaryBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
-        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+        Self::ArrayBuilderType::default()
     }
 
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(Some(
-            chrono::Datelike::num_days_from_ce(v) - arrow::temporal_conversions::UNIX_EPOCH_DAY as i32,
-        ));
+        array.check_offsets(v.as_bytes().len())?;
+        array.append_value(v.as_bytes());
         Ok(())
     }
 }
 
-// Treat both Buffer and ScalarBuffer<u8> the same
-impl ArrowSerialize for Buffer {
-    type ArrayBuilderType = BinaryBuilder;
+impl ArrowSerialize for LargeString {
+    type ArrayBuilderType = LargeStringBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
@@ -202,13 +611,19 @@ impl ArrowSerialize for Buffer {
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(Some(v.as_slice()));
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows, options.data_capacity())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &String, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_option(Some(v));
         Ok(())
     }
 }
-impl ArrowSerialize for ScalarBuffer<u8> {
-    type ArrayBuilderType = BinaryBuilder;
+
+impl ArrowSerialize for Utf8View {
+    type ArrayBuilderType = StringViewBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
@@ -216,85 +631,284 @@ impl ArrowSerialize for ScalarBuffer<u8> {
     }
 
     #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(Some(v));
+    fn arrow_serialize(v: &String, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v);
         Ok(())
     }
 }
 
-impl ArrowSerialize for Vec<u8> {
-    type ArrayBuilderType = BinaryBuilder;
+impl ArrowSerialize for bool {
+    type ArrayBuilderType = BooleanBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
         Self::ArrayBuilderType::default()
     }
 
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows)
+    }
+
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(Some(v));
+        array.append_value(*v);
         Ok(())
     }
 }
 
-impl ArrowSerialize for LargeBinary {
-    type ArrayBuilderType = LargeBinaryBuilder;
+impl ArrowSerialize for () {
+    type ArrayBuilderType = NullBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
-        Self::ArrayBuilderType::default()
+        Self::ArrayBuilderType::new()
     }
 
     #[inline]
-    fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_option(Some(v));
+    fn arrow_serialize(_v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_null();
         Ok(())
     }
 }
 
-impl<const SIZE: i32> ArrowSerialize for FixedSizeBinary<SIZE> {
-    type ArrayBuilderType = FixedSizeBinaryBuilder;
+impl<T: ?Sized> ArrowSerialize for std::marker::PhantomData<T> {
+    type ArrayBuilderType = NullBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
-        Self::ArrayBuilderType::new(SIZE)
+        Self::ArrayBuilderType::new()
     }
 
     #[inline]
-    fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_value(v)
+    fn arrow_serialize(_v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_null();
+        Ok(())
     }
 }
 
-impl<const SIZE: usize> ArrowSerialize for [u8; SIZE] {
-    type ArrayBuilderType = FixedSizeBinaryBuilder;
+impl ArrowSerialize for NaiveDateTime {
+    type ArrayBuilderType = TimestampNanosecondBuilder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
-        Self::ArrayBuilderType::new(SIZE as i32)
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
     }
 
     #[inline]
     fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-        array.append_value(v)
+        let v = v
+            .and_utc()
+            .timestamp_nanos_opt()
+            .ok_or_else(|| timestamp_overflow_error::<Self>(datatypes::TimeUnit::Nanosecond))?;
+        array.append_value(v);
+        Ok(())
     }
 }
 
-// Blanket implementation for Buffer
-impl<T> ArrowSerialize for ScalarBuffer<T>
-where
-    T: ArrowNativeType + ArrowSerialize + ArrowEnableVecForType + ArrowField<Type = T>,
-{
-    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+impl ArrowSerialize for NaiveDate {
+    type ArrayBuilderType = Date32Builder;
 
     #[inline]
     fn new_array() -> Self::ArrayBuilderType {
-        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
-        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
+        Self::ArrayBuilderType::default().with_data_type(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_option(Some(
+            chrono::Datelike::num_days_from_ce(v) - arrow::temporal_conversions::UNIX_EPOCH_DAY as i32,
+        ));
+        Ok(())
+    }
+}
+
+// Treat both Buffer and ScalarBuffer<u8> the same
+impl ArrowSerialize for Buffer {
+    type ArrayBuilderType = BinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.as_slice().len())?;
+        array.append_option(Some(v.as_slice()));
+        Ok(())
+    }
+}
+impl ArrowSerialize for ScalarBuffer<u8> {
+    type ArrayBuilderType = BinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        array.append_option(Some(v));
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for Vec<u8> {
+    type ArrayBuilderType = BinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows, options.data_capacity())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        array.append_option(Some(v));
+        Ok(())
+    }
+}
+
+impl<'a> ArrowSerialize for &'a [u8] {
+    type ArrayBuilderType = BinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows, options.data_capacity())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        array.append_value(v);
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for LargeBuffer {
+    type ArrayBuilderType = LargeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Buffer, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v.as_slice());
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for LargeBinary {
+    type ArrayBuilderType = LargeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::with_capacity(options.rows, options.data_capacity())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_option(Some(v));
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for ByteList {
+    type ArrayBuilderType = ListBuilder<UInt8Builder>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<u8 as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(UInt8Builder::new()).with_field(field)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        array.values().append_slice(v);
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for BinaryView {
+    type ArrayBuilderType = BinaryViewBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v);
+        Ok(())
+    }
+}
+
+impl<const SIZE: i32> ArrowSerialize for FixedSizeBinary<SIZE> {
+    type ArrayBuilderType = FixedSizeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(SIZE)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Vec<u8>, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v)
+    }
+}
+
+impl<const SIZE: usize> ArrowSerialize for [u8; SIZE] {
+    type ArrayBuilderType = FixedSizeBinaryBuilder;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(SIZE as i32)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.append_value(v)
+    }
+}
+
+// Blanket implementation for Buffer
+impl<T> ArrowSerialize for ScalarBuffer<T>
+where
+    T: ArrowNativeType + ArrowSerialize + ArrowEnableVecForType + ArrowField<Type = T>,
+{
+    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
     }
 
     #[inline]
     fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
         let values = array.values();
         for i in v.iter() {
             <T as ArrowSerialize>::arrow_serialize(i, values)?;
@@ -308,7 +922,6 @@ where
 impl<T> ArrowSerialize for Vec<T>
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -319,6 +932,79 @@ where
     }
 
     fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+// Blanket implementation for VecDeque
+impl<T> ArrowSerialize for VecDeque<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+// Blanket implementation for HashSet
+impl<T, S> ArrowSerialize for HashSet<T, S>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+// Blanket implementation for BTreeSet. Values are serialized in sorted order.
+impl<T> ArrowSerialize for BTreeSet<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = ListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        ListBuilder::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        array.check_offsets(v.len())?;
         let values = array.values();
         for i in v.iter() {
             <T as ArrowSerialize>::arrow_serialize(i, values)?;
@@ -331,7 +1017,6 @@ where
 impl<T> ArrowSerialize for LargeVec<T>
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = LargeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -351,10 +1036,53 @@ where
     }
 }
 
+impl<T> ArrowSerialize for ListView<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = ListViewBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        Self::ArrayBuilderType::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
+impl<T> ArrowSerialize for LargeListView<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type ArrayBuilderType = LargeListViewBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        let field = Arc::new(<T as ArrowField>::field(DEFAULT_FIELD_NAME));
+        Self::ArrayBuilderType::new(<T as ArrowSerialize>::new_array()).with_field(field)
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.append(true);
+        Ok(())
+    }
+}
+
 impl<T, const SIZE: i32> ArrowSerialize for FixedSizeVec<T, SIZE>
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -365,6 +1093,12 @@ where
     }
 
     fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        if v.len() != SIZE as usize {
+            return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                "Expected a list of length {SIZE}, got {}",
+                v.len()
+            )));
+        }
         let values = array.values();
         for i in v.iter() {
             <T as ArrowSerialize>::arrow_serialize(i, values)?;
@@ -374,10 +1108,255 @@ where
     }
 }
 
+/// Implemented by [`ArrowSerialize`] types that can be used as the values of a [`Dictionary`].
+pub trait ArrowDictionaryValue: ArrowSerialize {
+    /// The dictionary [`arrow::array::ArrayBuilder`] holding values of this type with keys of type `K`
+    type DictionaryBuilderType<K: ArrowDictionaryKeyType>: arrow::array::ArrayBuilder;
+
+    /// Create a new mutable dictionary array
+    fn new_dictionary_array<K: ArrowDictionaryKeyType>() -> Self::DictionaryBuilderType<K>;
+
+    /// Create a new mutable dictionary array with the given options
+    fn new_dictionary_array_with_options<K: ArrowDictionaryKeyType>(
+        _options: &SerializeOptions,
+    ) -> Self::DictionaryBuilderType<K> {
+        Self::new_dictionary_array()
+    }
+
+    /// Serialize this value to a dictionary
+    fn arrow_serialize_dictionary<K: ArrowDictionaryKeyType>(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::DictionaryBuilderType<K>,
+    ) -> arrow::error::Result<()>;
+}
+
+// Macro to implement dictionary values stored in a generic byte array.
+macro_rules! impl_dictionary_value_bytes {
+    ($physical_type:ty, $byte_type:ty) => {
+        impl ArrowDictionaryValue for $physical_type {
+            type DictionaryBuilderType<K: ArrowDictionaryKeyType> =
+                DictionaryBuilder<GenericByteDictionaryBuilder<K, $byte_type>>;
+
+            #[inline]
+            fn new_dictionary_array<K: ArrowDictionaryKeyType>() -> Self::DictionaryBuilderType<K> {
+                DictionaryBuilder::new(GenericByteDictionaryBuilder::new())
+            }
+
+            #[inline]
+            fn new_dictionary_array_with_options<K: ArrowDictionaryKeyType>(
+                options: &SerializeOptions,
+            ) -> Self::DictionaryBuilderType<K> {
+                DictionaryBuilder::with_options(GenericByteDictionaryBuilder::new(), options)
+            }
+
+            #[inline]
+            fn arrow_serialize_dictionary<K: ArrowDictionaryKeyType>(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::DictionaryBuilderType<K>,
+            ) -> arrow::error::Result<()> {
+                array.inner().append(v)?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_dictionary_value_bytes!(String, GenericStringType<i32>);
+impl_dictionary_value_bytes!(LargeString, GenericStringType<i64>);
+impl_dictionary_value_bytes!(Vec<u8>, GenericBinaryType<i32>);
+impl_dictionary_value_bytes!(LargeBinary, GenericBinaryType<i64>);
+
+// Macro to implement dictionary values stored in a primitive array.
+macro_rules! impl_dictionary_value_primitive {
+    ($physical_type:ty, $primitive_type:ty) => {
+        impl ArrowDictionaryValue for $physical_type {
+            type DictionaryBuilderType<K: ArrowDictionaryKeyType> =
+                DictionaryBuilder<PrimitiveDictionaryBuilder<K, $primitive_type>>;
+
+            #[inline]
+            fn new_dictionary_array<K: ArrowDictionaryKeyType>() -> Self::DictionaryBuilderType<K> {
+                DictionaryBuilder::new(PrimitiveDictionaryBuilder::new())
+            }
+
+            #[inline]
+            fn new_dictionary_array_with_options<K: ArrowDictionaryKeyType>(
+                options: &SerializeOptions,
+            ) -> Self::DictionaryBuilderType<K> {
+                DictionaryBuilder::with_options(PrimitiveDictionaryBuilder::new(), options)
+            }
+
+            #[inline]
+            fn arrow_serialize_dictionary<K: ArrowDictionaryKeyType>(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::DictionaryBuilderType<K>,
+            ) -> arrow::error::Result<()> {
+                array.inner().append(*v)?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_dictionary_value_primitive!(u8, datatypes::UInt8Type);
+impl_dictionary_value_primitive!(u16, datatypes::UInt16Type);
+impl_dictionary_value_primitive!(u32, datatypes::UInt32Type);
+impl_dictionary_value_primitive!(u64, datatypes::UInt64Type);
+impl_dictionary_value_primitive!(i8, datatypes::Int8Type);
+impl_dictionary_value_primitive!(i16, datatypes::Int16Type);
+impl_dictionary_value_primitive!(i32, datatypes::Int32Type);
+impl_dictionary_value_primitive!(i64, datatypes::Int64Type);
+
+impl<K, V> ArrowSerialize for Dictionary<K, V>
+where
+    K: ArrowDictionaryKey,
+    V: ArrowDictionaryValue,
+{
+    type ArrayBuilderType = <V as ArrowDictionaryValue>::DictionaryBuilderType<<K as ArrowDictionaryKey>::KeyType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        <V as ArrowDictionaryValue>::new_dictionary_array()
+    }
+
+    #[inline]
+    fn new_array_with_capacity(options: &SerializeOptions) -> Self::ArrayBuilderType {
+        <V as ArrowDictionaryValue>::new_dictionary_array_with_options(options)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        <V as ArrowDictionaryValue>::arrow_serialize_dictionary(v, array)
+    }
+}
+
+/// Implemented by [`ArrowSerialize`] types that can be used as the values of a [`RunEnd`].
+pub trait ArrowRunEndValue: ArrowSerialize {
+    /// The run-end encoded [`arrow::array::ArrayBuilder`] holding values of this type with run ends of type `R`
+    type RunEndBuilderType<R: RunEndIndexType>: arrow::array::ArrayBuilder;
+
+    /// Create a new mutable run-end encoded array
+    fn new_run_end_array<R: RunEndIndexType>() -> Self::RunEndBuilderType<R>;
+
+    /// Serialize this value to a run-end encoded array, extending the current run if the value is repeated
+    fn arrow_serialize_run_end<R: RunEndIndexType>(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::RunEndBuilderType<R>,
+    ) -> arrow::error::Result<()>;
+}
+
+// Macro to implement run-end encoded values stored in a generic byte array.
+macro_rules! impl_run_end_value_bytes {
+    ($physical_type:ty, $byte_type:ty) => {
+        impl ArrowRunEndValue for $physical_type {
+            type RunEndBuilderType<R: RunEndIndexType> = RunEndBuilder<GenericByteRunBuilder<R, $byte_type>>;
+
+            #[inline]
+            fn new_run_end_array<R: RunEndIndexType>() -> Self::RunEndBuilderType<R> {
+                RunEndBuilder::new(GenericByteRunBuilder::new())
+            }
+
+            #[inline]
+            fn arrow_serialize_run_end<R: RunEndIndexType>(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::RunEndBuilderType<R>,
+            ) -> arrow::error::Result<()> {
+                array.inner().append_value(v);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_run_end_value_bytes!(String, GenericStringType<i32>);
+impl_run_end_value_bytes!(LargeString, GenericStringType<i64>);
+impl_run_end_value_bytes!(Vec<u8>, GenericBinaryType<i32>);
+impl_run_end_value_bytes!(LargeBinary, GenericBinaryType<i64>);
+
+// Macro to implement run-end encoded values stored in a primitive array.
+macro_rules! impl_run_end_value_primitive {
+    ($physical_type:ty, $primitive_type:ty) => {
+        impl ArrowRunEndValue for $physical_type {
+            type RunEndBuilderType<R: RunEndIndexType> = RunEndBuilder<PrimitiveRunBuilder<R, $primitive_type>>;
+
+            #[inline]
+            fn new_run_end_array<R: RunEndIndexType>() -> Self::RunEndBuilderType<R> {
+                RunEndBuilder::new(PrimitiveRunBuilder::new())
+            }
+
+            #[inline]
+            fn arrow_serialize_run_end<R: RunEndIndexType>(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::RunEndBuilderType<R>,
+            ) -> arrow::error::Result<()> {
+                array.inner().append_value(*v);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_run_end_value_primitive!(u8, datatypes::UInt8Type);
+impl_run_end_value_primitive!(u16, datatypes::UInt16Type);
+impl_run_end_value_primitive!(u32, datatypes::UInt32Type);
+impl_run_end_value_primitive!(u64, datatypes::UInt64Type);
+impl_run_end_value_primitive!(i8, datatypes::Int8Type);
+impl_run_end_value_primitive!(i16, datatypes::Int16Type);
+impl_run_end_value_primitive!(i32, datatypes::Int32Type);
+impl_run_end_value_primitive!(i64, datatypes::Int64Type);
+impl_run_end_value_primitive!(half::f16, datatypes::Float16Type);
+impl_run_end_value_primitive!(f32, datatypes::Float32Type);
+impl_run_end_value_primitive!(f64, datatypes::Float64Type);
+
+impl<R, V> ArrowSerialize for RunEnd<R, V>
+where
+    R: ArrowRunEndIndex,
+    V: ArrowRunEndValue,
+{
+    type ArrayBuilderType = <V as ArrowRunEndValue>::RunEndBuilderType<<R as ArrowRunEndIndex>::RunEndType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        <V as ArrowRunEndValue>::new_run_end_array()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        <V as ArrowRunEndValue>::arrow_serialize_run_end(v, array)
+    }
+}
+
+impl<A> ArrowSerialize for FixedShapeTensor<A>
+where
+    A: ArrowTensorShape,
+    <A as ArrowTensorShape>::Element: ArrowSerialize,
+{
+    type ArrayBuilderType =
+        FixedSizeListBuilder<<<A as ArrowTensorShape>::Element as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        Self::ArrayBuilderType::new(
+            <<A as ArrowTensorShape>::Element as ArrowSerialize>::new_array(),
+            Self::num_elements() as i32,
+        )
+        .with_field(<<A as ArrowTensorShape>::Element as ArrowField>::field(
+            DEFAULT_FIELD_NAME,
+        ))
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        let values = array.values();
+        v.try_for_each_element(&mut |i| {
+            <<A as ArrowTensorShape>::Element as ArrowSerialize>::arrow_serialize(i, values)
+        })?;
+        array.append(true);
+        Ok(())
+    }
+}
+
 impl<T, const SIZE: usize> ArrowSerialize for [T; SIZE]
 where
     T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::ArrayBuilderType: Default,
 {
     type ArrayBuilderType = FixedSizeListBuilder<<T as ArrowSerialize>::ArrayBuilderType>;
 
@@ -397,34 +1376,151 @@ where
     }
 }
 
-// internal helper method to extend a mutable array
+// internal helper to create a map builder for the given key and value types
+pub(crate) fn new_map_array<K, V>(
+) -> MapBuilder<<K as ArrowSerialize>::ArrayBuilderType, <V as ArrowSerialize>::ArrayBuilderType>
+where
+    K: ArrowSerialize,
+    V: ArrowSerialize,
+{
+    MapBuilder::new(
+        None,
+        <K as ArrowSerialize>::new_array(),
+        <V as ArrowSerialize>::new_array(),
+    )
+    .with_keys_field(<K as ArrowField>::field(MAP_KEYS_FIELD_NAME))
+    .with_values_field(<V as ArrowField>::field(MAP_VALUES_FIELD_NAME))
+}
+
+// internal helper to serialize the entries of a map. Arrow map keys can't be null, so nullable key types are rejected,
+// and the entries are counted upfront so that overflowing the i32 offsets returns an error.
+pub(crate) fn arrow_serialize_map_helper<'a, K, V>(
+    entries: impl ExactSizeIterator<Item = (&'a <K as ArrowField>::Type, &'a <V as ArrowField>::Type)>,
+    array: &mut MapBuilder<<K as ArrowSerialize>::ArrayBuilderType, <V as ArrowSerialize>::ArrayBuilderType>,
+) -> arrow::error::Result<()>
+where
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
+{
+    if <K as ArrowField>::is_nullable() {
+        return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+            "Map keys can't be nullable, got keys of type `{}`",
+            std::any::type_name::<K>()
+        )));
+    }
+    check_map_offsets(array, entries.len())?;
+    for (k, v) in entries {
+        <K as ArrowSerialize>::arrow_serialize(k, array.keys())?;
+        <V as ArrowSerialize>::arrow_serialize(v, array.values())?;
+    }
+    array.append(true)
+}
+
+// Blanket implementation for HashMap
+impl<K, V, S> ArrowSerialize for HashMap<K, V, S>
+where
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
+{
+    type ArrayBuilderType =
+        MapBuilder<<K as ArrowSerialize>::ArrayBuilderType, <V as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        new_map_array::<K, V>()
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        arrow_serialize_map_helper::<K, V>(v.iter(), array)
+    }
+}
+
+// Blanket implementation for BTreeMap
+impl<K, V> ArrowSerialize for BTreeMap<K, V>
+where
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
+{
+    type ArrayBuilderType =
+        SortedMapBuilder<<K as ArrowSerialize>::ArrayBuilderType, <V as ArrowSerialize>::ArrayBuilderType>;
+
+    #[inline]
+    fn new_array() -> Self::ArrayBuilderType {
+        SortedMapBuilder::new(new_map_array::<K, V>())
+    }
+
+    fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+        // the keys are iterated in sorted order
+        arrow_serialize_map_helper::<K, V>(v.iter(), array.inner())
+    }
+}
+
+// internal helper method to extend a mutable array, whose errors name the row failing to serialize counting from
+// `first_row`
 fn arrow_serialize_extend_internal<
     'a,
-    A: 'static,
-    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    A: 'a,
+    T: ArrowSerialize + ArrowField<Type = A>,
     I: IntoIterator<Item = &'a A>,
 >(
     into_iter: I,
     array: &mut <T as ArrowSerialize>::ArrayBuilderType,
+    first_row: usize,
 ) -> arrow::error::Result<()> {
     let iter = into_iter.into_iter();
-    for i in iter {
-        <T as ArrowSerialize>::arrow_serialize(i, array)?;
+    for (row, i) in iter.enumerate() {
+        <T as ArrowSerialize>::arrow_serialize(i, array).map_err(|e| row_error(first_row + row, e))?;
     }
     Ok(())
 }
 
+/// Prefixes the message of a serialization error with the index of the row that failed to serialize.
+fn row_error(row: usize, error: arrow::error::ArrowError) -> arrow::error::ArrowError {
+    match error {
+        arrow::error::ArrowError::InvalidArgumentError(message) => {
+            arrow::error::ArrowError::InvalidArgumentError(format!("Row {row}: {message}"))
+        }
+        error => error,
+    }
+}
+
+/// Serializes an iterator by appending to an existing `arrow::ArrayBuilder`, such as one returned by
+/// [`ArrowSerialize::new_array`], so that several collections can be accumulated into the same array. Errors name
+/// the failing row counting the values already in the builder.
+pub fn arrow_serialize_extend<'a, A: 'a, T: ArrowSerialize + ArrowField<Type = A>, I: IntoIterator<Item = &'a A>>(
+    into_iter: I,
+    array: &mut <T as ArrowSerialize>::ArrayBuilderType,
+) -> arrow::error::Result<()> {
+    let first_row = array.len();
+    arrow_serialize_extend_internal::<A, T, I>(into_iter, array, first_row)
+}
+
+/// Serializes an iterator into an `arrow::ArrayBuilder` pre-sized with the capacity hints of `options`
+pub fn arrow_serialize_to_mutable_array_with_options<
+    'a,
+    A: 'a,
+    T: ArrowSerialize + ArrowField<Type = A>,
+    I: IntoIterator<Item = &'a A>,
+>(
+    into_iter: I,
+    options: &SerializeOptions,
+) -> arrow::error::Result<<T as ArrowSerialize>::ArrayBuilderType> {
+    let mut arr = <T as ArrowSerialize>::new_array_with_capacity(options);
+    arrow_serialize_extend_internal::<A, T, I>(into_iter, &mut arr, 0)?;
+    Ok(arr)
+}
+
 /// Serializes an iterator into an `arrow::ArrayBuilder`
 pub fn arrow_serialize_to_mutable_array<
     'a,
-    A: 'static,
-    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    A: 'a,
+    T: ArrowSerialize + ArrowField<Type = A>,
     I: IntoIterator<Item = &'a A>,
 >(
     into_iter: I,
 ) -> arrow::error::Result<<T as ArrowSerialize>::ArrayBuilderType> {
     let mut arr = <T as ArrowSerialize>::new_array();
-    arrow_serialize_extend_internal::<A, T, I>(into_iter, &mut arr)?;
+    arrow_serialize_extend_internal::<A, T, I>(into_iter, &mut arr, 0)?;
     Ok(arr)
 }
 
@@ -456,8 +1552,15 @@ impl FlattenRecordBatch for RecordBatch {
             ));
         }
 
+        // the schema metadata is kept
         let struct_array = array.as_ref().as_any().downcast_ref::<StructArray>().unwrap();
-        Ok(RecordBatch::from(struct_array))
+        let batch = RecordBatch::from(struct_array);
+        let schema = batch
+            .schema()
+            .as_ref()
+            .clone()
+            .with_metadata(self.schema().metadata().clone());
+        batch.with_schema(Arc::new(schema))
     }
 }
 
@@ -465,62 +1568,228 @@ impl FlattenRecordBatch for RecordBatch {
 pub trait TryIntoArrow<'a, ArrowArray, Element>
 where
     Self: IntoIterator<Item = &'a Element>,
-    Element: 'static,
+    Element: 'a,
 {
     /// Convert from any iterable collection into an `arrow::Array`
     fn try_into_arrow(self) -> arrow::error::Result<ArrowArray>
     where
-        Element: ArrowSerialize + ArrowField<Type = Element> + 'static;
+        Element: ArrowSerialize + ArrowField<Type = Element>;
 
     /// Convert from any iterable collection into an `arrow::Array` by coercing the conversion to a specific Arrow type.
     /// This is useful when the same rust type maps to one or more Arrow types for example `LargeString`.
     fn try_into_arrow_as_type<ArrowType>(self) -> arrow::error::Result<ArrowArray>
     where
-        ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static;
+        ArrowType: ArrowSerialize + ArrowField<Type = Element>;
+
+    /// Convert from any iterable collection into an `arrow::Array`, pre-sizing the builders with the capacity hints
+    /// of `options`, such as the number of rows of a large collection.
+    fn try_into_arrow_with_options(self, options: &SerializeOptions) -> arrow::error::Result<ArrowArray>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>;
 }
 
 impl<'a, Element, Collection> TryIntoArrow<'a, ArrayRef, Element> for Collection
 where
-    Element: 'static,
+    Element: 'a,
     Collection: IntoIterator<Item = &'a Element>,
 {
     fn try_into_arrow(self) -> arrow::error::Result<ArrayRef>
     where
-        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        Element: ArrowSerialize + ArrowField<Type = Element>,
     {
         Ok(arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.finish())
     }
 
     fn try_into_arrow_as_type<Field>(self) -> arrow::error::Result<ArrayRef>
     where
-        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        Field: ArrowSerialize + ArrowField<Type = Element>,
     {
         Ok(arrow_serialize_to_mutable_array::<Element, Field, Collection>(self)?.finish())
     }
+
+    fn try_into_arrow_with_options(self, options: &SerializeOptions) -> arrow::error::Result<ArrayRef>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        Ok(arrow_serialize_to_mutable_array_with_options::<Element, Element, Collection>(self, options)?.finish())
+    }
 }
 
 impl<'a, Element, Collection> TryIntoArrow<'a, RecordBatch, Element> for Collection
 where
-    Element: 'static,
+    Element: 'a,
     Collection: IntoIterator<Item = &'a Element>,
 {
     fn try_into_arrow(self) -> arrow::error::Result<RecordBatch>
     where
-        Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        Element: ArrowSerialize + ArrowField<Type = Element>,
     {
-        RecordBatch::try_from_iter([(
+        record_batch_with_metadata::<Element>(RecordBatch::try_from_iter([(
             "record_batch_item",
             arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.finish(),
-        )])
+        )])?)
     }
 
     fn try_into_arrow_as_type<Field>(self) -> arrow::error::Result<RecordBatch>
     where
-        Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
+        Field: ArrowSerialize + ArrowField<Type = Element>,
     {
-        RecordBatch::try_from_iter([(
+        record_batch_with_metadata::<Field>(RecordBatch::try_from_iter([(
             "record_batch_item",
             arrow_serialize_to_mutable_array::<Element, Field, Collection>(self)?.finish(),
-        )])
+        )])?)
+    }
+
+    fn try_into_arrow_with_options(self, options: &SerializeOptions) -> arrow::error::Result<RecordBatch>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        record_batch_with_metadata::<Element>(RecordBatch::try_from_iter([(
+            "record_batch_item",
+            arrow_serialize_to_mutable_array_with_options::<Element, Element, Collection>(self, options)?.finish(),
+        )])?)
+    }
+}
+
+/// Top-level API to serialize collections of structs to a `RecordBatch` whose columns are the fields of the struct
+pub trait TryIntoRecordBatch<'a, Element>
+where
+    Self: IntoIterator<Item = &'a Element>,
+    Element: 'a,
+{
+    /// Convert from any iterable collection of structs into an `arrow::record_batch::RecordBatch` with a column per
+    /// field and the schema metadata of the struct. Returns an error if the type isn't a struct or if a struct is null.
+    fn try_into_record_batch(self) -> arrow::error::Result<RecordBatch>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>;
+
+    /// Convert from any iterable collection into an `arrow::record_batch::RecordBatch` with a column per field by
+    /// coercing the conversion to a specific Arrow struct type, such as the mirror of a `remote` struct.
+    fn try_into_record_batch_as_type<ArrowType>(self) -> arrow::error::Result<RecordBatch>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element>;
+
+    /// Convert from any iterable collection of structs into an `arrow::record_batch::RecordBatch` with the target
+    /// `schema`, such as the schema of an existing dataset. The columns are matched to the fields of the schema by
+    /// name and reordered, and columns of another type are cast if no value changes meaning, such as `Utf8` to
+    /// `LargeUtf8` or nanosecond to millisecond timestamps. Returns an error listing every column that doesn't match.
+    fn try_into_arrow_with_schema(self, schema: &datatypes::Schema) -> arrow::error::Result<RecordBatch>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>;
+}
+
+impl<'a, Element, Collection> TryIntoRecordBatch<'a, Element> for Collection
+where
+    Element: 'a,
+    Collection: IntoIterator<Item = &'a Element>,
+{
+    fn try_into_record_batch(self) -> arrow::error::Result<RecordBatch>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        struct_record_batch::<Element>(arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.finish())
+    }
+
+    fn try_into_record_batch_as_type<Field>(self) -> arrow::error::Result<RecordBatch>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        struct_record_batch::<Field>(arrow_serialize_to_mutable_array::<Element, Field, Collection>(self)?.finish())
+    }
+
+    fn try_into_arrow_with_schema(self, schema: &datatypes::Schema) -> arrow::error::Result<RecordBatch>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        schema_cast::cast_to_schema(self.try_into_record_batch()?, schema)
+    }
+}
+
+/// Serializes the structs of `iter` into `RecordBatch`es of at most `max_rows_per_batch` rows, with a column per
+/// field like [`TryIntoRecordBatch::try_into_record_batch`], so that large collections are written in bounded
+/// batches. The values are serialized as the Arrow type `T`, which is usually the struct itself, and the iterator
+/// stops after the first error.
+///
+/// # Panics
+///
+/// Panics if `max_rows_per_batch` is 0.
+pub fn serialize_chunked<T, I>(
+    iter: I,
+    max_rows_per_batch: usize,
+) -> impl Iterator<Item = arrow::error::Result<RecordBatch>>
+where
+    T: ArrowSerialize,
+    I: IntoIterator,
+    I::Item: std::borrow::Borrow<<T as ArrowField>::Type>,
+{
+    assert!(
+        max_rows_per_batch > 0,
+        "max_rows_per_batch must be greater than 0"
+    );
+    let mut iter = iter.into_iter().fuse();
+    let mut serializer = TypedBatchSerializer::<T>::new();
+    let mut first_row = 0;
+    let mut failed = false;
+    std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        for item in iter.by_ref().take(max_rows_per_batch) {
+            let row = first_row + serializer.len();
+            if let Err(e) = serializer.push_row(std::borrow::Borrow::borrow(&item), row) {
+                failed = true;
+                return Some(Err(e));
+            }
+        }
+        if serializer.is_empty() {
+            return None;
+        }
+        first_row += serializer.len();
+        let batch = serializer.flush();
+        failed = batch.is_err();
+        Some(batch)
+    })
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "flight"))]
+/// The schema of the `RecordBatch`es serialized from the struct `T`, with a column per field and the
+/// [`ArrowField::schema_metadata`] of `T`.
+fn struct_schema<T: ArrowField>() -> arrow::error::Result<datatypes::Schema> {
+    match T::data_type() {
+        datatypes::DataType::Struct(fields) => Ok(datatypes::Schema::new(fields).with_metadata(T::schema_metadata())),
+        data_type => Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+            "Only structs can be converted to a RecordBatch, found {data_type}"
+        ))),
+    }
+}
+
+/// Converts the `StructArray` serialized from `T` to a `RecordBatch` of its columns, with the
+/// [`ArrowField::schema_metadata`] of `T`.
+fn struct_record_batch<T: ArrowField>(array: ArrayRef) -> arrow::error::Result<RecordBatch> {
+    let Some(array) = array.as_struct_opt() else {
+        return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+            "Only structs can be converted to a RecordBatch, found {}",
+            array.data_type()
+        )));
+    };
+    if array.null_count() > 0 {
+        return Err(arrow::error::ArrowError::InvalidArgumentError(
+            "Null structs can't be converted to a RecordBatch".to_string(),
+        ));
+    }
+    let (fields, columns, _) = array.clone().into_parts();
+    let schema = datatypes::Schema::new(fields).with_metadata(T::schema_metadata());
+    // structs without fields still have a number of rows
+    let options = arrow::record_batch::RecordBatchOptions::new().with_row_count(Some(array.len()));
+    RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+}
+
+/// Sets the schema metadata of `batch` to the [`ArrowField::schema_metadata`] of `T`.
+fn record_batch_with_metadata<T: ArrowField>(batch: RecordBatch) -> arrow::error::Result<RecordBatch> {
+    let metadata = T::schema_metadata();
+    if metadata.is_empty() {
+        return Ok(batch);
     }
+    let schema = batch.schema().as_ref().clone().with_metadata(metadata);
+    batch.with_schema(Arc::new(schema))
 }
@@ -0,0 +1,35 @@
+use std::borrow::Borrow;
+use std::io::Write;
+
+use arrow::json::{ArrayWriter, LineDelimitedWriter};
+
+use super::{ArrowSerialize, TypedWriter};
+use crate::field::ArrowField;
+
+/// Writes the structs of `rows` to `writer` as newline-delimited JSON, with an object per row whose keys are the
+/// names of the Arrow fields, so the derived types can also feed JSON consumers. Null values are omitted. `T` is the
+/// Arrow type the rows are serialized as, which is usually the struct itself.
+pub fn write_ndjson<T, W, I>(writer: W, rows: I) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let mut writer = TypedWriter::<T, _>::new(LineDelimitedWriter::new(writer));
+    writer.write_all(rows)?;
+    writer.close()
+}
+
+/// Writes the structs of `rows` to `writer` as a JSON array of objects, like [`write_ndjson`].
+pub fn write_json_array<T, W, I>(writer: W, rows: I) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let mut writer = TypedWriter::<T, _>::new(ArrayWriter::new(writer));
+    writer.write_all(rows)?;
+    writer.close()
+}
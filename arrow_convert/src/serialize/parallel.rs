@@ -0,0 +1,104 @@
+use arrow::array::{Array, ArrayBuilder, ArrayRef};
+use arrow::datatypes::DataType;
+use arrow::record_batch::RecordBatch;
+use rayon::prelude::*;
+
+use super::{
+    arrow_serialize_extend_internal, arrow_serialize_to_mutable_array, record_batch_with_metadata, ArrowSerialize,
+};
+use crate::field::ArrowField;
+
+/// The minimum number of values serialized by a task, below which splitting costs more than it saves.
+const MIN_CHUNK_LEN: usize = 1024;
+
+/// Top-level API to serialize slices to Arrow on the rayon thread pool, for CPU-bound serialization of large slices
+///
+/// The slice is split into a chunk per thread, each chunk is serialized into its own array, and the arrays are
+/// concatenated.
+pub trait ParTryIntoArrow<ArrowArray, Element> {
+    /// Convert a slice into an `arrow::Array` in parallel, like [`super::TryIntoArrow::try_into_arrow`].
+    fn par_try_into_arrow(&self) -> arrow::error::Result<ArrowArray>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>;
+
+    /// Convert a slice into an `arrow::Array` in parallel by coercing the conversion to a specific Arrow type, like
+    /// [`super::TryIntoArrow::try_into_arrow_as_type`].
+    fn par_try_into_arrow_as_type<ArrowType>(&self) -> arrow::error::Result<ArrowArray>
+    where
+        ArrowType: ArrowSerialize + ArrowField<Type = Element>;
+}
+
+impl<Element: Sync> ParTryIntoArrow<ArrayRef, Element> for [Element] {
+    fn par_try_into_arrow(&self) -> arrow::error::Result<ArrayRef>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        par_serialize::<Element, Element>(self)
+    }
+
+    fn par_try_into_arrow_as_type<Field>(&self) -> arrow::error::Result<ArrayRef>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        par_serialize::<Element, Field>(self)
+    }
+}
+
+impl<Element: Sync> ParTryIntoArrow<RecordBatch, Element> for [Element] {
+    fn par_try_into_arrow(&self) -> arrow::error::Result<RecordBatch>
+    where
+        Element: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        record_batch_with_metadata::<Element>(RecordBatch::try_from_iter([(
+            "record_batch_item",
+            par_serialize::<Element, Element>(self)?,
+        )])?)
+    }
+
+    fn par_try_into_arrow_as_type<Field>(&self) -> arrow::error::Result<RecordBatch>
+    where
+        Field: ArrowSerialize + ArrowField<Type = Element>,
+    {
+        record_batch_with_metadata::<Field>(RecordBatch::try_from_iter([(
+            "record_batch_item",
+            par_serialize::<Element, Field>(self)?,
+        )])?)
+    }
+}
+
+/// Serializes the chunks of `values` as `T` on the rayon thread pool and concatenates the arrays.
+///
+/// Types whose arrays can't be concatenated are serialized in a single pass.
+fn par_serialize<A: Sync, T: ArrowSerialize + ArrowField<Type = A>>(values: &[A]) -> arrow::error::Result<ArrayRef> {
+    let chunk_len = values.len().div_ceil(rayon::current_num_threads()).max(MIN_CHUNK_LEN);
+    if values.len() <= chunk_len || !is_concatenable(&<T as ArrowField>::data_type()) {
+        return Ok(arrow_serialize_to_mutable_array::<A, T, _>(values)?.finish());
+    }
+    let arrays = values
+        .par_chunks(chunk_len)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut array = <T as ArrowSerialize>::new_array();
+            arrow_serialize_extend_internal::<A, T, _>(chunk, &mut array, i * chunk_len)?;
+            Ok(array.finish())
+        })
+        .collect::<arrow::error::Result<Vec<_>>>()?;
+    let arrays = arrays.iter().map(|a| a.as_ref()).collect::<Vec<&dyn Array>>();
+    arrow::compute::concat(&arrays)
+}
+
+/// Returns whether `concat` supports arrays of `data_type`, which excludes run-end encoded and list view arrays at
+/// any depth.
+fn is_concatenable(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::RunEndEncoded(_, _) | DataType::ListView(_) | DataType::LargeListView(_) => false,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => is_concatenable(field.data_type()),
+        DataType::Struct(fields) => fields.iter().all(|f| is_concatenable(f.data_type())),
+        DataType::Union(fields, _) => fields.iter().all(|(_, f)| is_concatenable(f.data_type())),
+        DataType::Dictionary(_, value_type) => is_concatenable(value_type),
+        _ => true,
+    }
+}
@@ -0,0 +1,113 @@
+use std::borrow::Borrow;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::error::ArrowError;
+use parquet::arrow::{ArrowSchemaConverter, ArrowWriter};
+use parquet::basic::{BrotliLevel, Compression, Encoding, GzipLevel, ZstdLevel};
+use parquet::file::properties::{WriterProperties, WriterPropertiesBuilder};
+
+use super::{struct_schema, ArrowSerialize, TypedWriter};
+use crate::field::ArrowField;
+
+/// Writes the structs of `rows` to a new Parquet file at `path`, with a column per field and the schema of `T`. The
+/// rows are serialized in batches of [`super::DEFAULT_MAX_ROWS_PER_BATCH`] rows, and the row groups and compression
+/// follow `properties`. `T` is the Arrow type the rows are serialized as, which is usually the struct itself.
+///
+/// Without `properties`, the columns are written with the [`ParquetHint`](crate::field::ParquetHint)s of the fields
+/// of `T`. Properties built by [`with_parquet_hints`] keep them.
+pub fn write_parquet_file<T, I>(
+    path: impl AsRef<Path>,
+    rows: I,
+    properties: Option<WriterProperties>,
+) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    write_parquet::<T, _, _>(File::create(path)?, rows, properties)
+}
+
+/// Writes the structs of `rows` to `writer` as a Parquet file, like [`write_parquet_file`].
+pub fn write_parquet<T, W, I>(writer: W, rows: I, properties: Option<WriterProperties>) -> arrow::error::Result<()>
+where
+    T: ArrowSerialize,
+    W: Write + Send,
+    I: IntoIterator,
+    I::Item: Borrow<<T as ArrowField>::Type>,
+{
+    let properties = match properties {
+        Some(properties) => properties,
+        None => with_parquet_hints::<T>(WriterProperties::builder())?.build(),
+    };
+    let schema = Arc::new(struct_schema::<T>()?);
+    let mut writer = TypedWriter::<T, _>::new(ArrowWriter::try_new(writer, schema, Some(properties))?);
+    writer.write_all(rows)?;
+    writer.close()
+}
+
+/// Sets the Parquet writer settings of the columns of the fields of `T` in `builder`, from their
+/// [`ParquetHint`](crate::field::ParquetHint)s.
+///
+/// A hint applies to every Parquet column of its field, such as the columns of a nested struct or the values of a
+/// list, and the hints of nested fields take precedence. Codecs that require a level, such as `zstd`, use their
+/// default level when it's not set.
+pub fn with_parquet_hints<T: ArrowField>(
+    mut builder: WriterPropertiesBuilder,
+) -> arrow::error::Result<WriterPropertiesBuilder> {
+    let hints = T::parquet_hints();
+    if hints.is_empty() {
+        return Ok(builder);
+    }
+    let columns = ArrowSchemaConverter::new().convert(&struct_schema::<T>()?)?;
+    for hint in hints {
+        let error = |e: parquet::errors::ParquetError| {
+            ArrowError::InvalidArgumentError(format!(
+                "Invalid Parquet hint of `{}`: {e}",
+                hint.path.join(".")
+            ))
+        };
+        let compression = hint.compression.map(parse_compression).transpose().map_err(error)?;
+        let encoding = hint.encoding.map(str::parse::<Encoding>).transpose().map_err(error)?;
+        let mut found = false;
+        for column in columns.columns() {
+            if !column.path().parts().starts_with(&hint.path) {
+                continue;
+            }
+            found = true;
+            let path = column.path().clone();
+            if let Some(compression) = compression {
+                builder = builder.set_column_compression(path.clone(), compression);
+            }
+            if let Some(encoding) = encoding {
+                builder = builder.set_column_encoding(path.clone(), encoding);
+            }
+            if let Some(dictionary) = hint.dictionary {
+                builder = builder.set_column_dictionary_enabled(path.clone(), dictionary);
+            }
+            if let Some(bloom_filter) = hint.bloom_filter {
+                builder = builder.set_column_bloom_filter_enabled(path, bloom_filter);
+            }
+        }
+        if !found {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The Parquet hint of `{}` doesn't match a column",
+                hint.path.join(".")
+            )));
+        }
+    }
+    Ok(builder)
+}
+
+/// Parses a Parquet codec, with the default level of the codecs that require one when it's not set
+fn parse_compression(codec: &str) -> parquet::errors::Result<Compression> {
+    match codec {
+        "gzip" => Ok(Compression::GZIP(GzipLevel::default())),
+        "brotli" => Ok(Compression::BROTLI(BrotliLevel::default())),
+        "zstd" => Ok(Compression::ZSTD(ZstdLevel::default())),
+        codec => codec.parse(),
+    }
+}
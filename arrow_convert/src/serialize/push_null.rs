@@ -1,10 +1,11 @@
 use arrow::{
     array::{
         ArrayBuilder, BinaryBuilder, BooleanBufferBuilder, BooleanBuilder, FixedSizeBinaryBuilder,
-        FixedSizeListBuilder, LargeBinaryBuilder, LargeListBuilder, LargeStringBuilder, ListBuilder, PrimitiveBuilder,
-        StringBuilder,
+        FixedSizeListBuilder, GenericByteDictionaryBuilder, GenericByteRunBuilder, GenericByteViewBuilder,
+        GenericListViewBuilder, LargeBinaryBuilder, LargeListBuilder, LargeStringBuilder, ListBuilder, MapBuilder,
+        NullBuilder, OffsetSizeTrait, PrimitiveBuilder, PrimitiveDictionaryBuilder, PrimitiveRunBuilder, StringBuilder,
     },
-    datatypes::ArrowPrimitiveType,
+    datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType, ByteArrayType, ByteViewType, RunEndIndexType},
 };
 
 /// Trait for appending null values to an array builder.
@@ -54,6 +55,36 @@ impl PushNull for StringBuilder {
     }
 }
 
+impl<T: ByteViewType + ?Sized> PushNull for GenericByteViewBuilder<T> {
+    fn push_null(&mut self) {
+        GenericByteViewBuilder::<T>::append_null(self);
+    }
+}
+
+impl<K: ArrowDictionaryKeyType, T: ByteArrayType> PushNull for GenericByteDictionaryBuilder<K, T> {
+    fn push_null(&mut self) {
+        GenericByteDictionaryBuilder::<K, T>::append_null(self);
+    }
+}
+
+impl<K: ArrowDictionaryKeyType, V: ArrowPrimitiveType> PushNull for PrimitiveDictionaryBuilder<K, V> {
+    fn push_null(&mut self) {
+        PrimitiveDictionaryBuilder::<K, V>::append_null(self);
+    }
+}
+
+impl<R: RunEndIndexType, V: ByteArrayType> PushNull for GenericByteRunBuilder<R, V> {
+    fn push_null(&mut self) {
+        GenericByteRunBuilder::<R, V>::append_null(self);
+    }
+}
+
+impl<R: RunEndIndexType, V: ArrowPrimitiveType> PushNull for PrimitiveRunBuilder<R, V> {
+    fn push_null(&mut self) {
+        PrimitiveRunBuilder::<R, V>::append_null(self);
+    }
+}
+
 impl<T: ArrayBuilder> PushNull for ListBuilder<T> {
     fn push_null(&mut self) {
         ListBuilder::<T>::append_null(self);
@@ -66,12 +97,30 @@ impl<T: ArrayBuilder> PushNull for LargeListBuilder<T> {
     }
 }
 
+impl<O: OffsetSizeTrait, T: ArrayBuilder> PushNull for GenericListViewBuilder<O, T> {
+    fn push_null(&mut self) {
+        GenericListViewBuilder::<O, T>::append_null(self);
+    }
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> PushNull for MapBuilder<K, V> {
+    fn push_null(&mut self) {
+        MapBuilder::<K, V>::append(self, false).unwrap();
+    }
+}
+
 impl<T: ArrowPrimitiveType> PushNull for PrimitiveBuilder<T> {
     fn push_null(&mut self) {
         PrimitiveBuilder::<T>::append_null(self);
     }
 }
 
+impl PushNull for NullBuilder {
+    fn push_null(&mut self) {
+        self.append_null();
+    }
+}
+
 impl PushNull for BooleanBuilder {
     fn push_null(&mut self) {
         BooleanBuilder::append_null(self)
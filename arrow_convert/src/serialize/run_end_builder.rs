@@ -0,0 +1,63 @@
+use std::any::Any;
+use std::fmt;
+
+use arrow::array::{ArrayBuilder, ArrayRef};
+
+use super::PushNull;
+
+/// Builder for an Arrow `RunEndEncoded` array.
+///
+/// This wraps one of the arrow run builders, since not all of them implement `Debug`.
+pub struct RunEndBuilder<B: ArrayBuilder> {
+    inner: B,
+}
+
+impl<B: ArrayBuilder> RunEndBuilder<B> {
+    /// Creates a new `RunEndBuilder` wrapping the provided run builder
+    pub fn new(inner: B) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped run builder
+    pub fn inner(&mut self) -> &mut B {
+        &mut self.inner
+    }
+}
+
+impl<B: ArrayBuilder> fmt::Debug for RunEndBuilder<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunEndBuilder").field("len", &self.inner.len()).finish()
+    }
+}
+
+impl<B: ArrayBuilder> ArrayBuilder for RunEndBuilder<B> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        self.inner.finish()
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        self.inner.finish_cloned()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<B: ArrayBuilder + PushNull> PushNull for RunEndBuilder<B> {
+    fn push_null(&mut self) {
+        self.inner.push_null();
+    }
+}
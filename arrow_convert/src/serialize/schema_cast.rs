@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef};
+use arrow::compute::{cast_with_options, CastOptions};
+use arrow::datatypes::{DataType, Schema};
+use arrow::error::ArrowError;
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+
+/// Converts `batch` to the target `schema`, by selecting its columns by name in the order of the schema and casting
+/// the columns whose types differ with [`is_safe_cast`]. All the differences that can't be converted are listed in
+/// the error.
+pub(super) fn cast_to_schema(batch: RecordBatch, schema: &Schema) -> arrow::error::Result<RecordBatch> {
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(schema.fields().len());
+    let mut diff = vec![];
+    for field in schema.fields() {
+        let Some(column) = batch.column_by_name(field.name()) else {
+            diff.push(format!(
+                "missing column `{}` of type {}",
+                field.name(),
+                field.data_type()
+            ));
+            continue;
+        };
+        if !field.is_nullable() && column.null_count() > 0 {
+            diff.push(format!(
+                "column `{}` has nulls but isn't nullable",
+                field.name()
+            ));
+            continue;
+        }
+        if column.data_type() == field.data_type() {
+            columns.push(column.clone());
+        } else if is_safe_cast(column.data_type(), field.data_type()) {
+            // values that don't fit the target type are errors rather than nulls
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
+            match cast_with_options(column, field.data_type(), &options) {
+                Ok(column) => columns.push(column),
+                Err(e) => diff.push(format!("column `{}`: {e}", field.name())),
+            }
+        } else {
+            diff.push(format!(
+                "column `{}` of type {} can't be cast to {}",
+                field.name(),
+                column.data_type(),
+                field.data_type()
+            ));
+        }
+    }
+    for field in batch.schema().fields() {
+        if schema.field_with_name(field.name()).is_err() {
+            diff.push(format!(
+                "unexpected column `{}` of type {}",
+                field.name(),
+                field.data_type()
+            ));
+        }
+    }
+    if !diff.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The serialized columns don't match the target schema: {}",
+            diff.join(", ")
+        )));
+    }
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(Arc::new(schema.clone()), columns, &options)
+}
+
+/// Whether values of type `from` can be cast to `to` without changing their meaning: widening integers and floats,
+/// larger offsets or views for strings, binaries and lists, dates and timestamps with another unit, and dictionaries
+/// of the same values.
+fn is_safe_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    if from == to {
+        return true;
+    }
+    match (from, to) {
+        (Int8, Int16 | Int32 | Int64 | Float32 | Float64)
+        | (Int16, Int32 | Int64 | Float32 | Float64)
+        | (Int32, Int64 | Float64)
+        | (UInt8, UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float32 | Float64)
+        | (UInt16, UInt32 | UInt64 | Int32 | Int64 | Float32 | Float64)
+        | (UInt32, UInt64 | Int64 | Float64)
+        | (Float16, Float32 | Float64)
+        | (Float32, Float64)
+        | (Utf8, LargeUtf8 | Utf8View)
+        | (Binary, LargeBinary | BinaryView)
+        | (Date32, Date64) => true,
+        (Timestamp(_, from_tz), Timestamp(_, to_tz)) => from_tz == to_tz,
+        (List(from) | LargeList(from), LargeList(to)) | (List(from), List(to)) => {
+            (to.is_nullable() || !from.is_nullable()) && is_safe_cast(from.data_type(), to.data_type())
+        }
+        (Dictionary(_, from), Dictionary(_, to)) => is_safe_cast(from, to),
+        (Dictionary(_, from), to) => is_safe_cast(from, to),
+        (from, Dictionary(_, to)) => is_safe_cast(from, to),
+        _ => false,
+    }
+}
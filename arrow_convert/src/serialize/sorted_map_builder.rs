@@ -0,0 +1,79 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayBuilder, ArrayRef, MapArray, MapBuilder};
+
+use super::PushNull;
+
+/// Builder for an Arrow `Map` array whose keys are sorted within each entry.
+///
+/// This wraps [`MapBuilder`], which always emits unsorted maps, and flags the finished
+/// array as sorted. Callers are responsible for appending keys in sorted order.
+#[derive(Debug)]
+pub struct SortedMapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
+    inner: MapBuilder<K, V>,
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> SortedMapBuilder<K, V> {
+    /// Creates a new `SortedMapBuilder` wrapping the provided `MapBuilder`
+    pub fn new(inner: MapBuilder<K, V>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the key array builder of the map
+    pub fn keys(&mut self) -> &mut K {
+        self.inner.keys()
+    }
+
+    /// Returns the value array builder of the map
+    pub fn values(&mut self) -> &mut V {
+        self.inner.values()
+    }
+
+    /// Finish the current map array slot
+    pub fn append(&mut self, is_valid: bool) -> arrow::error::Result<()> {
+        self.inner.append(is_valid)
+    }
+
+    /// Returns the wrapped `MapBuilder`
+    pub(crate) fn inner(&mut self) -> &mut MapBuilder<K, V> {
+        &mut self.inner
+    }
+
+    fn into_sorted(map: MapArray) -> MapArray {
+        let (field, offsets, entries, nulls, _) = map.into_parts();
+        MapArray::new(field, offsets, entries, nulls, true)
+    }
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for SortedMapBuilder<K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Self::into_sorted(self.inner.finish()))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Self::into_sorted(self.inner.finish_cloned()))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> PushNull for SortedMapBuilder<K, V> {
+    fn push_null(&mut self) {
+        self.inner.push_null();
+    }
+}
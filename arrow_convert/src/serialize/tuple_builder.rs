@@ -0,0 +1,120 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayBuilder, ArrayRef, NullBufferBuilder, StructArray};
+use arrow::datatypes::{DataType, Fields};
+
+use super::{ArrowSerialize, PushNull};
+use crate::field::ArrowField;
+
+/// Builder for an Arrow `Struct` array holding rust tuples.
+///
+/// `B` is a tuple of the array builders of each tuple element, one child builder per struct field.
+#[derive(Debug)]
+pub struct TupleBuilder<B> {
+    fields: Fields,
+    builders: B,
+    validity: NullBufferBuilder,
+}
+
+impl<B> TupleBuilder<B> {
+    /// Creates a new `TupleBuilder` from the struct fields and the child builders
+    pub fn new(fields: Fields, builders: B) -> Self {
+        Self {
+            fields,
+            builders,
+            validity: NullBufferBuilder::new(0),
+        }
+    }
+
+    /// Returns the child array builders
+    pub fn values(&mut self) -> &mut B {
+        &mut self.builders
+    }
+
+    /// Finish the current struct array slot
+    pub fn append(&mut self, is_valid: bool) {
+        self.validity.append(is_valid);
+    }
+}
+
+// Macro to implement the builder and serialization traits for each tuple arity.
+macro_rules! impl_tuple_builder {
+    ($($name:ident $builder:ident $index:tt),+) => {
+        impl<$($builder),+> ArrayBuilder for TupleBuilder<($($builder,)+)>
+        where
+            $($builder: ArrayBuilder,)+
+        {
+            fn len(&self) -> usize {
+                self.validity.len()
+            }
+
+            fn finish(&mut self) -> ArrayRef {
+                let values = vec![$(self.builders.$index.finish(),)+];
+                Arc::new(StructArray::new(self.fields.clone(), values, self.validity.finish()))
+            }
+
+            fn finish_cloned(&self) -> ArrayRef {
+                let values = vec![$(self.builders.$index.finish_cloned(),)+];
+                Arc::new(StructArray::new(self.fields.clone(), values, self.validity.finish_cloned()))
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+        }
+
+        impl<$($builder),+> PushNull for TupleBuilder<($($builder,)+)>
+        where
+            $($builder: ArrayBuilder + PushNull,)+
+        {
+            fn push_null(&mut self) {
+                $(self.builders.$index.push_null();)+
+                self.validity.append_null();
+            }
+        }
+
+        impl<$($name),+> ArrowSerialize for ($($name,)+)
+        where
+            $($name: ArrowSerialize,)+
+        {
+            type ArrayBuilderType = TupleBuilder<($(<$name as ArrowSerialize>::ArrayBuilderType,)+)>;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                let DataType::Struct(fields) = <Self as ArrowField>::data_type() else {
+                    panic!("datatype is not struct")
+                };
+                TupleBuilder::new(fields, ($(<$name as ArrowSerialize>::new_array(),)+))
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &<Self as ArrowField>::Type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                $(<$name as ArrowSerialize>::arrow_serialize(&v.$index, &mut array.values().$index)?;)+
+                array.append(true);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple_builder!(A BA 0);
+impl_tuple_builder!(A BA 0, B BB 1);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5, G BG 6);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5, G BG 6, H BH 7);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5, G BG 6, H BH 7, I BI 8);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5, G BG 6, H BH 7, I BI 8, J BJ 9);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5, G BG 6, H BH 7, I BI 8, J BJ 9, K BK 10);
+impl_tuple_builder!(A BA 0, B BB 1, C BC 2, D BD 3, E BE 4, F BF 5, G BG 6, H BH 7, I BI 8, J BJ 9, K BK 10, L BL 11);
@@ -0,0 +1,129 @@
+use std::borrow::Borrow;
+use std::fmt;
+
+use arrow::record_batch::RecordBatchWriter;
+
+use super::{ArrowSerialize, TypedBatchSerializer};
+use crate::field::ArrowField;
+
+/// The default number of rows of the batches written by a [`TypedWriter`]
+pub const DEFAULT_MAX_ROWS_PER_BATCH: usize = 8192;
+
+/// Writes structs to any arrow `RecordBatchWriter`, such as an IPC or Parquet writer, by serializing them into
+/// `RecordBatch`es with a column per field and writing a batch every `max_rows_per_batch` rows.
+///
+/// `T` is the Arrow type the rows are serialized as, which is usually the struct itself. The pending rows are written
+/// and the writer is closed by [`TypedWriter::close`], or when the `TypedWriter` is dropped, in which case errors are
+/// ignored.
+pub struct TypedWriter<T: ArrowSerialize, W: RecordBatchWriter> {
+    serializer: TypedBatchSerializer<T>,
+    // only taken when the writer is closed
+    writer: Option<W>,
+    max_rows_per_batch: usize,
+    rows: usize,
+}
+
+impl<T: ArrowSerialize, W: RecordBatchWriter> TypedWriter<T, W> {
+    /// Creates a new `TypedWriter` writing batches of [`DEFAULT_MAX_ROWS_PER_BATCH`] rows to `writer`
+    pub fn new(writer: W) -> Self {
+        Self {
+            serializer: TypedBatchSerializer::new(),
+            writer: Some(writer),
+            max_rows_per_batch: DEFAULT_MAX_ROWS_PER_BATCH,
+            rows: 0,
+        }
+    }
+
+    /// Sets the number of rows after which a batch is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rows_per_batch` is 0.
+    pub fn with_max_rows_per_batch(mut self, max_rows_per_batch: usize) -> Self {
+        assert!(
+            max_rows_per_batch > 0,
+            "max_rows_per_batch must be greater than 0"
+        );
+        self.max_rows_per_batch = max_rows_per_batch;
+        self
+    }
+
+    /// Serializes a row, and writes a batch if it has `max_rows_per_batch` rows. Errors name the index of the row
+    /// among the rows written.
+    ///
+    /// A row that fails to serialize may have been partially appended, so it also discards the rows written since
+    /// the last batch, which are no longer counted by [`TypedWriter::rows`]. Call [`TypedWriter::flush`] to write
+    /// the pending rows before writing rows that may fail.
+    pub fn write(&mut self, row: &<T as ArrowField>::Type) -> arrow::error::Result<()> {
+        let pending = self.serializer.len();
+        if let Err(e) = self.serializer.push_row(row, self.rows) {
+            self.rows -= pending;
+            return Err(e);
+        }
+        self.rows += 1;
+        if self.serializer.len() >= self.max_rows_per_batch {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Serializes each of `rows` with [`TypedWriter::write`], such as the items of a `Vec` or of an iterator of
+    /// references.
+    pub fn write_all<I>(&mut self, rows: I) -> arrow::error::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<<T as ArrowField>::Type>,
+    {
+        for row in rows {
+            self.write(row.borrow())?;
+        }
+        Ok(())
+    }
+
+    /// Writes the rows that aren't yet written as a batch, if any
+    pub fn flush(&mut self) -> arrow::error::Result<()> {
+        if self.serializer.is_empty() {
+            return Ok(());
+        }
+        let batch = self.serializer.flush()?;
+        self.writer
+            .as_mut()
+            .expect("the writer is only taken when closed")
+            .write(&batch)
+    }
+
+    /// The number of rows written, including those not yet written as a batch, but not those discarded by an error
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Writes the pending rows and closes the writer
+    pub fn close(mut self) -> arrow::error::Result<()> {
+        self.flush()?;
+        self.writer
+            .take()
+            .expect("the writer is only taken when closed")
+            .close()
+    }
+}
+
+impl<T: ArrowSerialize, W: RecordBatchWriter> Drop for TypedWriter<T, W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            let _ = self.flush();
+            if let Some(writer) = self.writer.take() {
+                let _ = writer.close();
+            }
+        }
+    }
+}
+
+impl<T: ArrowSerialize, W: RecordBatchWriter> fmt::Debug for TypedWriter<T, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedWriter")
+            .field("serializer", &self.serializer)
+            .field("max_rows_per_batch", &self.max_rows_per_batch)
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
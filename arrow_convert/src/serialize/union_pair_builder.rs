@@ -0,0 +1,125 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayBuilder, ArrayRef, UnionArray};
+use arrow::buffer::ScalarBuffer;
+use arrow::datatypes::{UnionFields, UnionMode};
+
+use super::PushNull;
+
+/// Builder for an Arrow `Union` array with exactly two variants, with the type ids `0` and `1`.
+///
+/// `L` and `R` are the array builders of the first and second variant. A value is appended by pushing it
+/// to the child builder returned by [`UnionPairBuilder::left`] or [`UnionPairBuilder::right`], followed by
+/// a call to [`UnionPairBuilder::append_left`] or [`UnionPairBuilder::append_right`] respectively.
+#[derive(Debug)]
+pub struct UnionPairBuilder<L, R> {
+    fields: UnionFields,
+    left: L,
+    right: R,
+    type_ids: Vec<i8>,
+    // only present for dense unions
+    offsets: Option<Vec<i32>>,
+}
+
+impl<L, R> UnionPairBuilder<L, R>
+where
+    L: ArrayBuilder + PushNull,
+    R: ArrayBuilder + PushNull,
+{
+    /// Creates a new `UnionPairBuilder` from the union fields, the union mode and the child builders
+    pub fn new(fields: UnionFields, mode: UnionMode, left: L, right: R) -> Self {
+        Self {
+            fields,
+            left,
+            right,
+            type_ids: vec![],
+            offsets: match mode {
+                UnionMode::Dense => Some(vec![]),
+                UnionMode::Sparse => None,
+            },
+        }
+    }
+
+    /// Returns the builder of the first variant
+    pub fn left(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Returns the builder of the second variant
+    pub fn right(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Finish the current union slot, holding the value last pushed to the first variant
+    pub fn append_left(&mut self) {
+        self.type_ids.push(0);
+        match self.offsets.as_mut() {
+            Some(offsets) => offsets.push((self.left.len() - 1) as i32),
+            None => self.right.push_null(),
+        }
+    }
+
+    /// Finish the current union slot, holding the value last pushed to the second variant
+    pub fn append_right(&mut self) {
+        self.type_ids.push(1);
+        match self.offsets.as_mut() {
+            Some(offsets) => offsets.push((self.right.len() - 1) as i32),
+            None => self.left.push_null(),
+        }
+    }
+}
+
+impl<L, R> ArrayBuilder for UnionPairBuilder<L, R>
+where
+    L: ArrayBuilder + PushNull,
+    R: ArrayBuilder + PushNull,
+{
+    fn len(&self) -> usize {
+        self.type_ids.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        let type_ids = ScalarBuffer::from_iter(std::mem::take(&mut self.type_ids));
+        let offsets = self
+            .offsets
+            .as_mut()
+            .map(|o| ScalarBuffer::from_iter(std::mem::take(o)));
+        let children = vec![self.left.finish(), self.right.finish()];
+        Arc::new(UnionArray::try_new(self.fields.clone(), type_ids, offsets, children).unwrap())
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        let type_ids = self.type_ids.iter().cloned().collect::<ScalarBuffer<i8>>();
+        let offsets = self
+            .offsets
+            .as_ref()
+            .map(|o| o.iter().cloned().collect::<ScalarBuffer<i32>>());
+        let children = vec![self.left.finish_cloned(), self.right.finish_cloned()];
+        Arc::new(UnionArray::try_new(self.fields.clone(), type_ids, offsets, children).unwrap())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<L, R> PushNull for UnionPairBuilder<L, R>
+where
+    L: ArrayBuilder + PushNull,
+    R: ArrayBuilder + PushNull,
+{
+    // Arrow unions have no validity of their own, so a null is stored as a null of the first variant
+    fn push_null(&mut self) {
+        self.left.push_null();
+        self.append_left();
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec_roundtrip() {
+    use arrayvec::{ArrayString, ArrayVec};
+    use arrow::array::{Array, ArrayRef, ListArray, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        values: ArrayVec<i32, 4>,
+        code: ArrayString<8>,
+        codes: Vec<ArrayString<4>>,
+    }
+
+    let original: Vec<ArrayVec<i32, 4>> = vec![
+        ArrayVec::from([1, 2, 3, 4]),
+        ArrayVec::new(),
+        [5, 6].into_iter().collect(),
+    ];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    assert!(arrow_array.as_any().is::<ListArray>());
+    let roundtrip: Vec<ArrayVec<i32, 4>> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+
+    let original: Vec<Option<ArrayString<8>>> = vec![Some(ArrayString::from("abc").unwrap()), None];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    assert!(arrow_array.as_any().is::<StringArray>());
+    let roundtrip: Vec<Option<ArrayString<8>>> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+
+    let original = vec![S {
+        values: [7, 8, 9].into_iter().collect(),
+        code: ArrayString::from("AU").unwrap(),
+        codes: vec![ArrayString::from("NSW").unwrap(), ArrayString::from("VIC").unwrap()],
+    }];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(arrow_array.len(), 1);
+    let roundtrip: Vec<S> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec_exceeds_capacity() {
+    use arrayvec::{ArrayString, ArrayVec};
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wide {
+        values: Vec<Vec<i32>>,
+        code: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Narrow {
+        values: Vec<ArrayVec<i32, 2>>,
+        code: ArrayString<2>,
+    }
+
+    let original = vec![vec![1], vec![1, 2, 3]];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<ArrayVec<i32, 2>>> = arrow_array.clone().try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Expected at most 2 elements deserializing array of type `i32`, got 3"));
+
+    // the rows of a sliced array within the capacity are deserialized
+    let roundtrip: Vec<ArrayVec<i32, 2>> = arrow_array.slice(0, 1).try_into_collection().unwrap();
+    assert_eq!(roundtrip, vec![ArrayVec::from_iter([1])]);
+
+    let original = vec!["ab".to_string(), "abc".to_string()];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<ArrayString<2>>> = arrow_array.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Expected at most 2 bytes deserializing `ArrayString<2>`, got 3"));
+
+    // nested values are checked as well, prefixed by the field name
+    let original = vec![Wide {
+        values: vec![vec![1], vec![1, 2, 3]],
+        code: "ab".to_string(),
+    }];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Narrow>> = arrow_array.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("values: Expected at most 2 elements deserializing array of type `i32`, got 3"));
+
+    let original = vec![Wide {
+        values: vec![],
+        code: "abc".to_string(),
+    }];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Narrow>> = arrow_array.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("code: Expected at most 2 bytes deserializing `ArrayString<2>`, got 3"));
+
+    let original = vec![(1, "abc".to_string())];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<(i32, ArrayString<2>)>> = arrow_array.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("1: Expected at most 2 bytes deserializing `ArrayString<2>`, got 3"));
+
+    let original = vec![std::collections::BTreeMap::from([(1, "abc".to_string())])];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<std::collections::BTreeMap<i32, ArrayString<2>>>> =
+        arrow_array.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("values: Expected at most 2 bytes deserializing `ArrayString<2>`, got 3"));
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec_validate() {
+    use arrayvec::ArrayVec;
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    let original: Vec<ArrayVec<Positive, 2>> = vec![[Positive { v: 1 }, Positive { v: 0 }].into()];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<ArrayVec<Positive, 2>>> = arrow_array.try_into_collection();
+    assert!(result.unwrap_err().to_string().ends_with("[1]: v: 0 is not positive"));
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "async")]
+#[test]
+fn test_batch_sink() {
+    use arrow::array::AsArray;
+    use arrow::datatypes::Int64Type;
+    use arrow::error::ArrowError;
+    use arrow::record_batch::RecordBatch;
+    use arrow_convert::serialize::BatchSink;
+    use arrow_convert::{ArrowField, ArrowSerialize};
+    use futures::channel::mpsc;
+    use futures::{SinkExt, StreamExt};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+    }
+
+    fn ids(batches: &[RecordBatch]) -> Vec<Vec<i64>> {
+        batches
+            .iter()
+            .map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect()
+    }
+
+    futures::executor::block_on(async {
+        // the batches are yielded by the receiver of a channel
+        let (sender, receiver) = mpsc::unbounded();
+        let sender = sender.sink_map_err(|e| ArrowError::ExternalError(Box::new(e)));
+        let mut sink = BatchSink::<Row, _>::new(sender).with_max_rows_per_batch(2);
+        for id in 0..3 {
+            sink.feed(Row { id }).await.unwrap();
+        }
+        // sending flushes the pending rows
+        let row = Row { id: 3 };
+        sink.send(&row).await.unwrap();
+        sink.feed(Row { id: 4 }).await.unwrap();
+        <BatchSink<Row, _> as SinkExt<Row>>::close(&mut sink).await.unwrap();
+        drop(sink);
+
+        let batches = receiver.collect::<Vec<_>>().await;
+        assert_eq!(ids(&batches), vec![vec![0, 1], vec![2, 3], vec![4]]);
+        assert_eq!(batches[0].schema(), Row::arrow_schema());
+    });
+}
@@ -0,0 +1,1814 @@
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+#[test]
+fn test_field_name() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Totals {
+        #[arrow_field(name = "user-id")]
+        user_id: u64,
+        #[arrow_field(name = "2024_total")]
+        total_2024: f64,
+        #[arrow_field(type = "arrow_convert::field::LargeString", name = "Comment")]
+        comment: String,
+    }
+
+    assert_eq!(
+        *Totals::arrow_schema(),
+        Schema::new(vec![
+            Field::new("user-id", DataType::UInt64, false),
+            Field::new("2024_total", DataType::Float64, false),
+            Field::new("Comment", DataType::LargeUtf8, false),
+        ])
+    );
+
+    let original = vec![Totals {
+        user_id: 1,
+        total_2024: 10.5,
+        comment: "ok".to_string(),
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(arr.column_names(), vec!["user-id", "2024_total", "Comment"]);
+    let round_trip: Vec<Totals> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_variant_name() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum Reading {
+        #[arrow_field(name = "celsius-value")]
+        Celsius(f64),
+        Missing,
+    }
+
+    assert_eq!(
+        <Reading as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Union(
+            UnionFields::new(
+                vec![0, 1],
+                vec![
+                    Field::new("celsius-value", DataType::Float64, false),
+                    Field::new("Missing", DataType::Boolean, false),
+                ]
+            ),
+            UnionMode::Dense
+        )
+    );
+
+    let original = vec![Reading::Celsius(21.5), Reading::Missing];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Reading> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_rename_all() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(rename_all = "camelCase")]
+    struct Camel {
+        user_id: u64,
+        first_login_time: i64,
+        #[arrow_field(name = "override")]
+        renamed: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Screaming {
+        user_id: u64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(rename_all = "kebab-case")]
+    struct Kebab {
+        user_id: u64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse", rename_all = "snake_case")]
+    enum Status {
+        InProgress(i32),
+        Done,
+    }
+
+    assert_eq!(
+        *Camel::arrow_schema(),
+        Schema::new(vec![
+            Field::new("userId", DataType::UInt64, false),
+            Field::new("firstLoginTime", DataType::Int64, false),
+            Field::new("override", DataType::Boolean, false),
+        ])
+    );
+    assert_eq!(
+        *Screaming::arrow_schema(),
+        Schema::new(vec![Field::new("USER_ID", DataType::UInt64, false)])
+    );
+    assert_eq!(
+        *Kebab::arrow_schema(),
+        Schema::new(vec![Field::new("user-id", DataType::UInt64, false)])
+    );
+    assert_eq!(
+        <Status as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Union(
+            UnionFields::new(
+                vec![0, 1],
+                vec![
+                    Field::new("in_progress", DataType::Int32, false),
+                    Field::new("done", DataType::Boolean, false),
+                ]
+            ),
+            UnionMode::Sparse
+        )
+    );
+
+    let original = vec![Camel {
+        user_id: 1,
+        first_login_time: 2,
+        renamed: true,
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Camel> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Status::InProgress(50), Status::Done];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Status> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_skip() {
+    #[derive(Debug, Clone, PartialEq, Default)]
+    struct Handle(usize);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Cached {
+        id: i32,
+        #[arrow_field(skip)]
+        handle: Handle,
+        value: String,
+        #[arrow_field(skip)]
+        hits: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Pair(#[arrow_field(skip)] Handle, i32, String);
+
+    assert_eq!(
+        *Cached::arrow_schema(),
+        Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("value", DataType::Utf8, false),
+        ])
+    );
+    assert_eq!(
+        *Pair::arrow_schema(),
+        Schema::new(vec![
+            Field::new("1", DataType::Int32, false),
+            Field::new("2", DataType::Utf8, false),
+        ])
+    );
+
+    let original = vec![Cached {
+        id: 1,
+        handle: Handle(42),
+        value: "a".to_string(),
+        hits: 7,
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Cached> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Cached {
+            id: 1,
+            handle: Handle::default(),
+            value: "a".to_string(),
+            hits: 0,
+        }]
+    );
+
+    let original = vec![Pair(Handle(1), 2, "b".to_string())];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Pair> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Pair(Handle::default(), 2, "b".to_string())]);
+}
+
+#[test]
+fn test_skip_serializing_and_deserializing() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Order {
+        id: i32,
+        // computed on write, ignored on read
+        #[arrow_field(skip_deserializing)]
+        total: f64,
+        // read from older files, never written
+        #[arrow_field(skip_serializing)]
+        legacy_code: Option<String>,
+    }
+
+    assert_eq!(
+        *Order::arrow_schema(),
+        Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("total", DataType::Float64, false),
+        ])
+    );
+
+    let original = vec![Order {
+        id: 1,
+        total: 9.5,
+        legacy_code: Some("X".to_string()),
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(arr.column_names(), vec!["id", "total"]);
+    assert_eq!(arr.column(1).as_primitive::<Float64Type>().value(0), 9.5);
+
+    let round_trip: Vec<Order> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Order {
+            id: 1,
+            total: 0.0,
+            legacy_code: None,
+        }]
+    );
+
+    // a legacy file contains the read-only column, and may not contain the write-only column
+    let legacy: ArrayRef = Arc::new(StructArray::from(vec![
+        (
+            Arc::new(Field::new("legacy_code", DataType::Utf8, true)),
+            Arc::new(StringArray::from(vec![Some("A"), None])) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+        ),
+    ]));
+    let round_trip: Vec<Order> = legacy.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Order {
+                id: 1,
+                total: 0.0,
+                legacy_code: Some("A".to_string()),
+            },
+            Order {
+                id: 2,
+                total: 0.0,
+                legacy_code: None,
+            },
+        ]
+    );
+
+    // unknown columns are ignored, and mismatched types are rejected
+    let unknown: ArrayRef = Arc::new(StructArray::from(vec![
+        (
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("other", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+        ),
+    ]));
+    let round_trip: Vec<Order> = unknown.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Order {
+            id: 1,
+            total: 0.0,
+            legacy_code: None,
+        }]
+    );
+
+    let mismatched: ArrayRef = Arc::new(StructArray::from(vec![(
+        Arc::new(Field::new("id", DataType::Int64, false)),
+        Arc::new(Int64Array::from(vec![1])) as ArrayRef,
+    )]));
+    let result: arrow::error::Result<Vec<Order>> = mismatched.try_into_collection();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_default() {
+    fn default_currency() -> String {
+        "EUR".to_string()
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Price {
+        amount: i64,
+        #[arrow_field(default = "default_currency")]
+        currency: String,
+        #[arrow_field(default)]
+        discount: Option<f32>,
+        #[arrow_field(skip, default = "default_currency")]
+        display_currency: String,
+    }
+
+    assert_eq!(
+        *Price::arrow_schema(),
+        Schema::new(vec![
+            Field::new("amount", DataType::Int64, false),
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("discount", DataType::Float32, true),
+        ])
+    );
+
+    let original = vec![Price {
+        amount: 100,
+        currency: "USD".to_string(),
+        discount: Some(0.5),
+        display_currency: "USD".to_string(),
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Price> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Price {
+            amount: 100,
+            currency: "USD".to_string(),
+            discount: Some(0.5),
+            display_currency: "EUR".to_string(),
+        }]
+    );
+
+    // an older schema without the defaulted columns
+    let old: ArrayRef = Arc::new(StructArray::from(vec![(
+        Arc::new(Field::new("amount", DataType::Int64, false)),
+        Arc::new(Int64Array::from(vec![5, 6])) as ArrayRef,
+    )]));
+    let round_trip: Vec<Price> = old.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Price {
+                amount: 5,
+                currency: "EUR".to_string(),
+                discount: None,
+                display_currency: "EUR".to_string(),
+            },
+            Price {
+                amount: 6,
+                currency: "EUR".to_string(),
+                discount: None,
+                display_currency: "EUR".to_string(),
+            },
+        ]
+    );
+
+    // a missing column without a default is still an error
+    let missing: ArrayRef = Arc::new(StructArray::from(vec![(
+        Arc::new(Field::new("currency", DataType::Utf8, false)),
+        Arc::new(StringArray::from(vec!["USD"])) as ArrayRef,
+    )]));
+    let result: arrow::error::Result<Vec<Price>> = missing.try_into_collection();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_serialize_with() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    fn side_to_string(side: &Side) -> String {
+        match side {
+            Side::Buy => "buy".to_string(),
+            Side::Sell => "sell".to_string(),
+        }
+    }
+
+    fn side_from_string(side: String) -> Side {
+        match side.as_str() {
+            "buy" => Side::Buy,
+            "sell" => Side::Sell,
+            _ => panic!("unknown side {side}"),
+        }
+    }
+
+    fn to_cents(price: &f64) -> i64 {
+        (price * 100.0).round() as i64
+    }
+
+    fn from_cents(cents: i64) -> f64 {
+        cents as f64 / 100.0
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Trade {
+        #[arrow_field(
+            type = "String",
+            serialize_with = "side_to_string",
+            deserialize_with = "side_from_string"
+        )]
+        side: Side,
+        #[arrow_field(type = "i64", serialize_with = "to_cents", deserialize_with = "from_cents")]
+        price: f64,
+        #[arrow_field(type = "Option<String>", skip_serializing, deserialize_with = "legacy_side")]
+        legacy: Option<Side>,
+    }
+
+    fn legacy_side(side: Option<String>) -> Option<Side> {
+        side.map(side_from_string)
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Amount(#[arrow_field(type = "i64", serialize_with = "to_cents", deserialize_with = "from_cents")] f64);
+
+    assert_eq!(
+        *Trade::arrow_schema(),
+        Schema::new(vec![
+            Field::new("side", DataType::Utf8, false),
+            Field::new("price", DataType::Int64, false),
+        ])
+    );
+
+    let original = vec![
+        Trade {
+            side: Side::Buy,
+            price: 1.25,
+            legacy: None,
+        },
+        Trade {
+            side: Side::Sell,
+            price: 3.5,
+            legacy: None,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(arr.column(0).as_string::<i32>().value(1), "sell");
+    assert_eq!(arr.column(1).as_primitive::<Int64Type>().value(0), 125);
+    let round_trip: Vec<Trade> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Amount(0.99), Amount(12.0)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Int64);
+    let round_trip: Vec<Amount> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_transparent() {
+    #[derive(Debug, Clone, Copy, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct UserId(u64);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Nickname(Option<String>);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Tag {
+        name: String,
+        #[arrow_field(skip)]
+        cached_len: usize,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+        nickname: Nickname,
+        tags: Vec<Tag>,
+    }
+
+    assert_eq!(
+        <UserId as arrow_convert::field::ArrowField>::data_type(),
+        DataType::UInt64
+    );
+    assert_eq!(
+        *User::arrow_schema(),
+        Schema::new(vec![
+            Field::new("id", DataType::UInt64, false),
+            Field::new("manager", DataType::UInt64, true),
+            Field::new("nickname", DataType::Utf8, true),
+            Field::new(
+                "tags",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, false))),
+                false
+            ),
+        ])
+    );
+
+    let original = vec![UserId(1), UserId(2)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::UInt64);
+    let round_trip: Vec<UserId> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        User {
+            id: UserId(1),
+            manager: None,
+            nickname: Nickname(Some("ann".to_string())),
+            tags: vec![Tag {
+                name: "admin".to_string(),
+                cached_len: 0,
+            }],
+        },
+        User {
+            id: UserId(2),
+            manager: Some(UserId(1)),
+            nickname: Nickname(None),
+            tags: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<User> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_field_metadata() {
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Reading {
+        #[arrow_field(metadata(unit = "ms"))]
+        latency: i64,
+        #[arrow_field(name = "user-email", metadata(pii = "true", owner = "accounts"))]
+        email: Option<String>,
+        count: u32,
+    }
+
+    let schema = Reading::arrow_schema();
+    assert_eq!(
+        schema.field(0).metadata(),
+        &HashMap::from([("unit".to_string(), "ms".to_string())])
+    );
+    assert_eq!(schema.field(1).name(), "user-email");
+    assert!(schema.field(1).is_nullable());
+    assert_eq!(
+        schema.field(1).metadata(),
+        &HashMap::from([
+            ("pii".to_string(), "true".to_string()),
+            ("owner".to_string(), "accounts".to_string()),
+        ])
+    );
+    assert!(schema.field(2).metadata().is_empty());
+
+    let original = vec![
+        Reading {
+            latency: 12,
+            email: Some("a@b.c".to_string()),
+            count: 1,
+        },
+        Reading {
+            latency: 7,
+            email: None,
+            count: 2,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let DataType::Struct(fields) = b.data_type() else {
+        panic!("expected a struct array");
+    };
+    assert_eq!(fields[0].metadata().get("unit").map(String::as_str), Some("ms"));
+    let round_trip: Vec<Reading> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_schema_metadata() {
+    use arrow::record_batch::RecordBatch;
+    use arrow_convert::serialize::FlattenRecordBatch;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(schema_metadata(producer = "ingest-v2", table = "events"))]
+    struct Event {
+        #[arrow_field(metadata(unit = "ms"))]
+        ts: i64,
+        name: String,
+    }
+
+    let expected = HashMap::from([
+        ("producer".to_string(), "ingest-v2".to_string()),
+        ("table".to_string(), "events".to_string()),
+    ]);
+    assert_eq!(Event::arrow_schema().metadata(), &expected);
+    assert_eq!(
+        <Event as arrow_convert::field::ArrowField>::schema_metadata(),
+        expected
+    );
+
+    let original = vec![
+        Event {
+            ts: 1,
+            name: "a".to_string(),
+        },
+        Event {
+            ts: 2,
+            name: "b".to_string(),
+        },
+    ];
+    let batch: RecordBatch = original.try_into_arrow().unwrap();
+    assert_eq!(batch.schema().metadata(), &expected);
+
+    let flattened = batch.flatten().unwrap();
+    assert_eq!(flattened.schema(), Event::arrow_schema());
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_nullable() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Loose {
+        #[arrow_field(nullable)]
+        id: i64,
+        #[arrow_field(nullable)]
+        name: String,
+        score: Option<f64>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Strict {
+        id: i64,
+        name: String,
+        score: Option<f64>,
+    }
+
+    assert_eq!(
+        *Loose::arrow_schema(),
+        Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("score", DataType::Float64, true),
+        ])
+    );
+
+    let original = vec![
+        Loose {
+            id: 1,
+            name: "a".to_string(),
+            score: None,
+        },
+        Loose {
+            id: 2,
+            name: "b".to_string(),
+            score: Some(0.5),
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(arr.column(0).null_count(), 0);
+    let round_trip: Vec<Loose> = b.clone().try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    // the nullable columns are rejected by the strict struct, while the loose struct reads both
+    let result: arrow::error::Result<Vec<Strict>> = b.try_into_collection();
+    assert!(result.is_err());
+    let strict = vec![Strict {
+        id: 3,
+        name: "c".to_string(),
+        score: Some(1.0),
+    }];
+    let b: ArrayRef = strict.try_into_arrow().unwrap();
+    let loose: Vec<Loose> = b.try_into_collection().unwrap();
+    assert_eq!(
+        loose,
+        vec![Loose {
+            id: 3,
+            name: "c".to_string(),
+            score: Some(1.0),
+        }]
+    );
+}
+
+#[test]
+fn test_dictionary() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Visit {
+        #[arrow_field(dictionary)]
+        country: String,
+        #[arrow_field(dictionary = "u8")]
+        city: Option<String>,
+        #[arrow_field(dictionary = "i16")]
+        code: i64,
+    }
+
+    let dictionary = |key: DataType, value: DataType| DataType::Dictionary(Box::new(key), Box::new(value));
+    assert_eq!(
+        *Visit::arrow_schema(),
+        Schema::new(vec![
+            Field::new("country", dictionary(DataType::Int32, DataType::Utf8), false),
+            Field::new("city", dictionary(DataType::UInt8, DataType::Utf8), true),
+            Field::new("code", dictionary(DataType::Int16, DataType::Int64), false),
+        ])
+    );
+
+    let original = vec![
+        Visit {
+            country: "fr".to_string(),
+            city: Some("paris".to_string()),
+            code: 75,
+        },
+        Visit {
+            country: "fr".to_string(),
+            city: None,
+            code: 75,
+        },
+        Visit {
+            country: "de".to_string(),
+            city: Some("berlin".to_string()),
+            code: 10,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    let countries = arr
+        .column(0)
+        .as_any()
+        .downcast_ref::<DictionaryArray<Int32Type>>()
+        .unwrap();
+    assert_eq!(countries.values().len(), 2);
+    let round_trip: Vec<Visit> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_large() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Document {
+        #[arrow_field(large)]
+        body: String,
+        #[arrow_field(large)]
+        blob: Option<Vec<u8>>,
+        #[arrow_field(large)]
+        tags: Vec<String>,
+        #[arrow_field(large, dictionary)]
+        author: String,
+    }
+
+    assert_eq!(
+        *Document::arrow_schema(),
+        Schema::new(vec![
+            Field::new("body", DataType::LargeUtf8, false),
+            Field::new("blob", DataType::LargeBinary, true),
+            Field::new(
+                "tags",
+                DataType::LargeList(Arc::new(Field::new("item", DataType::Utf8, false))),
+                false
+            ),
+            Field::new(
+                "author",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::LargeUtf8)),
+                false
+            ),
+        ])
+    );
+
+    let original = vec![
+        Document {
+            body: "hello".to_string(),
+            blob: Some(vec![1, 2, 3]),
+            tags: vec!["a".to_string(), "b".to_string()],
+            author: "x".to_string(),
+        },
+        Document {
+            body: String::new(),
+            blob: None,
+            tags: vec![],
+            author: "x".to_string(),
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Document> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_fixed_size() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Sample {
+        #[arrow_field(fixed_size = 3)]
+        position: Vec<f32>,
+        #[arrow_field(fixed_size = 2)]
+        range: Option<Vec<i32>>,
+        #[arrow_field(fixed_size = 4)]
+        hash: Vec<u8>,
+        #[arrow_field(fixed_size = 2)]
+        pair: [i16; 2],
+    }
+
+    assert_eq!(
+        *Sample::arrow_schema(),
+        Schema::new(vec![
+            Field::new(
+                "position",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, false)), 3),
+                false
+            ),
+            Field::new(
+                "range",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, false)), 2),
+                true
+            ),
+            Field::new("hash", DataType::FixedSizeBinary(4), false),
+            Field::new(
+                "pair",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int16, false)), 2),
+                false
+            ),
+        ])
+    );
+
+    let original = vec![
+        Sample {
+            position: vec![1.0, 2.0, 3.0],
+            range: Some(vec![0, 10]),
+            hash: vec![1, 2, 3, 4],
+            pair: [1, 2],
+        },
+        Sample {
+            position: vec![4.0, 5.0, 6.0],
+            range: None,
+            hash: vec![5, 6, 7, 8],
+            pair: [3, 4],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Sample> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let invalid = vec![Sample {
+        position: vec![1.0, 2.0],
+        range: None,
+        hash: vec![1, 2, 3, 4],
+        pair: [1, 2],
+    }];
+    let result: arrow::error::Result<ArrayRef> = invalid.try_into_arrow();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_builder() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(builder, schema_metadata(source = "sensor"))]
+    struct Reading {
+        id: u32,
+        label: Option<String>,
+        #[arrow_field(skip)]
+        cached: bool,
+        values: Vec<f64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(builder = "PairColumns")]
+    struct Pair(i32, String);
+
+    let mut builder = ReadingBuilder::new();
+    assert!(builder.is_empty());
+    builder
+        .append_row(&Reading {
+            id: 1,
+            label: Some("a".to_string()),
+            cached: true,
+            values: vec![1.0],
+        })
+        .unwrap();
+    builder.append_id(2u32).unwrap();
+    builder.append_label(None).unwrap();
+    assert_eq!(builder.len(), 1);
+    builder.append_values(vec![2.0, 3.0]).unwrap();
+    assert_eq!(builder.len(), 2);
+
+    let batch = builder.finish_record_batch().unwrap();
+    assert_eq!(batch.schema(), Reading::arrow_schema());
+    assert_eq!(batch.num_rows(), 2);
+    assert!(builder.is_empty());
+
+    builder.append_id(3u32).unwrap();
+    builder.append_label(Some("c".to_string())).unwrap();
+    builder.append_values(vec![]).unwrap();
+    let array: ArrayRef = Arc::new(builder.finish().unwrap());
+    let round_trip: Vec<Reading> = array.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Reading {
+            id: 3,
+            label: Some("c".to_string()),
+            cached: false,
+            values: vec![],
+        }]
+    );
+
+    // the columns must have the same length
+    builder.append_id(4u32).unwrap();
+    assert!(builder.finish().is_err());
+
+    let mut builder = PairColumns::default();
+    builder.append_0(1).unwrap();
+    builder.append_1("x".to_string()).unwrap();
+    builder.append_row(&Pair(2, "y".to_string())).unwrap();
+    let array: ArrayRef = Arc::new(builder.finish().unwrap());
+    let round_trip: Vec<Pair> = array.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Pair(1, "x".to_string()), Pair(2, "y".to_string())]
+    );
+
+    #[derive(ArrowField, ArrowSerialize)]
+    #[arrow_field(builder)]
+    struct Borrowed<'a> {
+        name: &'a str,
+    }
+
+    let name = "z".to_string();
+    let mut builder = BorrowedBuilder::new();
+    builder.append_name(name.as_str()).unwrap();
+    builder.append_row(&Borrowed { name: &name }).unwrap();
+    let array = builder.finish().unwrap();
+    assert_eq!(array.column(0).as_string::<i32>().value(1), "z");
+}
+
+#[test]
+fn test_timestamp() {
+    use chrono::{DateTime, NaiveDateTime, Timelike, Utc};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(timestamp(unit = "ms"))]
+        local: NaiveDateTime,
+        #[arrow_field(timestamp(unit = "us", tz = "UTC"))]
+        created: DateTime<Utc>,
+        #[arrow_field(timestamp(unit = "s", tz = "Europe/Paris"))]
+        updated: Option<NaiveDateTime>,
+        #[arrow_field(timestamp(tz = "+02:00"))]
+        raw: i64,
+    }
+
+    assert_eq!(
+        *Event::arrow_schema(),
+        Schema::new(vec![
+            Field::new("local", DataType::Timestamp(TimeUnit::Millisecond, None), false),
+            Field::new(
+                "created",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                false
+            ),
+            Field::new(
+                "updated",
+                DataType::Timestamp(TimeUnit::Second, Some("Europe/Paris".into())),
+                true
+            ),
+            Field::new(
+                "raw",
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("+02:00".into())),
+                false
+            ),
+        ])
+    );
+
+    let created = DateTime::<Utc>::from_timestamp(1_700_000_000, 123_456_000).unwrap();
+    let original = vec![
+        Event {
+            local: NaiveDateTime::parse_from_str("2024-03-01 12:30:45.250", "%Y-%m-%d %H:%M:%S%.3f").unwrap(),
+            created,
+            updated: Some(created.naive_utc() - chrono::Duration::milliseconds(123_456)),
+            raw: -1,
+        },
+        Event {
+            local: NaiveDateTime::default(),
+            created,
+            updated: None,
+            raw: 0,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip[1], original[1]);
+    // the seconds unit truncates the milliseconds
+    assert_eq!(
+        round_trip[0].updated,
+        original[0].updated.and_then(|t| t.with_nanosecond(0))
+    );
+    assert_eq!(
+        Event {
+            updated: None,
+            ..round_trip[0].clone()
+        },
+        Event {
+            updated: None,
+            ..original[0].clone()
+        }
+    );
+}
+
+#[test]
+fn test_decimal() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Order {
+        #[arrow_field(decimal(precision = 18, scale = 4))]
+        price: i128,
+        #[arrow_field(decimal(precision = 10, scale = -2))]
+        total: Option<i128>,
+    }
+
+    assert_eq!(
+        *Order::arrow_schema(),
+        Schema::new(vec![
+            Field::new("price", DataType::Decimal128(18, 4), false),
+            Field::new("total", DataType::Decimal128(10, -2), true),
+        ])
+    );
+
+    let original = vec![
+        Order {
+            price: 12_3456,
+            total: Some(42),
+        },
+        Order { price: -1, total: None },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Order> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_remote() {
+    mod other {
+        #[derive(Debug, PartialEq)]
+        pub struct Point {
+            pub x: i32,
+            pub y: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq)]
+        pub struct Meters(pub f64);
+    }
+
+    #[derive(ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(remote = "other::Point")]
+    struct PointDef {
+        x: i32,
+        y: Option<String>,
+    }
+
+    #[derive(ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(remote = "other::Meters", transparent)]
+    struct MetersDef(f64);
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Route {
+        #[arrow_field(type = "PointDef")]
+        start: other::Point,
+        #[arrow_field(type = "Vec<PointDef>")]
+        stops: Vec<other::Point>,
+        #[arrow_field(type = "Option<MetersDef>")]
+        length: Option<other::Meters>,
+    }
+
+    assert_eq!(
+        <Route as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new(
+                "start",
+                <PointDef as arrow_convert::field::ArrowField>::data_type(),
+                false
+            ),
+            Field::new_list(
+                "stops",
+                Field::new(
+                    "item",
+                    <PointDef as arrow_convert::field::ArrowField>::data_type(),
+                    false
+                ),
+                false
+            ),
+            Field::new("length", DataType::Float64, true),
+        ]))
+    );
+
+    let routes = vec![
+        Route {
+            start: other::Point { x: 1, y: None },
+            stops: vec![other::Point {
+                x: 2,
+                y: Some("a".to_string()),
+            }],
+            length: Some(other::Meters(1.5)),
+        },
+        Route {
+            start: other::Point {
+                x: 3,
+                y: Some("b".to_string()),
+            },
+            stops: vec![],
+            length: None,
+        },
+    ];
+    let b: ArrayRef = routes.try_into_arrow().unwrap();
+    let round_trip: Vec<Route> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, routes);
+
+    // the remote type can be serialized directly through the mirror
+    let points = vec![other::Point { x: 4, y: None }];
+    let b: ArrayRef = points.try_into_arrow_as_type::<PointDef>().unwrap();
+    let round_trip: Vec<other::Point> = b.try_into_collection_as_type::<PointDef>().unwrap();
+    assert_eq!(round_trip, points);
+}
+
+#[test]
+fn test_validate() {
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    fn not_empty(v: &str) -> Result<(), &'static str> {
+        if v.is_empty() {
+            Err("is empty")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn ordered(range: &Range) -> Result<(), String> {
+        if range.start <= range.end {
+            Ok(())
+        } else {
+            Err(format!("start {} is after end {}", range.start, range.end))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(validate = "ordered")]
+    struct Range {
+        #[arrow_field(validate = "positive")]
+        start: i64,
+        end: i64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Schedule {
+        #[arrow_field(validate = "not_empty")]
+        name: String,
+        ranges: Vec<Range>,
+        current: Option<Range>,
+    }
+
+    let valid = vec![Schedule {
+        name: "a".to_string(),
+        ranges: vec![Range { start: 1, end: 2 }],
+        current: None,
+    }];
+    let b: ArrayRef = valid.try_into_arrow().unwrap();
+    let round_trip: Vec<Schedule> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, valid);
+
+    let err = |schedules: Vec<Schedule>| {
+        let b: ArrayRef = schedules.try_into_arrow().unwrap();
+        let result: arrow::error::Result<Vec<Schedule>> = b.try_into_collection();
+        result.unwrap_err().to_string()
+    };
+    let schedule = valid[0].clone();
+    assert_eq!(
+        err(vec![
+            schedule.clone(),
+            Schedule {
+                name: "".to_string(),
+                ..schedule.clone()
+            }
+        ]),
+        "Invalid argument error: Invalid row 1 deserializing test_attributes::test_validate::Schedule: name: is empty"
+    );
+    assert!(err(vec![Schedule {
+        ranges: vec![Range { start: 1, end: 2 }, Range { start: 0, end: 2 }],
+        ..schedule.clone()
+    }])
+    .ends_with(
+        "Invalid row 0 deserializing test_attributes::test_validate::Schedule: ranges: [1]: start: 0 is not positive"
+    ));
+    assert!(err(vec![Schedule {
+        current: Some(Range { start: 3, end: 2 }),
+        ..schedule.clone()
+    }])
+    .ends_with("current: start 3 is after end 2"));
+
+    // the values are also validated at the top level
+    let b: ArrayRef = vec![Range { start: 3, end: 2 }].try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Range>> = b.try_into_collection();
+    assert!(result.unwrap_err().to_string().ends_with("start 3 is after end 2"));
+}
+
+#[test]
+fn test_validate_nested() {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    enum Shape {
+        Empty,
+        Single(Positive),
+        Pair(Positive, Positive),
+        Named { first: Positive },
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "struct")]
+    enum Tagged {
+        Single(Positive),
+        Named { first: Positive },
+    }
+
+    fn err<T>(values: Vec<<T as arrow_convert::field::ArrowField>::Type>) -> String
+    where
+        T: arrow_convert::serialize::ArrowSerialize + arrow_convert::deserialize::ArrowDeserialize + 'static,
+        <T as arrow_convert::deserialize::ArrowDeserialize>::ArrayType: arrow_convert::deserialize::ArrowArrayIterable,
+        <T as arrow_convert::field::ArrowField>::Type: 'static,
+    {
+        let b: ArrayRef = values.try_into_arrow_as_type::<T>().unwrap();
+        let result: arrow::error::Result<Vec<<T as arrow_convert::field::ArrowField>::Type>> =
+            b.try_into_collection_as_type::<T>();
+        result.err().unwrap().to_string()
+    }
+
+    let valid = Positive { v: 1 };
+    let invalid = Positive { v: 0 };
+
+    // lists, arrays and sets prefix the index of the element
+    assert!(err::<[Positive; 2]>(vec![[valid.clone(), invalid.clone()]]).ends_with("[1]: v: 0 is not positive"));
+    assert!(
+        err::<BTreeSet<Positive>>(vec![BTreeSet::from([invalid.clone(), valid.clone()])])
+            .ends_with("[0]: v: 0 is not positive")
+    );
+    assert!(err::<HashSet<Positive>>(vec![HashSet::from([invalid.clone()])]).ends_with("[0]: v: 0 is not positive"));
+
+    // maps prefix the index of the entry and `keys` or `values`
+    assert!(
+        err::<BTreeMap<i32, Positive>>(vec![BTreeMap::from([(1, valid.clone()), (2, invalid.clone())])])
+            .ends_with("[1]: values: v: 0 is not positive")
+    );
+    assert!(
+        err::<HashMap<Positive, i32>>(vec![HashMap::from([(invalid.clone(), 1)])])
+            .ends_with("[0]: keys: v: 0 is not positive")
+    );
+
+    // tuples prefix the index of the field, and results `ok` or `err`
+    assert!(err::<(i32, Positive)>(vec![(1, invalid.clone())]).ends_with("1: v: 0 is not positive"));
+    assert!(
+        err::<Result<Positive, Positive>>(vec![Ok(valid.clone()), Err(invalid.clone())])
+            .ends_with("err: v: 0 is not positive")
+    );
+
+    // enums prefix the variant name, and the field name of struct and multi-field tuple variants
+    assert!(err::<Shape>(vec![Shape::Empty, Shape::Single(invalid.clone())]).ends_with("Single: v: 0 is not positive"));
+    assert!(err::<Shape>(vec![Shape::Pair(valid.clone(), invalid.clone())]).ends_with("Pair: 1: v: 0 is not positive"));
+    assert!(err::<Shape>(vec![Shape::Named { first: invalid.clone() }]).ends_with("Named: first: v: 0 is not positive"));
+    assert!(
+        err::<Tagged>(vec![Tagged::Named { first: invalid.clone() }]).ends_with("Named: first: v: 0 is not positive")
+    );
+
+    // nested containers prefix each level
+    assert!(
+        err::<Vec<BTreeMap<i32, Vec<Positive>>>>(vec![vec![BTreeMap::from([(
+            1,
+            vec![valid.clone(), invalid.clone()]
+        )])]])
+        .ends_with("[0]: [0]: values: [1]: v: 0 is not positive")
+    );
+
+    let b: ArrayRef = vec![Shape::Pair(valid.clone(), valid.clone())]
+        .try_into_arrow()
+        .unwrap();
+    let round_trip: Vec<Shape> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Shape::Pair(valid.clone(), valid)]);
+}
+
+#[test]
+fn test_validate_wrappers() {
+    use arrow_convert::field::{Dictionary, NestedOption, RunEnd};
+    use std::num::{Saturating, Wrapping};
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wrappers {
+        range: std::ops::Range<Positive>,
+        inclusive: std::ops::RangeInclusive<Positive>,
+        #[arrow_field(type = "NestedOption<Positive>")]
+        nested: Option<Option<Positive>>,
+    }
+
+    // an `i32` that must be even, as the values of dictionaries and run-end encoded arrays can't be derived structs
+    struct Even;
+
+    impl arrow_convert::field::ArrowField for Even {
+        type Type = i32;
+
+        fn data_type() -> DataType {
+            DataType::Int32
+        }
+    }
+
+    impl arrow_convert::deserialize::ArrowDeserialize for Even {
+        type ArrayType = Int32Array;
+
+        fn arrow_deserialize(v: Option<i32>) -> Option<i32> {
+            v
+        }
+
+        fn validate(v: &i32) -> Result<(), String> {
+            if v % 2 == 0 {
+                Ok(())
+            } else {
+                Err(format!("{v} is odd"))
+            }
+        }
+    }
+
+    let err = |wrappers: Wrappers| {
+        let b: ArrayRef = vec![wrappers].try_into_arrow().unwrap();
+        let result: arrow::error::Result<Vec<Wrappers>> = b.try_into_collection();
+        result.unwrap_err().to_string()
+    };
+    let valid = Positive { v: 1 };
+    let invalid = Positive { v: 0 };
+    let wrappers = Wrappers {
+        range: valid.clone()..valid.clone(),
+        inclusive: valid.clone()..=valid.clone(),
+        nested: Some(Some(valid.clone())),
+    };
+    let b: ArrayRef = vec![wrappers.clone()].try_into_arrow().unwrap();
+    let round_trip: Vec<Wrappers> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![wrappers.clone()]);
+
+    // ranges prefix `start` or `end`
+    assert!(err(Wrappers {
+        range: valid.clone()..invalid.clone(),
+        ..wrappers.clone()
+    })
+    .ends_with("range: end: v: 0 is not positive"));
+    assert!(err(Wrappers {
+        inclusive: invalid.clone()..=valid.clone(),
+        ..wrappers.clone()
+    })
+    .ends_with("inclusive: start: v: 0 is not positive"));
+    assert!(err(Wrappers {
+        nested: Some(Some(invalid.clone())),
+        ..wrappers.clone()
+    })
+    .ends_with("nested: v: 0 is not positive"));
+
+    // wrappers and encodings validate the wrapped values
+    let b: ArrayRef = Arc::new(Int32Array::from(vec![2, 3]));
+    let result: arrow::error::Result<Vec<Wrapping<i32>>> = b.clone().try_into_collection_as_type::<Wrapping<Even>>();
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.contains("Invalid row 1") && error.ends_with(": 3 is odd"),
+        "{error}"
+    );
+    let result: arrow::error::Result<Vec<Saturating<i32>>> = b.try_into_collection_as_type::<Saturating<Even>>();
+    assert!(result.unwrap_err().to_string().ends_with(": 3 is odd"));
+
+    let b: ArrayRef = vec![2, 2, 3].try_into_arrow_as_type::<Dictionary<i32, i32>>().unwrap();
+    let result: arrow::error::Result<Vec<i32>> = b.try_into_collection_as_type::<Dictionary<i32, Even>>();
+    assert!(result.unwrap_err().to_string().ends_with(": 3 is odd"));
+
+    let b: ArrayRef = vec![2, 2, 3].try_into_arrow_as_type::<RunEnd<i32, i32>>().unwrap();
+    let result: arrow::error::Result<Vec<i32>> = b.try_into_collection_as_type::<RunEnd<i32, Even>>();
+    assert!(result.unwrap_err().to_string().ends_with(": 3 is odd"));
+}
+
+#[test]
+fn test_alias() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct V1 {
+        user: String,
+        score: i64,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct V2 {
+        username: String,
+        points: i64,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(deny_unknown_fields)]
+    struct Current {
+        #[arrow_field(alias = "user", alias = "username")]
+        name: String,
+        #[arrow_field(alias = "points", default)]
+        score: i64,
+    }
+
+    assert_eq!(
+        <Current as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("score", DataType::Int64, false),
+        ]))
+    );
+
+    let b: ArrayRef = vec![V1 {
+        user: "a".to_string(),
+        score: 1,
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let current: Vec<Current> = b.try_into_collection().unwrap();
+    assert_eq!(
+        current,
+        vec![Current {
+            name: "a".to_string(),
+            score: 1
+        }]
+    );
+
+    let b: ArrayRef = vec![V2 {
+        username: "b".to_string(),
+        points: 2,
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let current: Vec<Current> = b.try_into_collection().unwrap();
+    assert_eq!(
+        current,
+        vec![Current {
+            name: "b".to_string(),
+            score: 2
+        }]
+    );
+
+    let b: ArrayRef = vec![Current {
+        name: "c".to_string(),
+        score: 3,
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let current: Vec<Current> = b.try_into_collection().unwrap();
+    assert_eq!(current[0].name, "c");
+
+    // the name is preferred over the aliases
+    let array = StructArray::from(vec![
+        (
+            Arc::new(Field::new("user", DataType::Utf8, false)),
+            Arc::new(StringArray::from(vec!["old"])) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("name", DataType::Utf8, false)),
+            Arc::new(StringArray::from(vec!["new"])) as ArrayRef,
+        ),
+    ]);
+    let b: ArrayRef = Arc::new(array);
+    let current: Vec<Current> = b.try_into_collection().unwrap();
+    assert_eq!(
+        current,
+        vec![Current {
+            name: "new".to_string(),
+            score: 0
+        }]
+    );
+}
+
+#[test]
+fn test_as_list() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Packet {
+        #[arrow_field(as_list)]
+        payload: Vec<u8>,
+        #[arrow_field(as_list)]
+        checksum: Option<Vec<u8>>,
+        raw: Vec<u8>,
+    }
+
+    let bytes = DataType::List(Arc::new(Field::new("item", DataType::UInt8, false)));
+    assert_eq!(
+        <Packet as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("payload", bytes.clone(), false),
+            Field::new("checksum", bytes, true),
+            Field::new("raw", DataType::Binary, false),
+        ]))
+    );
+
+    let packets = vec![
+        Packet {
+            payload: vec![1, 2, 3],
+            checksum: None,
+            raw: vec![4],
+        },
+        Packet {
+            payload: vec![],
+            checksum: Some(vec![5, 6]),
+            raw: vec![],
+        },
+    ];
+    let b: ArrayRef = packets.try_into_arrow().unwrap();
+    let payload = b.as_struct().column(0).as_list::<i32>();
+    assert_eq!(
+        payload.value(0).as_primitive::<UInt8Type>().values().to_vec(),
+        vec![1, 2, 3]
+    );
+    let round_trip: Vec<Packet> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, packets);
+
+    // the placeholder can be used directly
+    let lists = vec![vec![7u8, 8], vec![9]];
+    let b: ArrayRef = lists
+        .try_into_arrow_as_type::<arrow_convert::field::ByteList>()
+        .unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<arrow_convert::field::ByteList as arrow_convert::field::ArrowField>::data_type()
+    );
+    let round_trip: Vec<Vec<u8>> = b
+        .try_into_collection_as_type::<arrow_convert::field::ByteList>()
+        .unwrap();
+    assert_eq!(round_trip, lists);
+}
+
+#[test]
+fn test_view() {
+    use arrow_convert::deserialize::{arrow_array_view_iterator, ArrowView};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(view)]
+    struct Location {
+        lat: f64,
+        lon: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(view = "EventRef")]
+    struct Event {
+        id: u64,
+        #[arrow_field(large)]
+        name: String,
+        payload: Vec<u8>,
+        tag: Option<String>,
+        location: Option<Location>,
+        #[arrow_field(skip)]
+        cached: Option<u32>,
+    }
+
+    let events = vec![
+        Event {
+            id: 1,
+            name: "start".to_string(),
+            payload: vec![1, 2],
+            tag: None,
+            location: Some(Location { lat: 1.5, lon: -2.0 }),
+            cached: None,
+        },
+        Event {
+            id: 2,
+            name: "stop".to_string(),
+            payload: vec![],
+            tag: Some("urgent".to_string()),
+            location: None,
+            cached: None,
+        },
+    ];
+    let b: ArrayRef = events.try_into_arrow().unwrap();
+
+    let views = arrow_array_view_iterator::<Event>(b.as_ref())
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        views,
+        vec![
+            EventRef {
+                id: 1,
+                name: "start",
+                payload: &[1, 2],
+                tag: None,
+                location: Some(LocationView { lat: 1.5, lon: -2.0 }),
+            },
+            EventRef {
+                id: 2,
+                name: "stop",
+                payload: &[],
+                tag: Some("urgent"),
+                location: None,
+            },
+        ]
+    );
+
+    // the views borrow the strings of the array
+    let names = b.as_struct().column_by_name("name").unwrap().as_string::<i64>();
+    assert!(std::ptr::eq(views[1].name.as_ptr(), names.value(1).as_ptr()));
+
+    // null rows are yielded as `None`
+    let locations = vec![Some(Location { lat: 0.0, lon: 1.0 }), None];
+    let b: ArrayRef = locations.try_into_arrow().unwrap();
+    assert_eq!(
+        <Location as ArrowView>::iter_views(b.as_ref()).collect::<Vec<_>>(),
+        vec![Some(LocationView { lat: 0.0, lon: 1.0 }), None]
+    );
+    assert_eq!(
+        arrow_array_view_iterator::<Option<Location>>(b.as_ref())
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![Some(LocationView { lat: 0.0, lon: 1.0 }), None]
+    );
+
+    // arrays of other types are rejected
+    let b: ArrayRef = vec![1i32].try_into_arrow().unwrap();
+    assert!(arrow_array_view_iterator::<Location>(b.as_ref()).is_err());
+}
+
+#[test]
+fn test_index() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(builder)]
+    struct Reading {
+        value: f64,
+        unit: String,
+        #[arrow_field(index = 0)]
+        sensor_id: u32,
+        #[arrow_field(skip)]
+        cached: bool,
+        #[arrow_field(index = 3)]
+        note: Option<String>,
+    }
+
+    let fields = vec![
+        Field::new("sensor_id", DataType::UInt32, false),
+        Field::new("value", DataType::Float64, false),
+        Field::new("unit", DataType::Utf8, false),
+        Field::new("note", DataType::Utf8, true),
+    ];
+    assert_eq!(
+        <Reading as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(fields.clone()))
+    );
+    assert_eq!(Reading::arrow_schema().fields(), &Fields::from(fields));
+
+    let readings = vec![
+        Reading {
+            value: 1.5,
+            unit: "C".to_string(),
+            sensor_id: 7,
+            cached: false,
+            note: None,
+        },
+        Reading {
+            value: 2.0,
+            unit: "F".to_string(),
+            sensor_id: 8,
+            cached: false,
+            note: Some("calibrated".to_string()),
+        },
+    ];
+    let b: ArrayRef = readings.try_into_arrow().unwrap();
+    assert_eq!(
+        b.as_struct().column(0).as_primitive::<UInt32Type>().values().to_vec(),
+        vec![7, 8]
+    );
+    let round_trip: Vec<Reading> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, readings);
+
+    // the typed builder writes the columns in the same order
+    let mut builder = ReadingBuilder::new();
+    for reading in &readings {
+        builder.append_row(reading).unwrap();
+    }
+    let batch = builder.finish_record_batch().unwrap();
+    assert_eq!(batch.schema(), Reading::arrow_schema());
+    assert_eq!(
+        batch.column(0).as_primitive::<UInt32Type>().values().to_vec(),
+        vec![7, 8]
+    );
+}
+
+#[test]
+fn test_computed() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(builder, deny_unknown_fields)]
+    #[arrow_field(computed(with = "normalized_email", type = "String"))]
+    #[arrow_field(computed(with = "Account::tag_count", type = "u32", name = "tags"))]
+    struct Account {
+        email: String,
+        #[arrow_field(index = 0)]
+        id: u64,
+        tag_list: Vec<String>,
+    }
+
+    impl Account {
+        fn tag_count(&self) -> u32 {
+            self.tag_list.len() as u32
+        }
+    }
+
+    fn normalized_email(account: &Account) -> String {
+        account.email.trim().to_lowercase()
+    }
+
+    assert_eq!(
+        *Account::arrow_schema(),
+        Schema::new(vec![
+            Field::new("id", DataType::UInt64, false),
+            Field::new("email", DataType::Utf8, false),
+            Field::new(
+                "tag_list",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, false))),
+                false
+            ),
+            Field::new("normalized_email", DataType::Utf8, false),
+            Field::new("tags", DataType::UInt32, false),
+        ])
+    );
+
+    let original = vec![
+        Account {
+            email: " Ada@Example.com".to_string(),
+            id: 1,
+            tag_list: vec!["admin".to_string(), "ops".to_string()],
+        },
+        Account {
+            email: "bob@example.com".to_string(),
+            id: 2,
+            tag_list: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let columns = b.as_struct();
+    assert_eq!(
+        columns
+            .column_by_name("normalized_email")
+            .unwrap()
+            .as_string::<i32>()
+            .iter()
+            .collect::<Vec<_>>(),
+        vec![Some("ada@example.com"), Some("bob@example.com")]
+    );
+    assert_eq!(
+        columns
+            .column_by_name("tags")
+            .unwrap()
+            .as_primitive::<UInt32Type>()
+            .values()
+            .to_vec(),
+        vec![2, 0]
+    );
+
+    // the computed columns are ignored when deserializing
+    let round_trip: Vec<Account> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // the typed builder computes the columns of appended rows
+    let mut builder = AccountBuilder::new();
+    for account in &original {
+        builder.append_row(account).unwrap();
+    }
+    assert_eq!(
+        builder
+            .finish_record_batch()
+            .unwrap()
+            .column(3)
+            .as_string::<i32>()
+            .value(0),
+        "ada@example.com"
+    );
+}
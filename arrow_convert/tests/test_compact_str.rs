@@ -0,0 +1,48 @@
+#[cfg(feature = "compact_str")]
+#[test]
+fn test_compact_string_roundtrip() {
+    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use compact_str::CompactString;
+
+    let original = vec![
+        CompactString::from("short"),
+        CompactString::from("a string that is too long to be stored inline"),
+        CompactString::default(),
+    ];
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let string_array = arrow_array.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(
+        string_array.value(1),
+        "a string that is too long to be stored inline"
+    );
+
+    let roundtrip: Vec<CompactString> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Tagged {
+        name: CompactString,
+        alias: Option<CompactString>,
+        tags: Vec<CompactString>,
+    }
+
+    let original = vec![
+        Tagged {
+            name: "a".into(),
+            alias: None,
+            tags: vec!["x".into(), "y".into()],
+        },
+        Tagged {
+            name: "b".into(),
+            alias: Some("bee".into()),
+            tags: vec![],
+        },
+    ];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Tagged> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
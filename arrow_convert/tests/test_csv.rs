@@ -0,0 +1,48 @@
+#[cfg(feature = "csv")]
+#[test]
+fn test_write_csv() {
+    use arrow::csv::WriterBuilder;
+    use arrow_convert::serialize::write_csv;
+    use arrow_convert::{ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+        #[arrow_field(name = "label")]
+        name: Option<String>,
+        price: f64,
+    }
+
+    let rows = vec![
+        Row {
+            id: 1,
+            name: Some("a, b".to_string()),
+            price: 1.5,
+        },
+        Row {
+            id: 2,
+            name: None,
+            price: 3.0,
+        },
+    ];
+
+    let mut buffer = vec![];
+    write_csv::<Row, _, _>(&mut buffer, &rows, WriterBuilder::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "id,label,price\n1,\"a, b\",1.5\n2,,3.0\n"
+    );
+
+    let mut buffer = vec![];
+    let builder = WriterBuilder::new().with_header(false).with_delimiter(b';');
+    write_csv::<Row, _, _>(&mut buffer, &rows, builder).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), "1;a, b;1.5\n2;;3.0\n");
+
+    // nested fields can't be written
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Nested {
+        values: Vec<i64>,
+    }
+    assert!(write_csv::<Nested, _, _>(vec![], [Nested { values: vec![1] }], WriterBuilder::new()).is_err());
+}
@@ -131,3 +131,432 @@ fn test_deserialize_scalar_buffer_u8() {
         assert_eq!(&i, k);
     }
 }
+
+#[test]
+fn test_deserialize_deny_unknown_fields() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wide {
+        a: i64,
+        extra: String,
+        b: bool,
+        other: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(deny_unknown_fields)]
+    struct Narrow {
+        a: i64,
+        b: bool,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct OuterWide {
+        id: i32,
+        inner: Option<Wide>,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct OuterNarrow {
+        id: i32,
+        inner: Option<Narrow>,
+    }
+
+    let wide = vec![Wide {
+        a: 1,
+        extra: "x".to_string(),
+        b: true,
+        other: 2,
+    }];
+    let arr: ArrayRef = wide.try_into_arrow().unwrap();
+    let result: Result<Vec<Narrow>> = arr.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("Unknown fields"), "{error}");
+    assert!(error.ends_with(": extra, other"), "{error}");
+
+    let outer = vec![OuterWide {
+        id: 1,
+        inner: Some(wide[0].clone()),
+    }];
+    let arr: ArrayRef = outer.try_into_arrow().unwrap();
+    let result: Result<Vec<OuterNarrow>> = arr.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(error.ends_with(": inner.extra, inner.other"), "{error}");
+
+    // arrays without unknown columns are deserialized as usual
+    let narrow = vec![Narrow { a: 1, b: false }];
+    let arr: ArrayRef = narrow.try_into_arrow().unwrap();
+    let round_trip: Vec<Narrow> = arr.try_into_collection().unwrap();
+    assert_eq!(narrow, round_trip);
+}
+
+#[test]
+fn test_deserialize_deny_unknown_fields_nested() {
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wide {
+        a: i64,
+        extra: String,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(deny_unknown_fields)]
+    struct Narrow {
+        a: i64,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct OuterWide {
+        list: Vec<Wide>,
+        map: BTreeMap<i32, Wide>,
+        tuple: (i32, Wide),
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct OuterNarrow {
+        list: Vec<Narrow>,
+        map: BTreeMap<i32, Narrow>,
+        tuple: (i32, Narrow),
+    }
+
+    let wide = Wide {
+        a: 1,
+        extra: "x".to_string(),
+    };
+    let arr: ArrayRef = vec![vec![wide.clone()]].try_into_arrow().unwrap();
+    let result: Result<Vec<Vec<Narrow>>> = arr.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(error.ends_with(": extra"), "{error}");
+
+    let outer = vec![OuterWide {
+        list: vec![wide.clone()],
+        map: BTreeMap::from([(1, wide.clone())]),
+        tuple: (1, wide),
+    }];
+    let arr: ArrayRef = outer.try_into_arrow().unwrap();
+    let result: Result<Vec<OuterNarrow>> = arr.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.ends_with(": list.extra, map.values.extra, tuple.1.extra"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_deserialize_ignores_unknown_fields() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct InnerV2 {
+        added: Vec<i32>,
+        x: f64,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct RecordV2 {
+        version: u8,
+        id: i64,
+        inner: InnerV2,
+        name: Option<String>,
+        comment: String,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct InnerV1 {
+        x: f64,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct RecordV1 {
+        id: i64,
+        inner: InnerV1,
+        name: Option<String>,
+    }
+
+    let v2 = vec![
+        RecordV2 {
+            version: 2,
+            id: 1,
+            inner: InnerV2 { added: vec![1], x: 0.5 },
+            name: Some("a".to_string()),
+            comment: "new".to_string(),
+        },
+        RecordV2 {
+            version: 2,
+            id: 2,
+            inner: InnerV2 { added: vec![], x: 1.5 },
+            name: None,
+            comment: String::new(),
+        },
+    ];
+    let arr: ArrayRef = v2.try_into_arrow().unwrap();
+    let v1: Vec<RecordV1> = arr.try_into_collection().unwrap();
+    assert_eq!(
+        v1,
+        vec![
+            RecordV1 {
+                id: 1,
+                inner: InnerV1 { x: 0.5 },
+                name: Some("a".to_string()),
+            },
+            RecordV1 {
+                id: 2,
+                inner: InnerV1 { x: 1.5 },
+                name: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_deserialize_reordered_fields() {
+    use arrow::datatypes::{DataType, Field};
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+        label: Option<String>,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Reordered {
+        label: Option<String>,
+        y: i32,
+        x: i32,
+    }
+
+    let arr: ArrayRef = Arc::new(StructArray::from(vec![
+        (
+            Arc::new(Field::new("y", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![2, 4])) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("label", DataType::Utf8, true)),
+            Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("x", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1, 3])) as ArrayRef,
+        ),
+    ]));
+    let points: Vec<Point> = arr.try_into_collection().unwrap();
+    assert_eq!(
+        points,
+        vec![
+            Point {
+                x: 1,
+                y: 2,
+                label: Some("a".to_string()),
+            },
+            Point {
+                x: 3,
+                y: 4,
+                label: None
+            },
+        ]
+    );
+
+    let arr: ArrayRef = points.try_into_arrow().unwrap();
+    let reordered: Vec<Reordered> = arr.try_into_collection().unwrap();
+    assert_eq!(
+        reordered,
+        vec![
+            Reordered {
+                label: Some("a".to_string()),
+                y: 2,
+                x: 1,
+            },
+            Reordered {
+                label: None,
+                y: 4,
+                x: 3
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_deserialize_reordered_fields_nested() {
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Reordered {
+        y: i32,
+        x: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Outer {
+        list: Vec<Point>,
+        array: [Point; 1],
+        map: BTreeMap<i32, Point>,
+        tuple: (i32, Point),
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct ReorderedOuter {
+        list: Vec<Reordered>,
+        array: [Reordered; 1],
+        map: BTreeMap<i32, Reordered>,
+        tuple: (i32, Reordered),
+    }
+
+    let points = vec![vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }], vec![]];
+    let arr: ArrayRef = points.try_into_arrow().unwrap();
+    let reordered: Vec<Vec<Reordered>> = arr.try_into_collection().unwrap();
+    assert_eq!(
+        reordered,
+        vec![vec![Reordered { y: 2, x: 1 }, Reordered { y: 4, x: 3 }], vec![]]
+    );
+
+    let outer = vec![Outer {
+        list: vec![Point { x: 1, y: 2 }],
+        array: [Point { x: 3, y: 4 }],
+        map: BTreeMap::from([(5, Point { x: 6, y: 7 })]),
+        tuple: (8, Point { x: 9, y: 10 }),
+    }];
+    let arr: ArrayRef = outer.try_into_arrow().unwrap();
+    let reordered: Vec<ReorderedOuter> = arr.try_into_collection().unwrap();
+    assert_eq!(
+        reordered,
+        vec![ReorderedOuter {
+            list: vec![Reordered { y: 2, x: 1 }],
+            array: [Reordered { y: 4, x: 3 }],
+            map: BTreeMap::from([(5, Reordered { y: 7, x: 6 })]),
+            tuple: (8, Reordered { y: 10, x: 9 }),
+        }]
+    );
+
+    // the elements are still checked
+    let arr: ArrayRef = vec![[Point { x: 1, y: 2 }]].try_into_arrow().unwrap();
+    let result: Result<Vec<[Reordered; 2]>> = arr.try_into_collection();
+    assert!(result.is_err());
+    let arr: ArrayRef = vec![vec![Some(Point { x: 1, y: 2 })]].try_into_arrow().unwrap();
+    let result: Result<Vec<Vec<Reordered>>> = arr.try_into_collection();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_deserialize_wrapped_structs() {
+    use arrow_convert::field::NestedOption;
+    use std::ops::{Range, RangeInclusive};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(deny_unknown_fields)]
+    struct Reordered {
+        y: i32,
+        x: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Outer {
+        result: std::result::Result<Point, i32>,
+        range: Range<Point>,
+        range_inclusive: RangeInclusive<Point>,
+        #[arrow_field(type = "NestedOption<Point>")]
+        nested: Option<Option<Point>>,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct ReorderedOuter {
+        result: std::result::Result<Reordered, i32>,
+        range: Range<Reordered>,
+        range_inclusive: RangeInclusive<Reordered>,
+        #[arrow_field(type = "NestedOption<Reordered>")]
+        nested: Option<Option<Reordered>>,
+    }
+
+    // results, ranges and nested options check their children, so they accept reordered structs
+    let point = |x| Point { x, y: x + 1 };
+    let reordered = |x| Reordered { y: x + 1, x };
+    let outer = vec![Outer {
+        result: Ok(point(1)),
+        range: point(2)..point(3),
+        range_inclusive: point(4)..=point(5),
+        nested: Some(Some(point(6))),
+    }];
+    let arr: ArrayRef = outer.try_into_arrow().unwrap();
+    let result: Vec<ReorderedOuter> = arr.try_into_collection().unwrap();
+    assert_eq!(
+        result,
+        vec![ReorderedOuter {
+            result: Ok(reordered(1)),
+            range: reordered(2)..reordered(3),
+            range_inclusive: reordered(4)..=reordered(5),
+            nested: Some(Some(reordered(6))),
+        }]
+    );
+
+    // and return the unknown columns of their children
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wide {
+        x: i32,
+        y: i32,
+        extra: i32,
+    }
+    let wide = Wide { x: 1, y: 2, extra: 3 };
+    let arr: ArrayRef = vec![std::result::Result::<Wide, i32>::Ok(wide.clone())]
+        .try_into_arrow()
+        .unwrap();
+    let result: Result<Vec<std::result::Result<Reordered, i32>>> = arr.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(error.ends_with(": ok.extra"), "{error}");
+    let arr: ArrayRef = vec![wide.clone()..wide.clone()].try_into_arrow().unwrap();
+    let result: Result<Vec<Range<Reordered>>> = arr.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(error.ends_with(": start.extra, end.extra"), "{error}");
+    let arr: ArrayRef = vec![wide.clone()..=wide.clone()].try_into_arrow().unwrap();
+    let result: Result<Vec<RangeInclusive<Reordered>>> = arr.try_into_collection();
+    assert!(result.is_err());
+    let arr: ArrayRef = vec![Some(Some(wide))]
+        .try_into_arrow_as_type::<NestedOption<Wide>>()
+        .unwrap();
+    let result: Result<Vec<Option<Option<Reordered>>>> = arr.try_into_collection_as_type::<NestedOption<Reordered>>();
+    let error = result.unwrap_err().to_string();
+    assert!(error.ends_with(": value.extra"), "{error}");
+}
+
+#[test]
+fn test_deserialize_record_batch() {
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+    }
+
+    let rows = vec![
+        Row {
+            id: 1,
+            name: Some("a".to_string()),
+        },
+        Row { id: 2, name: None },
+    ];
+    let batch: RecordBatch = rows.try_into_record_batch().unwrap();
+    let round_trip: Vec<Row> = (&batch).try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+
+    // the columns are matched by name
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("extra", DataType::Boolean, false),
+            Field::new("id", DataType::Int64, false),
+        ])),
+        vec![
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+            Arc::new(BooleanArray::from(vec![true, false])),
+            Arc::new(Int64Array::from(vec![1, 2])),
+        ],
+    )
+    .unwrap();
+    let round_trip: Vec<Row> = batch.try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)])),
+        vec![Arc::new(Int32Array::from(vec![1]))],
+    )
+    .unwrap();
+    let result: Result<Vec<Row>> = batch.try_into_collection();
+    assert!(result.is_err());
+}
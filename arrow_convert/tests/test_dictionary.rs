@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{ArrowField, Dictionary, LargeString};
+use arrow_convert::serialize::{SerializeOptions, TryIntoArrow, TypedBatchSerializer};
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_dictionary_schema() {
+    assert_eq!(
+        <Dictionary<i32, String> as ArrowField>::data_type(),
+        DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+    );
+    assert_eq!(
+        <Dictionary<u8, LargeString> as ArrowField>::data_type(),
+        DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::LargeUtf8))
+    );
+    assert_eq!(
+        <Dictionary<i16, i64> as ArrowField>::data_type(),
+        DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Int64))
+    );
+}
+
+#[test]
+fn test_dictionary_round_trip() {
+    let original = vec![
+        "red".to_string(),
+        "green".to_string(),
+        "red".to_string(),
+        "red".to_string(),
+    ];
+
+    let b: ArrayRef = original.try_into_arrow_as_type::<Dictionary<i32, String>>().unwrap();
+    let dictionary = b.as_any().downcast_ref::<DictionaryArray<Int32Type>>().unwrap();
+    assert_eq!(dictionary.values().len(), 2);
+    assert_eq!(dictionary.keys().values().to_vec(), vec![0, 1, 0, 0]);
+
+    let round_trip: Vec<String> = b.try_into_collection_as_type::<Dictionary<i32, String>>().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![Some(7i64), None, Some(7), Some(-1)];
+    let b: ArrayRef = original
+        .try_into_arrow_as_type::<Option<Dictionary<u16, i64>>>()
+        .unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<i64>> = b.try_into_collection_as_type::<Option<Dictionary<u16, i64>>>().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_dictionary_null_values() {
+    // dictionaries produced by other writers may contain null values
+    let keys = Int8Array::from(vec![Some(0), Some(1), None, Some(1)]);
+    let values = Arc::new(StringArray::from(vec![Some("a"), None]));
+    let b: ArrayRef = Arc::new(DictionaryArray::new(keys, values));
+
+    let round_trip: Vec<Option<String>> = b
+        .try_into_collection_as_type::<Option<Dictionary<i8, String>>>()
+        .unwrap();
+    assert_eq!(round_trip, vec![Some("a".to_string()), None, None, None]);
+}
+
+#[test]
+fn test_dictionary_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        #[arrow_field(type = "Dictionary<i32, String>")]
+        status: String,
+        #[arrow_field(type = "Option<Dictionary<i8, Vec<u8>>>")]
+        code: Option<Vec<u8>>,
+        #[arrow_field(type = "Vec<Dictionary<u32, String>>")]
+        tags: Vec<String>,
+    }
+
+    let original = vec![
+        Row {
+            status: "active".to_string(),
+            code: Some(b"x".to_vec()),
+            tags: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+        },
+        Row {
+            status: "active".to_string(),
+            code: None,
+            tags: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        struct_array.column_by_name("status").unwrap().data_type(),
+        &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+    );
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_shared_dictionary_across_batches() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(type = "Dictionary<i32, String>")]
+        kind: String,
+        #[arrow_field(type = "Option<Dictionary<i8, i64>>")]
+        code: Option<i64>,
+    }
+
+    fn event(kind: &str, code: Option<i64>) -> Event {
+        Event {
+            kind: kind.to_string(),
+            code,
+        }
+    }
+
+    let mut serializer =
+        TypedBatchSerializer::<Event>::with_options(SerializeOptions::default().with_shared_dictionaries(true));
+    serializer.push(&event("open", Some(3))).unwrap();
+    serializer.push(&event("close", None)).unwrap();
+    let first = serializer.flush().unwrap();
+    serializer.push(&event("close", Some(3))).unwrap();
+    serializer.push(&event("retry", Some(5))).unwrap();
+    serializer.push(&event("open", Some(5))).unwrap();
+    assert_eq!(serializer.len(), 3);
+    let second = serializer.flush().unwrap();
+
+    let kinds = first.column(0).as_dictionary::<Int32Type>();
+    assert_eq!(kinds.keys().values().to_vec(), vec![0, 1]);
+    assert_eq!(
+        kinds.values().as_string::<i32>(),
+        &StringArray::from(vec!["open", "close"])
+    );
+
+    // the second batch keeps the keys of the first batch and appends the new values to its dictionary
+    let kinds = second.column(0).as_dictionary::<Int32Type>();
+    assert_eq!(second.num_rows(), 3);
+    assert_eq!(kinds.keys().values().to_vec(), vec![1, 2, 0]);
+    assert_eq!(
+        kinds.values().as_string::<i32>(),
+        &StringArray::from(vec!["open", "close", "retry"])
+    );
+    let codes = second.column(1).as_dictionary::<Int8Type>();
+    assert_eq!(codes.keys(), &Int8Array::from(vec![0, 1, 1]));
+    assert_eq!(
+        codes.values().as_primitive::<Int64Type>(),
+        &Int64Array::from(vec![3, 5])
+    );
+
+    let array = StructArray::from(second);
+    let round_trip: Vec<Event> = arrow_convert::deserialize::arrow_array_deserialize_iterator(&array)
+        .unwrap()
+        .collect();
+    assert_eq!(
+        round_trip,
+        vec![event("close", Some(3)), event("retry", Some(5)), event("open", Some(5))]
+    );
+
+    // without the option each batch has its own dictionary
+    let mut serializer = TypedBatchSerializer::<Event>::new();
+    serializer.push(&event("open", None)).unwrap();
+    serializer.flush().unwrap();
+    serializer.push(&event("close", None)).unwrap();
+    let batch = serializer.flush().unwrap();
+    let kinds = batch.column(0).as_dictionary::<Int32Type>();
+    assert_eq!(kinds.keys().values().to_vec(), vec![0]);
+    assert_eq!(kinds.values().len(), 1);
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::serialize::{serialize_dynamic, ArrowValue, DynamicBatchSerializer};
+use pretty_assertions::assert_eq;
+
+fn schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new("name", DataType::LargeUtf8, true),
+        Field::new("score", DataType::Float64, true),
+        Field::new(
+            "at",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            true,
+        ),
+        Field::new_list("tags", Field::new_list_field(DataType::Utf8, false), true),
+        Field::new_struct(
+            "owner",
+            vec![
+                Field::new("uid", DataType::UInt8, false),
+                Field::new("admin", DataType::Boolean, true),
+            ],
+            true,
+        ),
+    ]))
+}
+
+#[test]
+fn test_serialize_dynamic() {
+    let rows = vec![
+        vec![
+            ArrowValue::Int(1),
+            ArrowValue::Utf8("a".to_string()),
+            ArrowValue::Int(3),
+            ArrowValue::Int(1_700_000_000_000),
+            ArrowValue::List(vec![
+                ArrowValue::Utf8("x".to_string()),
+                ArrowValue::Utf8("y".to_string()),
+            ]),
+            ArrowValue::Struct(vec![ArrowValue::UInt(7), ArrowValue::Boolean(true)]),
+        ],
+        vec![
+            ArrowValue::Int(2),
+            ArrowValue::Null,
+            ArrowValue::Float(0.5),
+            ArrowValue::Null,
+            ArrowValue::Null,
+            ArrowValue::Null,
+        ],
+    ];
+
+    let batch = serialize_dynamic(&rows, schema()).unwrap();
+    assert_eq!(batch.schema(), schema());
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(
+        batch.column(0).as_primitive::<Int32Type>().values().to_vec(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        batch.column(1).as_string::<i64>().iter().collect::<Vec<_>>(),
+        vec![Some("a"), None]
+    );
+    assert_eq!(
+        batch.column(2).as_primitive::<Float64Type>().values().to_vec(),
+        vec![3.0, 0.5]
+    );
+    assert_eq!(
+        batch.column(3).as_primitive::<TimestampMillisecondType>().value(0),
+        1_700_000_000_000
+    );
+    let tags = batch.column(4).as_list::<i32>();
+    assert_eq!(tags.null_count(), 1);
+    assert_eq!(
+        tags.value(0).as_string::<i32>(),
+        &StringArray::from(vec!["x", "y"])
+    );
+    let owner = batch.column(5).as_struct();
+    assert_eq!(owner.null_count(), 1);
+    assert_eq!(owner.column(0).as_primitive::<UInt8Type>().value(0), 7);
+}
+
+#[test]
+fn test_serialize_dynamic_maps() {
+    // values are looked up by column name, and missing values are null
+    let rows = vec![
+        HashMap::from([
+            ("id".to_string(), ArrowValue::Int(1)),
+            ("name".to_string(), ArrowValue::Utf8("a".to_string())),
+        ]),
+        HashMap::from([("id".to_string(), ArrowValue::UInt(2))]),
+    ];
+    let batch = serialize_dynamic(&rows, schema()).unwrap();
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(
+        batch.column(0).as_primitive::<Int32Type>().values().to_vec(),
+        vec![1, 2]
+    );
+    assert_eq!(batch.column(1).null_count(), 1);
+    assert_eq!(batch.column(5).null_count(), 2);
+}
+
+#[test]
+fn test_dynamic_batch_serializer() {
+    let mut serializer = DynamicBatchSerializer::try_new(schema()).unwrap();
+    serializer.push(&[ArrowValue::Int(1)][..]).unwrap();
+    serializer.push(&vec![ArrowValue::Int(2)]).unwrap();
+    assert_eq!(serializer.len(), 2);
+
+    // errors name the row and column, and discard the current batch
+    assert_eq!(
+        serializer
+            .push(&vec![ArrowValue::Int(300), ArrowValue::Int(1)])
+            .unwrap_err()
+            .to_string(),
+        "Invalid argument error: Row 2: Value Int(1) of column `name` doesn't fit its type LargeUtf8"
+    );
+    assert!(serializer.is_empty());
+    assert_eq!(
+        serializer
+            .push(&vec![ArrowValue::Int(i64::MAX)])
+            .unwrap_err()
+            .to_string(),
+        "Invalid argument error: Row 0: Value Int(9223372036854775807) of column `id` doesn't fit its type Int32"
+    );
+    assert_eq!(
+        serializer.push(&vec![ArrowValue::Null]).unwrap_err().to_string(),
+        "Invalid argument error: Row 0: Column `id` isn't nullable but has no value"
+    );
+    assert_eq!(
+        serializer
+            .push(&HashMap::from([
+                ("id".to_string(), ArrowValue::Int(1)),
+                ("other".to_string(), ArrowValue::Int(1)),
+            ]))
+            .unwrap_err()
+            .to_string(),
+        "Invalid argument error: Row 0: The row has 2 values but only 1 of them match a column of the schema"
+    );
+
+    serializer.push(&vec![ArrowValue::Int(3)]).unwrap();
+    let batch = serializer.flush().unwrap();
+    assert_eq!(
+        batch.column(0).as_primitive::<Int32Type>().values().to_vec(),
+        vec![3]
+    );
+    assert!(serializer.is_empty());
+    assert_eq!(serializer.flush().unwrap().num_rows(), 0);
+
+    let schema = Arc::new(Schema::new(vec![Field::new("f", DataType::Float16, false)]));
+    assert!(DynamicBatchSerializer::try_new(schema).is_err());
+}
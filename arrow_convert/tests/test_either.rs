@@ -0,0 +1,184 @@
+#[cfg(feature = "either")]
+#[test]
+fn test_either_schema() {
+    use arrow::datatypes::{DataType, Field, UnionFields, UnionMode};
+    use arrow_convert::field::{ArrowField, SparseEither};
+    use either::Either;
+    use pretty_assertions::assert_eq;
+
+    let fields = UnionFields::new(
+        0..2,
+        vec![
+            Field::new("left", DataType::Int32, false),
+            Field::new("right", DataType::Utf8, false),
+        ],
+    );
+    assert_eq!(
+        <Either<i32, String> as ArrowField>::data_type(),
+        DataType::Union(fields.clone(), UnionMode::Dense)
+    );
+    assert_eq!(
+        <SparseEither<i32, String> as ArrowField>::data_type(),
+        DataType::Union(fields, UnionMode::Sparse)
+    );
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn test_either_roundtrip() {
+    use arrow::array::{Array, ArrayRef, UnionArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::SparseEither;
+    use arrow_convert::serialize::TryIntoArrow;
+    use either::Either;
+    use pretty_assertions::assert_eq;
+
+    let original: Vec<Either<i64, String>> = vec![Either::Left(1), Either::Right("two".to_string()), Either::Left(3)];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert!(union.offsets().is_some());
+    assert_eq!(union.child(0).len(), 2);
+    assert_eq!(union.child(1).len(), 1);
+    let round_trip: Vec<Either<i64, String>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let b: ArrayRef = original.try_into_arrow_as_type::<SparseEither<i64, String>>().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert!(union.offsets().is_none());
+    assert_eq!(union.child(0).len(), 3);
+    assert_eq!(union.child(1).len(), 3);
+    let round_trip: Vec<Either<i64, String>> = b.try_into_collection_as_type::<SparseEither<i64, String>>().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<Option<Either<bool, f64>>> = vec![Some(Either::Right(1.5)), None, Some(Either::Left(true))];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<Either<bool, f64>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let b: ArrayRef = original
+        .try_into_arrow_as_type::<Option<SparseEither<bool, f64>>>()
+        .unwrap();
+    let round_trip: Vec<Option<Either<bool, f64>>> = b
+        .try_into_collection_as_type::<Option<SparseEither<bool, f64>>>()
+        .unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn test_either_in_struct() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use either::Either;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        id: Either<u64, String>,
+        #[arrow_field(type = "arrow_convert::field::SparseEither<i32, Vec<i32>>")]
+        value: Either<i32, Vec<i32>>,
+        tags: Vec<Either<bool, String>>,
+    }
+
+    let original = vec![
+        Event {
+            id: Either::Left(1),
+            value: Either::Right(vec![1, 2]),
+            tags: vec![Either::Left(true), Either::Right("x".to_string())],
+        },
+        Event {
+            id: Either::Right("abc".to_string()),
+            value: Either::Left(7),
+            tags: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn test_either_validate() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use either::Either;
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    let original: Vec<Either<i32, Positive>> = vec![Either::Right(Positive { v: 0 })];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Either<i32, Positive>>> = arrow_array.try_into_collection();
+    assert!(result.unwrap_err().to_string().ends_with("right: v: 0 is not positive"));
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn test_either_reordered_fields() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::SparseEither;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use either::Either;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(deny_unknown_fields)]
+    struct Reordered {
+        y: i32,
+        x: i32,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wide {
+        x: i32,
+        y: i32,
+        extra: i32,
+    }
+
+    let original: Vec<Either<i32, Point>> = vec![Either::Right(Point { x: 1, y: 2 }), Either::Left(3)];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: Vec<Either<i32, Reordered>> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(
+        result,
+        vec![Either::Right(Reordered { y: 2, x: 1 }), Either::Left(3)]
+    );
+
+    let wide: Vec<Either<i32, Wide>> = vec![Either::Right(Wide { x: 1, y: 2, extra: 3 })];
+    let wide_array: ArrayRef = wide.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Either<i32, Reordered>>> = wide_array.try_into_collection();
+    let error = result.unwrap_err().to_string();
+    assert!(error.ends_with(": right.extra"), "{error}");
+
+    let arrow_array: ArrayRef = original.try_into_arrow_as_type::<SparseEither<i32, Point>>().unwrap();
+    let result: Vec<Either<i32, Point>> = arrow_array
+        .clone()
+        .try_into_collection_as_type::<SparseEither<i32, Point>>()
+        .unwrap();
+    assert_eq!(result, original);
+    // the union mode is still checked
+    let result: arrow::error::Result<Vec<Either<i32, Point>>> = arrow_array.try_into_collection();
+    assert!(result.is_err());
+}
@@ -188,3 +188,524 @@ fn test_slice() {
         assert_eq!(round_trip, original_slice);
     }
 }
+
+#[test]
+fn test_enum_struct_and_tuple_variants() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Address {
+        city: String,
+    }
+
+    // dense is the default mode
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    enum Event {
+        Login {
+            user: String,
+            #[arrow_field(name = "remember-me")]
+            remember_me: bool,
+        },
+        Move(i64, i64),
+        Relocate(Address),
+        Logout,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum SparseEvent {
+        Login { user: String, attempts: Option<u8> },
+        Move(i64, i64),
+        Logout,
+    }
+
+    assert_eq!(
+        <Event as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Union(
+            UnionFields::new(
+                vec![0, 1, 2, 3],
+                vec![
+                    Field::new(
+                        "Login",
+                        DataType::Struct(Fields::from(vec![
+                            Field::new("user", DataType::Utf8, false),
+                            Field::new("remember-me", DataType::Boolean, false),
+                        ])),
+                        false
+                    ),
+                    Field::new(
+                        "Move",
+                        DataType::Struct(Fields::from(vec![
+                            Field::new("0", DataType::Int64, false),
+                            Field::new("1", DataType::Int64, false),
+                        ])),
+                        false
+                    ),
+                    Field::new(
+                        "Relocate",
+                        DataType::Struct(Fields::from(vec![Field::new("city", DataType::Utf8, false)])),
+                        false
+                    ),
+                    Field::new("Logout", DataType::Boolean, false),
+                ]
+            ),
+            UnionMode::Dense
+        )
+    );
+
+    let enums = vec![
+        Event::Login {
+            user: "ann".to_string(),
+            remember_me: true,
+        },
+        Event::Move(1, -2),
+        Event::Relocate(Address {
+            city: "Lyon".to_string(),
+        }),
+        Event::Logout,
+        Event::Move(3, 4),
+    ];
+    let b: ArrayRef = enums.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+
+    let enums = vec![
+        Some(SparseEvent::Login {
+            user: "bob".to_string(),
+            attempts: None,
+        }),
+        Some(SparseEvent::Move(5, 6)),
+        Some(SparseEvent::Logout),
+        Some(SparseEvent::Login {
+            user: "eve".to_string(),
+            attempts: Some(3),
+        }),
+    ];
+    let b: ArrayRef = enums.try_into_arrow().unwrap();
+    assert_eq!(b.len(), 4);
+    let round_trip: Vec<Option<SparseEvent>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+}
+
+#[test]
+fn test_enum_mode() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "sparse")]
+    enum Shape {
+        Circle(f64),
+        Rect { w: f64, h: f64 },
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "dense")]
+    enum DenseShape {
+        Circle(f64),
+        Rect { w: f64, h: f64 },
+        Empty,
+    }
+
+    let shapes = vec![Shape::Circle(1.0), Shape::Rect { w: 2.0, h: 3.0 }, Shape::Empty];
+    let b: ArrayRef = shapes.try_into_arrow().unwrap();
+    let DataType::Union(_, mode) = b.data_type() else {
+        panic!("expected a union")
+    };
+    assert_eq!(mode, &UnionMode::Sparse);
+    let arr = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert!(arr.offsets().is_none());
+    assert_eq!(arr.child(0).len(), 3);
+    let round_trip: Vec<Shape> = b.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, shapes);
+
+    // the union mode and type ids must match the enum
+    let result: arrow::error::Result<Vec<DenseShape>> = b.try_into_collection();
+    assert!(result.is_err());
+    let fields = UnionFields::new(
+        vec![1, 2, 3],
+        vec![
+            Field::new("Circle", DataType::Float64, false),
+            Field::new("Rect", DataType::Float64, false),
+            Field::new("Empty", DataType::Boolean, false),
+        ],
+    );
+    let shifted = UnionArray::try_new(
+        fields,
+        vec![1i8].into(),
+        None,
+        vec![
+            std::sync::Arc::new(Float64Array::from(vec![1.0])) as ArrayRef,
+            std::sync::Arc::new(Float64Array::from(vec![0.0])),
+            std::sync::Arc::new(BooleanArray::from(vec![false])),
+        ],
+    )
+    .unwrap();
+    let shifted: ArrayRef = std::sync::Arc::new(shifted);
+    let result: arrow::error::Result<Vec<Shape>> = shifted.try_into_collection();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_dictionary_enum() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "dictionary", rename_all = "lowercase")]
+    enum Color {
+        Red,
+        Green,
+        #[arrow_field(name = "dark-blue")]
+        Blue,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Car {
+        color: Color,
+        roof: Option<Color>,
+    }
+
+    assert_eq!(
+        *Car::arrow_schema(),
+        Schema::new(vec![
+            Field::new(
+                "color",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                false
+            ),
+            Field::new(
+                "roof",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                true
+            ),
+        ])
+    );
+
+    let colors = vec![Color::Red, Color::Blue, Color::Red, Color::Green];
+    let b: ArrayRef = colors.try_into_arrow().unwrap();
+    let dictionary = b.as_any().downcast_ref::<DictionaryArray<Int32Type>>().unwrap();
+    assert_eq!(dictionary.keys().values().to_vec(), vec![0, 1, 0, 2]);
+    assert_eq!(
+        dictionary.values().as_string::<i32>().iter().collect::<Vec<_>>(),
+        vec![Some("red"), Some("dark-blue"), Some("green")]
+    );
+    let round_trip: Vec<Color> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, colors);
+
+    let cars = vec![
+        Car {
+            color: Color::Green,
+            roof: None,
+        },
+        Car {
+            color: Color::Blue,
+            roof: Some(Color::Red),
+        },
+    ];
+    let b: ArrayRef = cars.try_into_arrow().unwrap();
+    let round_trip: Vec<Car> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, cars);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "dictionary")]
+    enum Shade {
+        Light,
+        #[arrow_field(fallback)]
+        Other,
+    }
+
+    let keys = Int32Array::from(vec![0, 1]);
+    let values = std::sync::Arc::new(StringArray::from(vec!["Light", "Dark"]));
+    let b: ArrayRef = std::sync::Arc::new(DictionaryArray::new(keys, values));
+    let round_trip: Vec<Shade> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Shade::Light, Shade::Other]);
+}
+
+#[test]
+fn test_dictionary_enum_unknown_variant() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "dictionary")]
+    enum Color {
+        Red,
+    }
+
+    let keys = Int32Array::from(vec![0]);
+    let values = std::sync::Arc::new(StringArray::from(vec!["purple"]));
+    let b: ArrayRef = std::sync::Arc::new(DictionaryArray::new(keys, values));
+    let result: arrow::error::Result<Vec<Color>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Unknown variant `purple` deserializing `Color`"));
+}
+
+#[test]
+fn test_repr_enum() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(repr = "i8")]
+    enum Priority {
+        Low = -1,
+        Normal = 0,
+        High = 10,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(repr = "u32")]
+    enum Status {
+        Active = 1,
+        Closed = 2,
+        #[arrow_field(fallback)]
+        Unknown = 0,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Ticket {
+        priority: Priority,
+        status: Option<Status>,
+    }
+
+    assert_eq!(
+        *Ticket::arrow_schema(),
+        Schema::new(vec![
+            Field::new("priority", DataType::Int8, false),
+            Field::new("status", DataType::UInt32, true),
+        ])
+    );
+
+    let priorities = vec![Priority::High, Priority::Low, Priority::Normal];
+    let b: ArrayRef = priorities.try_into_arrow().unwrap();
+    assert_eq!(b.as_primitive::<Int8Type>().values().to_vec(), vec![10, -1, 0]);
+    let round_trip: Vec<Priority> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, priorities);
+
+    let tickets = vec![
+        Ticket {
+            priority: Priority::Low,
+            status: Some(Status::Closed),
+        },
+        Ticket {
+            priority: Priority::High,
+            status: None,
+        },
+    ];
+    let b: ArrayRef = tickets.try_into_arrow().unwrap();
+    let round_trip: Vec<Ticket> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, tickets);
+
+    // unknown codes are deserialized to the fallback variant
+    let b: ArrayRef = std::sync::Arc::new(UInt32Array::from(vec![1, 7, 2]));
+    let round_trip: Vec<Status> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Status::Active, Status::Unknown, Status::Closed]
+    );
+}
+
+#[test]
+fn test_repr_enum_unknown_code() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(repr = "i16")]
+    enum Priority {
+        Low,
+        High,
+    }
+
+    // the same layouts with codes in place of `Priority`
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum RawTask {
+        Todo(i16),
+        Done,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum Task {
+        Todo(Priority),
+        Done,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "struct")]
+    enum RawEvent {
+        Created { priority: i16, owner: String },
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "struct")]
+    enum Event {
+        Created { priority: Priority, owner: String },
+    }
+
+    let b: ArrayRef = std::sync::Arc::new(Int16Array::from(vec![0, 3]));
+    let result: arrow::error::Result<Vec<Priority>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Unknown variant `3` deserializing `Priority`"));
+
+    // codes of variants are checked with the variant name, and the field name of struct variants
+    let b: ArrayRef = vec![RawTask::Done, RawTask::Todo(3)].try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Task>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Todo: Unknown variant `3` deserializing `Priority`"));
+
+    let raw = vec![RawEvent::Created {
+        priority: 3,
+        owner: "a".to_string(),
+    }];
+    let b: ArrayRef = raw.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Event>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Created: priority: Unknown variant `3` deserializing `Priority`"));
+
+    // so are the tags of struct enums
+    let raw = vec![RawEvent::Created {
+        priority: 0,
+        owner: "a".to_string(),
+    }];
+    let b: ArrayRef = raw.try_into_arrow().unwrap();
+    let array = b.as_struct();
+    let mut columns = array.columns().to_vec();
+    columns[0] = std::sync::Arc::new(StringArray::from(vec!["Deleted"]));
+    let b: ArrayRef = std::sync::Arc::new(StructArray::new(array.fields().clone(), columns, None));
+    let result: arrow::error::Result<Vec<Event>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Unknown variant `Deleted` deserializing `Event`"));
+}
+
+#[test]
+fn test_struct_enum() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "struct", rename_all = "snake_case")]
+    enum Shape {
+        Circle(f64),
+        Rect { width: u32, height: u32 },
+        Label(Option<String>),
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Drawing {
+        id: i32,
+        shape: Option<Shape>,
+    }
+
+    assert_eq!(
+        <Shape as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("tag", DataType::Utf8, false),
+            Field::new("circle", DataType::Float64, true),
+            Field::new(
+                "rect",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("width", DataType::UInt32, false),
+                    Field::new("height", DataType::UInt32, false),
+                ])),
+                true
+            ),
+            Field::new("label", DataType::Utf8, true),
+            Field::new("empty", DataType::Boolean, true),
+        ]))
+    );
+
+    let shapes = vec![
+        Some(Shape::Circle(1.5)),
+        Some(Shape::Rect { width: 2, height: 3 }),
+        None,
+        Some(Shape::Label(None)),
+        Some(Shape::Label(Some("a".to_string()))),
+        Some(Shape::Empty),
+    ];
+    let b: ArrayRef = shapes.try_into_arrow().unwrap();
+    let array = b.as_struct();
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(
+        array.column(0).as_string::<i32>().iter().collect::<Vec<_>>(),
+        vec![
+            Some("circle"),
+            Some("rect"),
+            None,
+            Some("label"),
+            Some("label"),
+            Some("empty")
+        ]
+    );
+    assert_eq!(array.column(1).null_count(), 5);
+    let round_trip: Vec<Option<Shape>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, shapes);
+
+    let drawings = vec![
+        Drawing {
+            id: 1,
+            shape: Some(Shape::Rect { width: 4, height: 5 }),
+        },
+        Drawing { id: 2, shape: None },
+    ];
+    let b: ArrayRef = drawings.try_into_arrow().unwrap();
+    let round_trip: Vec<Drawing> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, drawings);
+
+    // a union isn't read as a struct
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    enum Union {
+        Circle(f64),
+    }
+    let b: ArrayRef = vec![Union::Circle(1.0)].try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Shape>> = b.try_into_collection();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_enum_type_ids() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum Dense {
+        #[arrow_field(type_id = 5)]
+        A(i32),
+        #[arrow_field(type_id = 7)]
+        B(String),
+        C,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum Sparse {
+        #[arrow_field(type_id = 10)]
+        A(i32),
+        #[arrow_field(type_id = 0)]
+        B(bool),
+    }
+
+    let DataType::Union(fields, _) = <Dense as arrow_convert::field::ArrowField>::data_type() else {
+        panic!("expected a union");
+    };
+    assert_eq!(
+        fields.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+        vec![5, 7, 2]
+    );
+
+    let values = vec![Some(Dense::A(1)), None, Some(Dense::B("b".to_string())), Some(Dense::C)];
+    let b: ArrayRef = values.try_into_arrow().unwrap();
+    let array = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(array.type_ids().to_vec(), vec![5, 5, 7, 2]);
+    let round_trip: Vec<Option<Dense>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, values);
+
+    let values = vec![Sparse::A(1), Sparse::B(true)];
+    let b: ArrayRef = values.try_into_arrow().unwrap();
+    let array = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(array.type_ids().to_vec(), vec![10, 0]);
+    let round_trip: Vec<Sparse> = b.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, values);
+
+    // the type ids must match to deserialize
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum Positional {
+        A(i32),
+        B(bool),
+    }
+    let result: arrow::error::Result<Vec<Positional>> = b.try_into_collection();
+    assert!(result.is_err());
+}
@@ -0,0 +1,65 @@
+#[cfg(feature = "flight")]
+#[test]
+fn test_flight_data_stream() {
+    use arrow::array::StructArray;
+    use arrow_convert::deserialize::arrow_array_deserialize_iterator;
+    use arrow_convert::serialize::{flight_data_stream, DEFAULT_MAX_ROWS_PER_BATCH};
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use arrow_flight::decode::FlightRecordBatchStream;
+    use arrow_flight::encode::FlightDataEncoderBuilder;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+    }
+
+    let rows = (0..DEFAULT_MAX_ROWS_PER_BATCH as i64 + 10)
+        .map(|id| Row {
+            id,
+            name: (id % 2 == 0).then(|| id.to_string()),
+        })
+        .collect::<Vec<_>>();
+
+    futures::executor::block_on(async {
+        // the messages are a schema followed by a message per batch
+        let messages = flight_data_stream::<Row, _>(rows.clone(), FlightDataEncoderBuilder::new())
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), 3);
+
+        let stream = flight_data_stream::<Row, _>(rows.clone(), FlightDataEncoderBuilder::new()).unwrap();
+        let batches = FlightRecordBatchStream::new_from_flight_data(stream)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![DEFAULT_MAX_ROWS_PER_BATCH, 10]
+        );
+        let decoded = batches
+            .into_iter()
+            .flat_map(|batch| {
+                assert_eq!(batch.schema(), Row::arrow_schema());
+                arrow_array_deserialize_iterator::<Row>(&StructArray::from(batch))
+                    .unwrap()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, rows);
+
+        // the schema is sent without rows
+        let messages = flight_data_stream::<Row, _>(Vec::<Row>::new(), FlightDataEncoderBuilder::new())
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), 1);
+    });
+
+    assert!(flight_data_stream::<i64, _>(vec![1i64], FlightDataEncoderBuilder::new()).is_err());
+}
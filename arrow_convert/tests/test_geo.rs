@@ -0,0 +1,142 @@
+#[cfg(feature = "geo")]
+#[test]
+fn test_geo_schema() {
+    use arrow::datatypes::DataType;
+    use arrow_convert::field::{ArrowField, EXTENSION_NAME_KEY};
+    use geo_types::{Geometry, Point, Polygon};
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(<Point as ArrowField>::data_type(), DataType::Binary);
+    assert_eq!(<Geometry as ArrowField>::data_type(), DataType::Binary);
+
+    let field = <Option<Polygon> as ArrowField>::field("shape");
+    assert!(field.is_nullable());
+    assert_eq!(
+        field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str),
+        Some("geoarrow.wkb")
+    );
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn test_geo_wkb_encoding() {
+    use arrow::array::{ArrayRef, BinaryArray};
+    use arrow_convert::serialize::TryIntoArrow;
+    use geo_types::Point;
+    use pretty_assertions::assert_eq;
+
+    let original = vec![Point::new(1.0, 2.0)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let binary = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+
+    let mut expected = vec![1u8, 1, 0, 0, 0];
+    expected.extend_from_slice(&1.0f64.to_le_bytes());
+    expected.extend_from_slice(&2.0f64.to_le_bytes());
+    assert_eq!(binary.value(0), expected.as_slice());
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn test_geo_roundtrip() {
+    use arrow::array::{Array, ArrayRef, BinaryArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use geo_types::{
+        coord, line_string, point, polygon, Geometry, GeometryCollection, LineString, MultiPoint, Polygon, Rect,
+    };
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    let original: Vec<Option<LineString>> = vec![Some(line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]), None];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<LineString>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<Polygon> = vec![
+        polygon!(
+            exterior: [(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 0.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0), (x: 1.0, y: 1.0)]],
+        ),
+        Polygon::new(LineString(vec![]), vec![]),
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Polygon> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<Geometry> = vec![
+        point!(x: 1.0, y: 2.0).into(),
+        MultiPoint(vec![point!(x: 1.0, y: 2.0), point!(x: 3.0, y: 4.0)]).into(),
+        Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: 5.0, y: 6.0).into(),
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)].into(),
+        ])),
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Geometry> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // a `Rect` is written as a polygon
+    let rect = Rect::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 1.0, y: 1.0 });
+    let original: Vec<Geometry> = vec![rect.into()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Geometry> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Geometry::Polygon(rect.to_polygon())]);
+
+    // big-endian WKB is accepted
+    let mut big_endian = vec![0u8, 0, 0, 0, 1];
+    big_endian.extend_from_slice(&3.0f64.to_be_bytes());
+    big_endian.extend_from_slice(&4.0f64.to_be_bytes());
+    let b: ArrayRef = Arc::new(BinaryArray::from_vec(vec![big_endian.as_slice()]));
+    let round_trip: Vec<geo_types::Point> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![point!(x: 3.0, y: 4.0)]);
+}
+
+#[cfg(feature = "geo")]
+#[test]
+#[should_panic(expected = "Expected a WKB geometry of type")]
+fn test_geo_mismatched_geometry_type() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use geo_types::{point, Point, Polygon};
+
+    let original: Vec<Point> = vec![point!(x: 1.0, y: 2.0)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let _: Vec<Polygon> = b.try_into_collection().unwrap();
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn test_geo_in_struct() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use geo_types::{line_string, point, LineString, Point};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Trip {
+        start: Point,
+        route: Option<LineString>,
+        stops: Vec<Point>,
+    }
+
+    let original = vec![
+        Trip {
+            start: point!(x: 0.0, y: 0.0),
+            route: Some(line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]),
+            stops: vec![point!(x: 0.5, y: 0.5)],
+        },
+        Trip {
+            start: point!(x: 1.0, y: 1.0),
+            route: None,
+            stops: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Trip> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
@@ -0,0 +1,83 @@
+#[cfg(feature = "indexmap")]
+#[test]
+fn test_indexmap_roundtrip() {
+    use arrow::array::{Array, ArrayRef, MapArray, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::ArrowField;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use indexmap::IndexMap;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        columns: IndexMap<String, Option<i32>>,
+    }
+
+    let original = vec![
+        S {
+            columns: IndexMap::from([
+                ("z".to_string(), Some(1)),
+                ("a".to_string(), None),
+                ("m".to_string(), Some(3)),
+            ]),
+        },
+        S {
+            columns: IndexMap::new(),
+        },
+    ];
+
+    assert_eq!(
+        <IndexMap<String, Option<i32>> as ArrowField>::data_type(),
+        <HashMap<String, Option<i32>> as ArrowField>::data_type()
+    );
+
+    let arrow_array: ArrayRef = original.iter().map(|s| &s.columns).try_into_arrow().unwrap();
+    let map_array = arrow_array.as_any().downcast_ref::<MapArray>().unwrap();
+    let keys = map_array.keys().as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(keys.iter().flatten().collect::<Vec<_>>(), vec!["z", "a", "m"]);
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<S> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+
+    // IndexMap equality ignores order, so check it explicitly.
+    let keys = roundtrip[0].columns.keys().collect::<Vec<_>>();
+    assert_eq!(keys, vec!["z", "a", "m"]);
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn test_indexmap_validate() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use indexmap::IndexMap;
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    let original = vec![IndexMap::from([
+        ("a".to_string(), Positive { v: 1 }),
+        ("b".to_string(), Positive { v: 0 }),
+    ])];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<IndexMap<String, Positive>>> = arrow_array.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("[1]: values: v: 0 is not positive"));
+}
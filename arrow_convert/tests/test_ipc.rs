@@ -0,0 +1,59 @@
+#[cfg(feature = "ipc")]
+#[test]
+fn test_write_ipc() {
+    use arrow::ipc::reader::{FileReader, StreamReader};
+    use arrow::record_batch::RecordBatch;
+    use arrow_convert::deserialize::arrow_array_deserialize_iterator;
+    use arrow_convert::serialize::{write_ipc_file, write_ipc_stream};
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(schema_metadata(source = "test"))]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+    }
+
+    fn read(batches: Vec<RecordBatch>) -> Vec<Row> {
+        batches
+            .into_iter()
+            .flat_map(|batch| {
+                let array = arrow::array::StructArray::from(batch);
+                arrow_array_deserialize_iterator::<Row>(&array)
+                    .unwrap()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    // enough rows for several batches
+    let rows = (0..10_000)
+        .map(|id| Row {
+            id,
+            name: (id % 3 != 0).then(|| format!("row {id}")),
+        })
+        .collect::<Vec<_>>();
+
+    let mut buffer = vec![];
+    write_ipc_stream::<Row, _, _>(&mut buffer, &rows).unwrap();
+    let reader = StreamReader::try_new(buffer.as_slice(), None).unwrap();
+    assert_eq!(reader.schema(), Row::arrow_schema());
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(read(batches), rows);
+
+    let path = std::env::temp_dir().join(format!(
+        "arrow_convert_test_write_ipc_{}.arrow",
+        std::process::id()
+    ));
+    write_ipc_file::<Row, _>(&path, rows.iter().take(3)).unwrap();
+    let reader = FileReader::try_new(std::fs::File::open(&path).unwrap(), None).unwrap();
+    assert_eq!(reader.schema(), Row::arrow_schema());
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read(batches), rows[..3]);
+
+    // only structs have a schema
+    assert!(write_ipc_stream::<i64, _, _>(vec![], [1i64]).is_err());
+}
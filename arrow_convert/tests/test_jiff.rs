@@ -0,0 +1,139 @@
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff_schema() {
+    use arrow::datatypes::{DataType, IntervalUnit, TimeUnit};
+    use arrow_convert::field::ArrowField;
+    use jiff::civil::{Date, Time};
+    use jiff::{Span, Timestamp};
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        <Timestamp as ArrowField>::data_type(),
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    );
+    assert_eq!(<Date as ArrowField>::data_type(), DataType::Date32);
+    assert_eq!(
+        <Time as ArrowField>::data_type(),
+        DataType::Time64(TimeUnit::Nanosecond)
+    );
+    assert_eq!(
+        <Span as ArrowField>::data_type(),
+        DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff_roundtrip() {
+    use arrow::array::{Array, ArrayRef, Date32Array, IntervalMonthDayNanoArray};
+    use arrow::datatypes::IntervalMonthDayNano;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use jiff::civil::{date, time, Date, Time};
+    use jiff::{Span, Timestamp, ToSpan};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        at: Timestamp,
+        day: Date,
+        time: Option<Time>,
+        history: Vec<Timestamp>,
+    }
+
+    let original = vec![date(1970, 1, 2), date(1969, 12, 31), date(2024, 2, 29)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let days = b.as_any().downcast_ref::<Date32Array>().unwrap();
+    assert_eq!(days.values().to_vec(), vec![1, -1, 19782]);
+    let round_trip: Vec<Date> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let at = Timestamp::from_nanosecond(1_700_000_000_123_456_789).unwrap();
+    let original = vec![
+        Event {
+            at,
+            day: date(2023, 11, 14),
+            time: Some(time(22, 13, 20, 123_456_789)),
+            history: vec![Timestamp::UNIX_EPOCH, at],
+        },
+        Event {
+            at: Timestamp::from_second(-86_400).unwrap(),
+            day: date(1900, 1, 1),
+            time: None,
+            history: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // spans are normalized to months, days and nanoseconds
+    let original = vec![1.year().months(2).weeks(1).days(3).hours(4).nanoseconds(5), Span::new()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let intervals = b.as_any().downcast_ref::<IntervalMonthDayNanoArray>().unwrap();
+    assert_eq!(
+        intervals.value(0),
+        IntervalMonthDayNano::new(14, 10, 4 * 3_600_000_000_000 + 5)
+    );
+    let round_trip: Vec<Span> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip[0].fieldwise(),
+        14.months().days(10).nanoseconds(4 * 3_600_000_000_000i64 + 5)
+    );
+    assert_eq!(round_trip[1].fieldwise(), Span::new());
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff_overflow() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::serialize::TryIntoArrow;
+    use jiff::{Timestamp, ToSpan};
+
+    // nanosecond timestamps only cover the years 1677 to 2262
+    let r: arrow::error::Result<ArrayRef> = [Timestamp::MAX].try_into_arrow();
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "Invalid argument error: Row 0: 9999-12-30T22:00:00.999999999Z overflows an Arrow `Timestamp(Nanosecond, \
+         Some(\"UTC\"))`"
+    );
+
+    // the time units of a span are folded into an `i64` of nanoseconds
+    let r: arrow::error::Result<ArrayRef> = [Some(175_307_616.hours())].try_into_arrow();
+    assert!(r.is_err());
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff_span_signs() {
+    use arrow::array::{ArrayRef, IntervalMonthDayNanoArray};
+    use arrow::datatypes::IntervalMonthDayNano;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use jiff::{Span, ToSpan};
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    // negative intervals are negative spans
+    let array: ArrayRef = Arc::new(IntervalMonthDayNanoArray::from(vec![
+        IntervalMonthDayNano::new(-1, -5, -3),
+    ]));
+    let spans: Vec<Span> = array.try_into_collection().unwrap();
+    assert_eq!(spans[0].fieldwise(), -(1.month().days(5).nanoseconds(3)));
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+#[should_panic(expected = "Expected an interval whose components have the same sign")]
+fn test_jiff_span_mixed_signs() {
+    use arrow::array::{ArrayRef, IntervalMonthDayNanoArray};
+    use arrow::datatypes::IntervalMonthDayNano;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use jiff::Span;
+    use std::sync::Arc;
+
+    let array: ArrayRef = Arc::new(IntervalMonthDayNanoArray::from(vec![
+        IntervalMonthDayNano::new(1, -5, 0),
+    ]));
+    let _: Vec<Option<Span>> = array.try_into_collection().unwrap();
+}
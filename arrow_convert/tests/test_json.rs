@@ -0,0 +1,97 @@
+#[cfg(feature = "json")]
+#[test]
+fn test_json_schema() {
+    use arrow::datatypes::DataType;
+    use arrow_convert::field::{ArrowField, LargeJson};
+    use arrow_convert::ArrowField;
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField)]
+    struct Event {
+        payload: Value,
+        #[arrow_field(type = "Option<LargeJson>")]
+        extra: Option<Value>,
+    }
+
+    let schema = Event::arrow_schema();
+    let payload = schema.field_with_name("payload").unwrap();
+    assert_eq!(payload.data_type(), &DataType::Utf8);
+    assert!(!payload.is_nullable());
+    assert_eq!(
+        payload.metadata().get("ARROW:extension:name").map(String::as_str),
+        Some("arrow.json")
+    );
+
+    let extra = schema.field_with_name("extra").unwrap();
+    assert_eq!(extra.data_type(), &DataType::LargeUtf8);
+    assert!(extra.is_nullable());
+    assert_eq!(
+        extra.metadata().get("ARROW:extension:name").map(String::as_str),
+        Some("arrow.json")
+    );
+
+    assert_eq!(
+        <Option<Value> as ArrowField>::field("v"),
+        <Value as ArrowField>::field("v").with_nullable(true)
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_round_trip() {
+    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::LargeJson;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        payload: Value,
+        #[arrow_field(type = "Option<LargeJson>")]
+        extra: Option<Value>,
+        tags: Vec<Value>,
+    }
+
+    let original: Vec<Value> = vec![json!({"a": 1, "b": [true, null]}), json!("text"), Value::Null];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let strings = b.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(strings.value(0), r#"{"a":1,"b":[true,null]}"#);
+    assert_eq!(strings.value(2), "null");
+    let round_trip: Vec<Value> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![
+        Event {
+            payload: json!({"kind": "click", "x": 10}),
+            extra: Some(json!([1, 2.5, "three"])),
+            tags: vec![json!("a"), json!({"b": null})],
+        },
+        Event {
+            payload: json!(null),
+            extra: None,
+            tags: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.len(), 2);
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "json")]
+#[test]
+#[should_panic(expected = "Expected valid JSON")]
+fn test_json_invalid() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use serde_json::Value;
+
+    let original = vec!["{not json".to_string()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let _: Vec<Value> = b.try_into_collection().unwrap();
+}
@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{ArrowField, MAP_ENTRIES_FIELD_NAME, MAP_KEYS_FIELD_NAME, MAP_VALUES_FIELD_NAME};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+fn map_data_type(key: DataType, value: DataType, value_nullable: bool, sorted: bool) -> DataType {
+    DataType::Map(
+        Arc::new(Field::new(
+            MAP_ENTRIES_FIELD_NAME,
+            DataType::Struct(Fields::from(vec![
+                Field::new(MAP_KEYS_FIELD_NAME, key, false),
+                Field::new(MAP_VALUES_FIELD_NAME, value, value_nullable),
+            ])),
+            false,
+        )),
+        sorted,
+    )
+}
+
+#[test]
+fn test_hash_map_schema() {
+    assert_eq!(
+        <HashMap<String, i64> as ArrowField>::data_type(),
+        map_data_type(DataType::Utf8, DataType::Int64, false, false)
+    );
+    assert_eq!(
+        <HashMap<i32, Option<String>> as ArrowField>::data_type(),
+        map_data_type(DataType::Int32, DataType::Utf8, true, false)
+    );
+}
+
+#[test]
+fn test_hash_map_round_trip() {
+    let original: Vec<HashMap<String, i64>> = vec![
+        HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]),
+        HashMap::new(),
+        HashMap::from([("c".to_string(), 3)]),
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<HashMap<String, i64> as ArrowField>::data_type()
+    );
+    let map_array = b.as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(map_array.value_length(0), 2);
+    assert_eq!(map_array.value_length(1), 0);
+
+    let round_trip: Vec<HashMap<String, i64>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_hash_map_nullable() {
+    let original: Vec<Option<HashMap<i32, Option<String>>>> = vec![
+        Some(HashMap::from([(1, Some("one".to_string())), (2, None)])),
+        None,
+        Some(HashMap::from([(3, None)])),
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<HashMap<i32, Option<String>>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_hash_map_nullable_keys() {
+    // arrow map keys can't be null, so maps with nullable keys are rejected rather than panicking on `finish`
+    let original: Vec<HashMap<Option<String>, i32>> = vec![HashMap::from([(Some("one".to_string()), 1), (None, 2)])];
+    let result: arrow::error::Result<ArrayRef> = original.try_into_arrow();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Invalid argument error: Row 0: Map keys can't be nullable, got keys of type \
+         `core::option::Option<alloc::string::String>`"
+    );
+
+    let original: Vec<BTreeMap<Option<i32>, i32>> = vec![BTreeMap::new(), BTreeMap::from([(Some(1), 1)])];
+    let result: arrow::error::Result<ArrayRef> = original.try_into_arrow();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Invalid argument error: Row 0: Map keys can't be nullable, got keys of type `core::option::Option<i32>`"
+    );
+}
+
+#[test]
+fn test_hash_map_nested() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Value {
+        a: i64,
+        b: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Root {
+        name: String,
+        attributes: HashMap<String, Value>,
+        lists: HashMap<u32, Vec<i32>>,
+        nested: Vec<HashMap<String, Option<f64>>>,
+    }
+
+    let original = vec![
+        Root {
+            name: "first".to_string(),
+            attributes: HashMap::from([
+                (
+                    "x".to_string(),
+                    Value {
+                        a: 1,
+                        b: vec!["p".to_string()],
+                    },
+                ),
+                ("y".to_string(), Value { a: 2, b: vec![] }),
+            ]),
+            lists: HashMap::from([(1, vec![1, 2, 3]), (2, vec![])]),
+            nested: vec![HashMap::from([("z".to_string(), Some(1.5)), ("w".to_string(), None)])],
+        },
+        Root {
+            name: "second".to_string(),
+            attributes: HashMap::new(),
+            lists: HashMap::new(),
+            nested: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Root> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_btree_map_schema() {
+    assert_eq!(
+        <BTreeMap<String, i64> as ArrowField>::data_type(),
+        map_data_type(DataType::Utf8, DataType::Int64, false, true)
+    );
+}
+
+#[test]
+fn test_btree_map_round_trip() {
+    let original: Vec<Option<BTreeMap<String, Option<i64>>>> = vec![
+        Some(BTreeMap::from([
+            ("b".to_string(), Some(2)),
+            ("a".to_string(), None),
+            ("c".to_string(), Some(3)),
+        ])),
+        None,
+        Some(BTreeMap::new()),
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<BTreeMap<String, Option<i64>> as ArrowField>::data_type()
+    );
+
+    // keys are written in sorted order
+    let map_array = b.as_any().downcast_ref::<MapArray>().unwrap();
+    let keys = map_array.keys().as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(keys.iter().flatten().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+
+    let round_trip: Vec<Option<BTreeMap<String, Option<i64>>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+#[should_panic(expected = "Expected sorted unique keys")]
+fn test_btree_map_unsorted_keys() {
+    let mut builder = MapBuilder::new(None, Int32Builder::new(), Int32Builder::new()).with_values_field(Field::new(
+        MAP_VALUES_FIELD_NAME,
+        DataType::Int32,
+        false,
+    ));
+    builder.keys().append_value(2);
+    builder.values().append_value(20);
+    builder.keys().append_value(1);
+    builder.values().append_value(10);
+    builder.append(true).unwrap();
+
+    let (field, offsets, entries, nulls, _) = builder.finish().into_parts();
+    let b: ArrayRef = Arc::new(MapArray::new(field, offsets, entries, nulls, true));
+    let _: Vec<BTreeMap<i32, i32>> = b.try_into_collection().unwrap();
+}
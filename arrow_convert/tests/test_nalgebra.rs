@@ -0,0 +1,111 @@
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_schema() {
+    use arrow::datatypes::{DataType, Field};
+    use arrow_convert::field::ArrowField;
+    use nalgebra::{SMatrix, SVector};
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        <SVector<f32, 3> as ArrowField>::data_type(),
+        DataType::FixedSizeList(Field::new("item", DataType::Float32, false).into(), 3)
+    );
+    assert_eq!(
+        <SMatrix<f64, 2, 3> as ArrowField>::data_type(),
+        DataType::FixedSizeList(Field::new("item", DataType::Float64, false).into(), 6)
+    );
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_roundtrip() {
+    use arrow::array::{Array, ArrayRef, FixedSizeListArray, Int32Array};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use nalgebra::{Matrix2x3, SVector, Vector3};
+    use pretty_assertions::assert_eq;
+
+    let original: Vec<Vector3<f64>> = vec![Vector3::new(1.0, 2.0, 3.0), Vector3::zeros()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Vector3<f64>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // matrices are stored in column-major order
+    let original: Vec<Matrix2x3<i32>> = vec![Matrix2x3::new(1, 2, 3, 4, 5, 6)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let list = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let values = list.values().as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(values.values()[..], [1, 4, 2, 5, 3, 6]);
+    let round_trip: Vec<Matrix2x3<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<Option<SVector<i64, 2>>> = vec![Some(SVector::from([1, 2])), None];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<SVector<i64, 2>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_in_struct() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use nalgebra::{Matrix3, Vector3};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Pose {
+        position: Vector3<f32>,
+        rotation: Matrix3<f32>,
+        waypoints: Vec<Vector3<f32>>,
+    }
+
+    let original = vec![
+        Pose {
+            position: Vector3::new(1.0, 2.0, 3.0),
+            rotation: Matrix3::identity(),
+            waypoints: vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)],
+        },
+        Pose {
+            position: Vector3::zeros(),
+            rotation: Matrix3::from_diagonal_element(2.0),
+            waypoints: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Pose> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_validate() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use nalgebra::SVector;
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    let original: Vec<SVector<Positive, 2>> = vec![SVector::from([Positive { v: 1 }, Positive { v: 0 }])];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<SVector<Positive, 2>>> = arrow_array.try_into_collection();
+    assert!(result.unwrap_err().to_string().ends_with("[1]: v: 0 is not positive"));
+}
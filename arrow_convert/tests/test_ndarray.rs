@@ -0,0 +1,151 @@
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_schema() {
+    use arrow::datatypes::{DataType, Field};
+    use arrow_convert::field::{ArrowField, FixedArray1, FixedArray2, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY};
+    use ndarray::Array1;
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        <Array1<f64> as ArrowField>::data_type(),
+        DataType::List(Field::new("item", DataType::Float64, false).into())
+    );
+    assert_eq!(
+        <FixedArray1<f32, 3> as ArrowField>::data_type(),
+        DataType::FixedSizeList(Field::new("item", DataType::Float32, false).into(), 3)
+    );
+    assert_eq!(
+        <FixedArray2<i32, 2, 3> as ArrowField>::data_type(),
+        DataType::FixedSizeList(Field::new("item", DataType::Int32, false).into(), 6)
+    );
+
+    let field = <FixedArray2<i32, 2, 3> as ArrowField>::field("matrix");
+    assert_eq!(
+        field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str),
+        Some("arrow.fixed_shape_tensor")
+    );
+    assert_eq!(
+        field.metadata().get(EXTENSION_METADATA_KEY).map(String::as_str),
+        Some(r#"{"shape":[2,3]}"#)
+    );
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_roundtrip() {
+    use arrow::array::{Array, ArrayRef, FixedSizeListArray, Int32Array};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{FixedArray1, FixedArray2};
+    use arrow_convert::serialize::TryIntoArrow;
+    use ndarray::{array, Array1, Array2};
+    use pretty_assertions::assert_eq;
+
+    let original: Vec<Array1<i64>> = vec![array![1, 2, 3], array![], array![4]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Array1<i64>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<Option<Array1<f32>>> = vec![Some(array![1.0, 2.0]), None, Some(array![3.0, 4.0])];
+    let b: ArrayRef = original
+        .try_into_arrow_as_type::<Option<FixedArray1<f32, 2>>>()
+        .unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Array1<f32>>> = b.try_into_collection_as_type::<Option<FixedArray1<f32, 2>>>().unwrap();
+    assert_eq!(round_trip, original);
+
+    // a transposed (column-major) matrix is serialized in logical row-major order
+    let original: Vec<Array2<i32>> = vec![
+        array![[1, 2, 3], [4, 5, 6]],
+        array![[1, 4], [2, 5], [3, 6]].reversed_axes(),
+    ];
+    let b: ArrayRef = original.try_into_arrow_as_type::<FixedArray2<i32, 2, 3>>().unwrap();
+    let list = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let values = list.values().as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(values.values()[..], [1, 2, 3, 4, 5, 6, 1, 2, 3, 4, 5, 6]);
+    let round_trip: Vec<Array2<i32>> = b.try_into_collection_as_type::<FixedArray2<i32, 2, 3>>().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_shape_mismatch() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::field::{FixedArray1, FixedArray2};
+    use arrow_convert::serialize::TryIntoArrow;
+    use ndarray::{array, Array1, Array2};
+
+    let original: Vec<Array1<i16>> = vec![array![1, 2, 3]];
+    let result: arrow::error::Result<ArrayRef> = original.try_into_arrow_as_type::<FixedArray1<i16, 2>>();
+    assert!(result.is_err());
+
+    let original: Vec<Array2<i16>> = vec![array![[1, 2], [3, 4]]];
+    let result: arrow::error::Result<ArrayRef> = original.try_into_arrow_as_type::<FixedArray2<i16, 1, 4>>();
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_in_struct() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{FixedArray1, FixedArray2};
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use ndarray::{array, Array1, Array2};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Sample {
+        readings: Array1<f64>,
+        #[arrow_field(type = "FixedArray1<f32, 3>")]
+        embedding: Array1<f32>,
+        #[arrow_field(type = "Option<FixedArray2<f64, 2, 2>>")]
+        covariance: Option<Array2<f64>>,
+    }
+
+    let original = vec![
+        Sample {
+            readings: array![1.0, 2.0],
+            embedding: array![0.1, 0.2, 0.3],
+            covariance: Some(array![[1.0, 0.5], [0.5, 1.0]]),
+        },
+        Sample {
+            readings: array![],
+            embedding: array![0.0, 0.0, 0.0],
+            covariance: None,
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Sample> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_validate() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use ndarray::Array1;
+
+    fn positive(v: &i64) -> Result<(), String> {
+        if *v > 0 {
+            Ok(())
+        } else {
+            Err(format!("{v} is not positive"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Positive {
+        #[arrow_field(validate = "positive")]
+        v: i64,
+    }
+
+    let original: Vec<Array1<Positive>> = vec![Array1::from_vec(vec![Positive { v: 1 }, Positive { v: 0 }])];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Array1<Positive>>> = arrow_array.try_into_collection();
+    assert!(result.unwrap_err().to_string().ends_with("[1]: v: 0 is not positive"));
+}
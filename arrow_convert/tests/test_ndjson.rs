@@ -0,0 +1,56 @@
+#[cfg(feature = "ndjson")]
+#[test]
+fn test_write_ndjson() {
+    use arrow_convert::serialize::{write_json_array, write_ndjson};
+    use arrow_convert::{ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+        #[arrow_field(name = "label")]
+        name: Option<String>,
+        tags: Vec<String>,
+        point: Point,
+    }
+
+    let rows = vec![
+        Row {
+            id: 1,
+            name: Some("a".to_string()),
+            tags: vec!["x".to_string()],
+            point: Point { x: 1, y: 2 },
+        },
+        Row {
+            id: 2,
+            name: None,
+            tags: vec![],
+            point: Point { x: 3, y: 4 },
+        },
+    ];
+
+    let mut buffer = vec![];
+    write_ndjson::<Row, _, _>(&mut buffer, &rows).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "{\"id\":1,\"label\":\"a\",\"tags\":[\"x\"],\"point\":{\"x\":1,\"y\":2}}\n\
+         {\"id\":2,\"tags\":[],\"point\":{\"x\":3,\"y\":4}}\n"
+    );
+
+    let mut buffer = vec![];
+    write_json_array::<Row, _, _>(&mut buffer, rows.iter().take(1)).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "[{\"id\":1,\"label\":\"a\",\"tags\":[\"x\"],\"point\":{\"x\":1,\"y\":2}}]"
+    );
+
+    let mut buffer = vec![];
+    write_json_array::<Row, _, _>(&mut buffer, Vec::<Row>::new()).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), "[]");
+}
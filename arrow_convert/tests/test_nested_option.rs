@@ -0,0 +1,68 @@
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{ArrowField, NestedOption, NESTED_OPTION_FIELD_NAME};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_nested_option_schema() {
+    assert_eq!(
+        <NestedOption<i32> as ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![Field::new(
+            NESTED_OPTION_FIELD_NAME,
+            DataType::Int32,
+            true
+        )]))
+    );
+    assert!(<NestedOption<i32> as ArrowField>::is_nullable());
+}
+
+#[test]
+fn test_nested_option_round_trip() {
+    let original: Vec<Option<Option<String>>> = vec![Some(Some("a".to_string())), Some(None), None];
+
+    let b: ArrayRef = original.try_into_arrow_as_type::<NestedOption<String>>().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.null_count(), 1);
+    assert_eq!(struct_array.column(0).null_count(), 2);
+
+    let round_trip: Vec<Option<Option<String>>> = b.try_into_collection_as_type::<NestedOption<String>>().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_nested_option_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Patch {
+        id: u32,
+        // `None` leaves the value unchanged, `Some(None)` clears it
+        #[arrow_field(type = "NestedOption<i64>")]
+        score: Option<Option<i64>>,
+        #[arrow_field(type = "Vec<NestedOption<bool>>")]
+        flags: Vec<Option<Option<bool>>>,
+    }
+
+    let original = vec![
+        Patch {
+            id: 1,
+            score: Some(Some(10)),
+            flags: vec![Some(Some(true)), Some(None), None],
+        },
+        Patch {
+            id: 2,
+            score: Some(None),
+            flags: vec![],
+        },
+        Patch {
+            id: 3,
+            score: None,
+            flags: vec![None],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Patch> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "ordered-float")]
+#[test]
+fn test_ordered_float_roundtrip() {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use arrow::array::{ArrayRef, Float32Array, Float64Array};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use ordered_float::{NotNan, OrderedFloat};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        price: NotNan<f64>,
+        weights: BTreeMap<OrderedFloat<f64>, String>,
+        levels: BTreeSet<NotNan<f32>>,
+        ratio: Option<OrderedFloat<f32>>,
+    }
+
+    let original = vec![OrderedFloat(1.5f32), OrderedFloat(f32::NAN), OrderedFloat(-0.0)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert!(b.as_any().is::<Float32Array>());
+    let round_trip: Vec<OrderedFloat<f32>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![Some(NotNan::new(2.0f64).unwrap()), None];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert!(b.as_any().is::<Float64Array>());
+    let round_trip: Vec<Option<NotNan<f64>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![S {
+        price: NotNan::new(9.99).unwrap(),
+        weights: BTreeMap::from([
+            (OrderedFloat(0.5), "half".to_string()),
+            (OrderedFloat(1.0), "one".to_string()),
+        ]),
+        levels: BTreeSet::from([NotNan::new(3.0).unwrap(), NotNan::new(1.0).unwrap()]),
+        ratio: None,
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "ordered-float")]
+#[test]
+fn test_not_nan_rejects_nan() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use ordered_float::NotNan;
+
+    let original = vec![1.0f64, f64::NAN];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<NotNan<f64>>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Expected a non-NaN value deserializing `NotNan<f64>`"));
+
+    // nulls and nested values are checked as well
+    let original = vec![None, Some(vec![f32::NAN])];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let result: arrow::error::Result<Vec<Option<Vec<NotNan<f32>>>>> = b.try_into_collection();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .ends_with("Expected a non-NaN value deserializing `NotNan<f32>`"));
+
+    let original = vec![None, Some(1.0f32)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Option<NotNan<f32>>> = b.try_into_collection().unwrap();
+    assert_eq!(roundtrip, vec![None, Some(NotNan::new(1.0).unwrap())]);
+}
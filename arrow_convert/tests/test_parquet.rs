@@ -0,0 +1,152 @@
+#[cfg(feature = "parquet")]
+#[test]
+fn test_write_parquet() {
+    use arrow::record_batch::RecordBatch;
+    use arrow_convert::deserialize::arrow_array_deserialize_iterator;
+    use arrow_convert::serialize::{write_parquet, write_parquet_file};
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::file::properties::WriterProperties;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+        values: Vec<f64>,
+    }
+
+    fn read(path: &std::path::Path) -> Vec<Row> {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<RecordBatch>, _>>().unwrap();
+        batches
+            .into_iter()
+            .flat_map(|batch| {
+                assert_eq!(batch.schema().fields(), Row::arrow_schema().fields());
+                let array = arrow::array::StructArray::from(batch);
+                arrow_array_deserialize_iterator::<Row>(&array)
+                    .unwrap()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    let rows = (0..10_000)
+        .map(|id| Row {
+            id,
+            name: (id % 3 != 0).then(|| format!("row {id}")),
+            values: vec![id as f64; (id % 4) as usize],
+        })
+        .collect::<Vec<_>>();
+
+    let path = std::env::temp_dir().join(format!(
+        "arrow_convert_test_write_parquet_{}.parquet",
+        std::process::id()
+    ));
+    let properties = WriterProperties::builder().set_max_row_group_size(4_000).build();
+    write_parquet_file::<Row, _>(&path, &rows, Some(properties)).unwrap();
+    assert_eq!(read(&path), rows);
+
+    write_parquet::<Row, _, _>(std::fs::File::create(&path).unwrap(), rows.iter().take(3), None).unwrap();
+    assert_eq!(read(&path), rows[..3]);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_hints() {
+    use arrow_convert::field::{ArrowField, ParquetHint};
+    use arrow_convert::serialize::{with_parquet_hints, write_parquet};
+    use arrow_convert::{ArrowField, ArrowSerialize};
+    use parquet::basic::{Compression, Encoding, ZstdLevel};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::schema::types::ColumnPath;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Location {
+        #[arrow_field(parquet(encoding = "byte_stream_split"))]
+        lat: f64,
+        lon: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Event {
+        #[arrow_field(parquet(encoding = "delta_binary_packed", dictionary = false))]
+        id: i64,
+        #[arrow_field(parquet(compression = "zstd", bloom_filter = true))]
+        tags: Vec<String>,
+        #[arrow_field(parquet(dictionary = false))]
+        location: Option<Location>,
+    }
+
+    assert_eq!(
+        Event::parquet_hints(),
+        vec![
+            ParquetHint {
+                path: vec!["id".to_string()],
+                encoding: Some("delta_binary_packed"),
+                dictionary: Some(false),
+                ..Default::default()
+            },
+            ParquetHint {
+                path: vec!["tags".to_string()],
+                compression: Some("zstd"),
+                bloom_filter: Some(true),
+                ..Default::default()
+            },
+            ParquetHint {
+                path: vec!["location".to_string()],
+                dictionary: Some(false),
+                ..Default::default()
+            },
+            ParquetHint {
+                path: vec!["location".to_string(), "lat".to_string()],
+                encoding: Some("byte_stream_split"),
+                ..Default::default()
+            },
+        ]
+    );
+
+    // the hints of a field apply to all its columns
+    let properties = with_parquet_hints::<Event>(WriterProperties::builder())
+        .unwrap()
+        .build();
+    let tags = ColumnPath::new(vec!["tags".to_string(), "list".to_string(), "item".to_string()]);
+    assert_eq!(
+        properties.compression(&tags),
+        Compression::ZSTD(ZstdLevel::default())
+    );
+    assert!(properties.bloom_filter_properties(&tags).is_some());
+    let lat = ColumnPath::new(vec!["location".to_string(), "lat".to_string()]);
+    let lon = ColumnPath::new(vec!["location".to_string(), "lon".to_string()]);
+    assert_eq!(properties.encoding(&lat), Some(Encoding::BYTE_STREAM_SPLIT));
+    assert_eq!(properties.encoding(&lon), None);
+    assert!(!properties.dictionary_enabled(&lat));
+    assert!(!properties.dictionary_enabled(&lon));
+    assert!(properties.dictionary_enabled(&ColumnPath::from("other")));
+
+    // the hints are used when writing without properties
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        #[arrow_field(parquet(encoding = "delta_binary_packed", dictionary = false))]
+        id: i64,
+        value: i64,
+    }
+
+    let rows = (0..100).map(|id| Row { id, value: id }).collect::<Vec<_>>();
+    let path = std::env::temp_dir().join(format!(
+        "arrow_convert_test_parquet_hints_{}.parquet",
+        std::process::id()
+    ));
+    write_parquet::<Row, _, _>(std::fs::File::create(&path).unwrap(), &rows, None).unwrap();
+    let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+    let row_group = reader.metadata().row_group(0);
+    assert!(row_group.column(0).encodings().contains(&Encoding::DELTA_BINARY_PACKED));
+    assert!(!row_group.column(1).encodings().contains(&Encoding::DELTA_BINARY_PACKED));
+    std::fs::remove_file(&path).unwrap();
+}
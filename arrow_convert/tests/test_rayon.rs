@@ -0,0 +1,144 @@
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_try_into_arrow() {
+    use arrow::array::ArrayRef;
+    use arrow::record_batch::RecordBatch;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::LargeString;
+    use arrow_convert::serialize::{ParTryIntoArrow, TryIntoArrow};
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+        values: Vec<f64>,
+    }
+
+    // enough rows to be split into several chunks
+    let rows = (0..10_000)
+        .map(|id| Row {
+            id,
+            name: (id % 3 != 0).then(|| id.to_string()),
+            values: vec![id as f64; (id % 4) as usize],
+        })
+        .collect::<Vec<_>>();
+    // the chunks are serialized by several threads even on machines with a single core
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    let array: ArrayRef = pool.install(|| rows.par_try_into_arrow()).unwrap();
+    let expected: ArrayRef = rows.try_into_arrow().unwrap();
+    assert_eq!(&array, &expected);
+    let round_trip: Vec<Row> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+
+    let batch: RecordBatch = rows.par_try_into_arrow().unwrap();
+    assert_eq!(batch.num_rows(), rows.len());
+
+    let strings = (0..5_000).map(|i| i.to_string()).collect::<Vec<_>>();
+    let array: ArrayRef = pool
+        .install(|| strings.par_try_into_arrow_as_type::<LargeString>())
+        .unwrap();
+    let expected: ArrayRef = strings.try_into_arrow_as_type::<LargeString>().unwrap();
+    assert_eq!(&array, &expected);
+
+    let empty: ArrayRef = rows[..0].par_try_into_arrow().unwrap();
+    assert_eq!(empty.len(), 0);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_try_into_arrow_encoded_columns() {
+    use arrow::array::{Array, ArrayRef};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::RunEnd;
+    use arrow_convert::serialize::ParTryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(mode = "dense")]
+    enum Value {
+        Int(i64),
+        Text(String),
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Encoded {
+        #[arrow_field(dictionary)]
+        category: String,
+        value: Value,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct RunEncoded {
+        #[arrow_field(run_end_encoded)]
+        region: String,
+        #[arrow_field(type = "RunEnd<i32, i64>")]
+        bucket: i64,
+        encoded: Encoded,
+    }
+
+    let rows = (0..20_000)
+        .map(|id: i64| RunEncoded {
+            region: format!("region {}", id / 1000),
+            bucket: id / 100,
+            encoded: Encoded {
+                category: format!("category {}", id % 7),
+                value: if id % 2 == 0 {
+                    Value::Int(id)
+                } else {
+                    Value::Text(id.to_string())
+                },
+            },
+        })
+        .collect::<Vec<_>>();
+    // several chunks are serialized by several threads
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    // run-end encoded columns are serialized in a single pass
+    let array: ArrayRef = pool.install(|| rows.par_try_into_arrow()).unwrap();
+    assert_eq!(array.len(), rows.len());
+    let round_trip: Vec<RunEncoded> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+
+    // dictionary and union columns are concatenated
+    let encoded = rows.iter().map(|r| r.encoded.clone()).collect::<Vec<_>>();
+    let array: ArrayRef = pool.install(|| encoded.par_try_into_arrow()).unwrap();
+    let round_trip: Vec<Encoded> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, encoded);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_try_into_arrow_list_view() {
+    use arrow::array::{Array, ArrayRef};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::{LargeListView, ListView};
+    use arrow_convert::serialize::ParTryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Nested {
+        #[arrow_field(type = "LargeListView<i32>")]
+        values: Vec<i32>,
+    }
+
+    let rows = (0..20_000).map(|id| vec![id; id as usize % 3]).collect::<Vec<_>>();
+    // several chunks are serialized by several threads
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    // list view columns are serialized in a single pass
+    let array: ArrayRef = pool
+        .install(|| rows.par_try_into_arrow_as_type::<ListView<i32>>())
+        .unwrap();
+    assert_eq!(array.len(), rows.len());
+    let round_trip: Vec<Vec<i32>> = array.try_into_collection_as_type::<ListView<i32>>().unwrap();
+    assert_eq!(round_trip, rows);
+
+    let nested = rows.into_iter().map(|values| Nested { values }).collect::<Vec<_>>();
+    let array: ArrayRef = pool.install(|| nested.par_try_into_arrow()).unwrap();
+    let round_trip: Vec<Nested> = array.try_into_collection().unwrap();
+    assert_eq!(round_trip, nested);
+}
@@ -3,13 +3,17 @@ use arrow::datatypes::*;
 use arrow_convert::deserialize::arrow_array_deserialize_iterator_as_type;
 use arrow_convert::deserialize::*;
 use arrow_convert::field::DEFAULT_FIELD_NAME;
-use arrow_convert::field::{LargeBinary, I128};
+use arrow_convert::field::{
+    BinaryView, DurationMicroseconds, DurationMilliseconds, DurationNanoseconds, DurationSeconds, LargeBinary,
+    OsStringLossy, OsStringUtf8, Utf8View, I128,
+};
 use arrow_convert::serialize::*;
 use arrow_convert::{
-    field::{FixedSizeBinary, FixedSizeVec, LargeString, LargeVec},
+    field::{FixedSizeBinary, FixedSizeVec, LargeListView, LargeString, LargeVec, ListView},
     ArrowDeserialize, ArrowField, ArrowSerialize,
 };
 use half::f16;
+use std::collections::VecDeque;
 use std::f32::INFINITY;
 use std::sync::Arc;
 
@@ -71,6 +75,43 @@ fn test_large_string_nested() {
     assert_eq!(round_trip, strs);
 }
 
+#[test]
+fn test_utf8_view() {
+    let strs = vec![
+        "short".to_string(),
+        "a string that is longer than twelve bytes".to_string(),
+    ];
+    let b: ArrayRef = strs.try_into_arrow_as_type::<Utf8View>().unwrap();
+    assert_eq!(b.data_type(), &DataType::Utf8View);
+    let round_trip: Vec<String> = b.try_into_collection_as_type::<Utf8View>().unwrap();
+    assert_eq!(round_trip, strs);
+
+    let strs = [Some("1".to_string()), None];
+    let b: ArrayRef = strs.try_into_arrow_as_type::<Option<Utf8View>>().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<String>> = b.try_into_collection_as_type::<Option<Utf8View>>().unwrap();
+    assert_eq!(round_trip, strs);
+}
+
+#[test]
+fn test_utf8_view_nested() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        #[arrow_field(type = "Utf8View")]
+        name: String,
+        #[arrow_field(type = "Vec<Utf8View>")]
+        tags: Vec<String>,
+    }
+
+    let original = [S {
+        name: "view".to_string(),
+        tags: vec!["a".to_string(), "b".to_string()],
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
 #[test]
 fn test_large_binary() {
     let strs = [b"abc".to_vec()];
@@ -80,6 +121,44 @@ fn test_large_binary() {
     assert_eq!(round_trip, strs);
 }
 
+#[test]
+fn test_large_buffer() {
+    use arrow::buffer::Buffer;
+    use arrow_convert::field::LargeBuffer;
+
+    let original = vec![
+        Some(Buffer::from(b"abc".as_slice())),
+        None,
+        Some(Buffer::from(b"".as_slice())),
+        Some(Buffer::from(b"de".as_slice())),
+    ];
+    let b: ArrayRef = original.try_into_arrow_as_type::<Option<LargeBuffer>>().unwrap();
+    assert_eq!(b.data_type(), &DataType::LargeBinary);
+    let round_trip: Vec<Option<Buffer>> = b.clone().try_into_collection_as_type::<Option<LargeBuffer>>().unwrap();
+    assert_eq!(round_trip, original);
+
+    // the deserialized buffers point into the values buffer of the array
+    let values = b.as_any().downcast_ref::<LargeBinaryArray>().unwrap().values();
+    let last = round_trip[3].as_ref().unwrap();
+    assert_eq!(last.as_ptr(), values[3..].as_ptr());
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Blob {
+        #[arrow_field(type = "LargeBuffer")]
+        payload: Buffer,
+        #[arrow_field(type = "Vec<LargeBuffer>")]
+        chunks: Vec<Buffer>,
+    }
+
+    let original = vec![Blob {
+        payload: Buffer::from(b"payload".as_slice()),
+        chunks: vec![Buffer::from(b"a".as_slice()), Buffer::from(b"bc".as_slice())],
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Blob> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
 #[test]
 fn test_large_binary_nested() {
     let strs = [vec![b"abc".to_vec(), b"abd".to_vec()]];
@@ -96,6 +175,28 @@ fn test_large_binary_nested() {
     assert_eq!(round_trip, strs);
 }
 
+#[test]
+fn test_binary_view() {
+    let bins = [b"abc".to_vec(), b"a binary value longer than twelve bytes".to_vec()];
+    let b: ArrayRef = bins.try_into_arrow_as_type::<BinaryView>().unwrap();
+    assert_eq!(b.data_type(), &DataType::BinaryView);
+    let round_trip: Vec<Vec<u8>> = b.try_into_collection_as_type::<BinaryView>().unwrap();
+    assert_eq!(round_trip, bins);
+
+    let bins = [vec![Some(b"abc".to_vec()), None]];
+    let b: ArrayRef = bins.try_into_arrow_as_type::<Vec<Option<BinaryView>>>().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::List(Arc::new(Field::new(
+            DEFAULT_FIELD_NAME,
+            DataType::BinaryView,
+            true
+        )))
+    );
+    let round_trip: Vec<Vec<Option<Vec<u8>>>> = b.try_into_collection_as_type::<Vec<Option<BinaryView>>>().unwrap();
+    assert_eq!(round_trip, bins);
+}
+
 #[test]
 fn test_fixed_size_binary() {
     let strs = [b"abc".to_vec()];
@@ -133,6 +234,51 @@ fn test_large_vec_nested() {
     assert_eq!(round_trip, strs);
 }
 
+#[test]
+fn test_list_view() {
+    let ints = vec![Some(vec![1, 2, 3]), None, Some(vec![])];
+    let b: ArrayRef = ints.try_into_arrow_as_type::<Option<ListView<i32>>>().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::ListView(Arc::new(Field::new(DEFAULT_FIELD_NAME, DataType::Int32, false)))
+    );
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Vec<i32>>> = b.try_into_collection_as_type::<Option<ListView<i32>>>().unwrap();
+    assert_eq!(round_trip, ints);
+}
+
+#[test]
+fn test_large_list_view_nested() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        #[arrow_field(type = "LargeListView<ListView<String>>")]
+        groups: Vec<Vec<String>>,
+    }
+
+    let original = [
+        S {
+            groups: vec![vec!["a".to_string()], vec![], vec!["b".to_string(), "c".to_string()]],
+        },
+        S { groups: vec![] },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // list views produced by other writers may have out of order and overlapping views
+    let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+    let field = Arc::new(Field::new(DEFAULT_FIELD_NAME, DataType::Int32, false));
+    let b: ArrayRef = Arc::new(LargeListViewArray::new(
+        field,
+        vec![2i64, 0, 1].into(),
+        vec![2i64, 3, 1].into(),
+        values,
+        None,
+    ));
+    let round_trip: Vec<Vec<i32>> = b.try_into_collection_as_type::<LargeListView<i32>>().unwrap();
+    assert_eq!(round_trip, vec![vec![3, 4], vec![1, 2, 3], vec![2]]);
+}
+
 #[test]
 fn test_fixed_size_vec() {
     let ints = vec![vec![1, 2, 3]];
@@ -148,6 +294,248 @@ fn test_fixed_size_vec() {
     assert_eq!(round_trip, ints);
 }
 
+#[test]
+fn test_fixed_size_array() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Record {
+        hash: [u8; 4],
+        embedding: [f32; 3],
+        patches: [[i32; 2]; 2],
+        hashes: Vec<[u8; 2]>,
+        labels: Option<[String; 2]>,
+    }
+
+    let original = vec![
+        Record {
+            hash: [0xde, 0xad, 0xbe, 0xef],
+            embedding: [0.1, 0.2, 0.3],
+            patches: [[1, 2], [3, 4]],
+            hashes: vec![[1, 2], [3, 4]],
+            labels: Some(["a".to_string(), "b".to_string()]),
+        },
+        Record {
+            hash: [0; 4],
+            embedding: [0.0; 3],
+            patches: [[0; 2]; 2],
+            hashes: vec![],
+            labels: None,
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Record> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original: Vec<Vec<[i64; 2]>> = vec![vec![[1, 2], [3, 4]], vec![]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Vec<[i64; 2]>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+#[should_panic(expected = "Expected size of 3 deserializing array of type `i32`, got 2")]
+fn test_fixed_size_array_length_mismatch() {
+    let values: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    let _ = <[i32; 3] as ArrowDeserialize>::arrow_deserialize(Some(values));
+}
+
+#[test]
+fn test_vec_deque() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Window {
+        samples: VecDeque<f64>,
+        labels: Option<VecDeque<Option<String>>>,
+    }
+
+    let mut samples = VecDeque::with_capacity(3);
+    samples.push_back(2.0);
+    samples.push_back(3.0);
+    samples.push_front(1.0);
+
+    let original = vec![
+        Window {
+            samples,
+            labels: Some(VecDeque::from(vec![Some("a".to_string()), None])),
+        },
+        Window {
+            samples: VecDeque::new(),
+            labels: None,
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Window> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    assert_eq!(
+        <VecDeque<i32> as arrow_convert::field::ArrowField>::data_type(),
+        <Vec<i32> as arrow_convert::field::ArrowField>::data_type()
+    );
+    let ints = vec![VecDeque::from(vec![1, 2, 3])];
+    let b: ArrayRef = ints.try_into_arrow().unwrap();
+    let round_trip: Vec<Vec<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![vec![1, 2, 3]]);
+}
+
+#[test]
+fn test_unit() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Placeholder {
+        id: i32,
+        reserved: (),
+        markers: Vec<()>,
+    }
+
+    assert_eq!(
+        <() as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Null
+    );
+    assert!(<() as arrow_convert::field::ArrowField>::is_nullable());
+
+    let original = vec![(), (), ()];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Null);
+    assert_eq!(b.len(), 3);
+    let round_trip: Vec<()> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        Placeholder {
+            id: 1,
+            reserved: (),
+            markers: vec![(), ()],
+        },
+        Placeholder {
+            id: 2,
+            reserved: (),
+            markers: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Placeholder> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_phantom_data() {
+    use std::marker::PhantomData;
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Meters {
+        value: f64,
+        unit: PhantomData<fn() -> u8>,
+    }
+
+    assert_eq!(
+        <PhantomData<str> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Null
+    );
+
+    let original = vec![
+        Meters {
+            value: 1.5,
+            unit: PhantomData,
+        },
+        Meters {
+            value: 2.5,
+            unit: PhantomData,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.column(1).data_type(), &DataType::Null);
+    let round_trip: Vec<Meters> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_numeric_wrappers() {
+    use std::num::{Saturating, Wrapping};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Counters {
+        hash: Wrapping<u64>,
+        budget: Saturating<i32>,
+        deltas: Vec<Wrapping<i16>>,
+        level: Option<Saturating<u8>>,
+    }
+
+    assert_eq!(
+        <Wrapping<u32> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::UInt32
+    );
+    assert_eq!(
+        <Saturating<i64> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Int64
+    );
+
+    let original = vec![Wrapping(u8::MAX) + Wrapping(2), Wrapping(7)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let values = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+    assert_eq!(values.values()[..], [1, 7]);
+    let round_trip: Vec<Wrapping<u8>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        Counters {
+            hash: Wrapping(u64::MAX) * Wrapping(3),
+            budget: Saturating(i32::MAX) + Saturating(1),
+            deltas: vec![Wrapping(-1), Wrapping(i16::MIN)],
+            level: Some(Saturating(3)),
+        },
+        Counters {
+            hash: Wrapping(0),
+            budget: Saturating(-5),
+            deltas: vec![],
+            level: None,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Counters> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_f16_lists() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Embedding {
+        values: Vec<f16>,
+        fixed: [f16; 3],
+        #[arrow_field(type = "FixedSizeVec<f16, 2>")]
+        fixed_vec: Vec<f16>,
+        #[arrow_field(type = "LargeVec<Option<f16>>")]
+        sparse: Vec<Option<f16>>,
+    }
+
+    assert_eq!(
+        <Vec<f16> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::List(Arc::new(Field::new(
+            DEFAULT_FIELD_NAME,
+            DataType::Float16,
+            false
+        )))
+    );
+
+    let h = f16::from_f32;
+    let original = vec![
+        Embedding {
+            values: vec![h(0.5), h(-1.25)],
+            fixed: [h(1.0), h(2.0), h(3.0)],
+            fixed_vec: vec![h(0.0), h(-0.0)],
+            sparse: vec![Some(h(4.0)), None],
+        },
+        Embedding {
+            values: vec![],
+            fixed: [f16::MAX, f16::MIN, f16::EPSILON],
+            fixed_vec: vec![f16::INFINITY, f16::NEG_INFINITY],
+            sparse: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Embedding> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
 #[test]
 fn test_primitive_type_vec() {
     macro_rules! test_int_type {
@@ -286,3 +674,333 @@ fn test_escaped_name() {
     let round_trip: Vec<EscapedName> = b.try_into_collection().unwrap();
     assert_eq!(array.as_slice(), round_trip.as_slice());
 }
+
+#[test]
+fn test_result() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Row {
+        id: i32,
+        outcome: Result<f64, String>,
+        attempts: Vec<Result<u8, String>>,
+        maybe: Option<Result<i64, bool>>,
+    }
+
+    assert_eq!(
+        <Result<i32, String> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Union(
+            UnionFields::new(
+                0..2,
+                vec![
+                    Field::new("ok", DataType::Int32, false),
+                    Field::new("err", DataType::Utf8, false),
+                ]
+            ),
+            UnionMode::Dense
+        )
+    );
+
+    let original: Vec<Result<i32, String>> = vec![Ok(1), Err("failed".to_string()), Ok(3)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.child(0).len(), 2);
+    assert_eq!(union.child(1).len(), 1);
+    let round_trip: Vec<Result<i32, String>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        Row {
+            id: 1,
+            outcome: Ok(1.5),
+            attempts: vec![Err("timeout".to_string()), Ok(2)],
+            maybe: None,
+        },
+        Row {
+            id: 2,
+            outcome: Err("invalid".to_string()),
+            attempts: vec![],
+            maybe: Some(Err(true)),
+        },
+        Row {
+            id: 3,
+            outcome: Ok(0.0),
+            attempts: vec![Ok(1)],
+            maybe: Some(Ok(7)),
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_durations() {
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Timings {
+        #[arrow_field(type = "DurationSeconds")]
+        timeout: Duration,
+        #[arrow_field(type = "DurationMilliseconds")]
+        elapsed: Duration,
+        #[arrow_field(type = "Option<DurationMicroseconds<i64>>")]
+        offset: Option<i64>,
+        #[arrow_field(type = "Vec<DurationNanoseconds>")]
+        laps: Vec<Duration>,
+    }
+
+    assert_eq!(
+        <Timings as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("timeout", DataType::Duration(TimeUnit::Second), false),
+            Field::new("elapsed", DataType::Duration(TimeUnit::Millisecond), false),
+            Field::new("offset", DataType::Duration(TimeUnit::Microsecond), true),
+            Field::new(
+                "laps",
+                DataType::List(Arc::new(Field::new(
+                    DEFAULT_FIELD_NAME,
+                    DataType::Duration(TimeUnit::Nanosecond),
+                    false
+                ))),
+                false
+            ),
+        ]))
+    );
+
+    let original = vec![
+        Timings {
+            timeout: Duration::from_secs(30),
+            elapsed: Duration::from_millis(1500),
+            offset: Some(-250),
+            laps: vec![Duration::from_nanos(1), Duration::from_micros(7)],
+        },
+        Timings {
+            timeout: Duration::from_secs(0),
+            elapsed: Duration::from_millis(0),
+            offset: None,
+            laps: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Timings> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    // sub-unit precision is truncated
+    let b: ArrayRef = [Duration::from_millis(2500)]
+        .try_into_arrow_as_type::<DurationSeconds>()
+        .unwrap();
+    let round_trip: Vec<Duration> = b.try_into_collection_as_type::<DurationSeconds>().unwrap();
+    assert_eq!(round_trip, vec![Duration::from_secs(2)]);
+
+    // overflowing an i64 returns an error
+    let result: arrow::error::Result<ArrayRef> = [Duration::MAX].try_into_arrow_as_type::<DurationNanoseconds>();
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Expected a duration representable as `core::time::Duration`, got -1")]
+fn test_negative_duration() {
+    let b: ArrayRef = Arc::new(DurationSecondArray::from(vec![-1]));
+    let _: Vec<std::time::Duration> = b.try_into_collection_as_type::<DurationSeconds>().unwrap();
+}
+
+#[test]
+fn test_timestamps() {
+    use arrow_convert::field::{TimestampMicroseconds, TimestampNanoseconds, UtcTimeZone};
+    use chrono::{DateTime, NaiveDateTime, Utc};
+
+    let times = [DateTime::<Utc>::from_timestamp(1_000, 1_500).unwrap()];
+    let b: ArrayRef = times
+        .try_into_arrow_as_type::<TimestampMicroseconds<DateTime<Utc>, UtcTimeZone>>()
+        .unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+    );
+    // sub-unit precision is truncated
+    let round_trip: Vec<DateTime<Utc>> = b
+        .try_into_collection_as_type::<TimestampMicroseconds<DateTime<Utc>, UtcTimeZone>>()
+        .unwrap();
+    assert_eq!(
+        round_trip,
+        vec![DateTime::<Utc>::from_timestamp(1_000, 1_000).unwrap()]
+    );
+
+    // the default value type is `NaiveDateTime` without timezone
+    let b: ArrayRef = [NaiveDateTime::default()]
+        .try_into_arrow_as_type::<TimestampNanoseconds>()
+        .unwrap();
+    assert_eq!(b.data_type(), &DataType::Timestamp(TimeUnit::Nanosecond, None));
+
+    // overflowing an i64 returns an error
+    let result: arrow::error::Result<ArrayRef> = [NaiveDateTime::MAX].try_into_arrow_as_type::<TimestampNanoseconds>();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_naive_date_time_overflow() {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Event {
+        id: i32,
+        at: NaiveDateTime,
+    }
+
+    let valid = NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let overflowing = NaiveDate::from_ymd_opt(3000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    let result: arrow::error::Result<ArrayRef> = [valid, overflowing].try_into_arrow();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Invalid argument error: Row 1: Value of `chrono::naive::datetime::NaiveDateTime` overflows an Arrow \
+         `Timestamp(Nanosecond)`"
+    );
+
+    // the non-nullable field of a struct returns the error instead of panicking on a null
+    let events = vec![Event { id: 1, at: valid }, Event { id: 2, at: overflowing }];
+    let result: arrow::error::Result<ArrayRef> = events.try_into_arrow();
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .starts_with("Invalid argument error: Row 1: "));
+
+    let b: ArrayRef = events[..1].try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, events[..1]);
+}
+
+#[test]
+fn test_os_string_and_c_string() {
+    use std::ffi::{CString, OsString};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct FfiRecord {
+        path: OsString,
+        #[arrow_field(type = "OsStringUtf8")]
+        name: OsString,
+        #[arrow_field(type = "Option<OsStringLossy>")]
+        label: Option<OsString>,
+        symbol: CString,
+        args: Vec<CString>,
+    }
+
+    assert_eq!(
+        <FfiRecord as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("path", DataType::Binary, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("label", DataType::Utf8, true),
+            Field::new("symbol", DataType::Binary, false),
+            Field::new(
+                "args",
+                DataType::List(Arc::new(Field::new(
+                    DEFAULT_FIELD_NAME,
+                    DataType::Binary,
+                    false
+                ))),
+                false
+            ),
+        ]))
+    );
+
+    let original = vec![
+        FfiRecord {
+            path: OsString::from("/tmp/file"),
+            name: OsString::from("file"),
+            label: Some(OsString::from("temporary")),
+            symbol: CString::new("main").unwrap(),
+            args: vec![CString::new("-v").unwrap(), CString::new("").unwrap()],
+        },
+        FfiRecord {
+            path: OsString::new(),
+            name: OsString::new(),
+            label: None,
+            symbol: CString::default(),
+            args: vec![],
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    // the trailing nul byte is not stored
+    assert_eq!(arr.column(3).as_binary::<i32>().value(0), b"main");
+    let round_trip: Vec<FfiRecord> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_os_string_non_unicode() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let original = vec![OsString::from_vec(vec![b'a', 0xff, b'b'])];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<OsString> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let result: arrow::error::Result<ArrayRef> = original.try_into_arrow_as_type::<OsStringUtf8>();
+    assert!(result.is_err());
+
+    let b: ArrayRef = original.try_into_arrow_as_type::<OsStringLossy>().unwrap();
+    let round_trip: Vec<OsString> = b.try_into_collection_as_type::<OsStringLossy>().unwrap();
+    assert_eq!(round_trip, vec![OsString::from("a\u{FFFD}b")]);
+}
+
+#[test]
+#[should_panic(expected = "Expected no interior nul byte deserializing `std::ffi::CString`")]
+fn test_c_string_interior_nul() {
+    let b: ArrayRef = Arc::new(BinaryArray::from(vec![b"a\0b".as_ref()]));
+    let _: Vec<std::ffi::CString> = b.try_into_collection().unwrap();
+}
+
+#[test]
+fn test_range() {
+    use std::ops::{Range, RangeInclusive};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Window {
+        span: Range<i64>,
+        bounds: RangeInclusive<f64>,
+        gaps: Vec<Range<u32>>,
+        optional: Option<Range<String>>,
+    }
+
+    assert_eq!(
+        <Range<i64> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("start", DataType::Int64, false),
+            Field::new("end", DataType::Int64, false),
+        ]))
+    );
+    assert_eq!(
+        <RangeInclusive<f64> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("start", DataType::Float64, false),
+            Field::new("end", DataType::Float64, false),
+        ]))
+    );
+
+    let original = vec![
+        Window {
+            span: 0..10,
+            bounds: 0.5..=1.5,
+            gaps: vec![1..2, 5..7],
+            optional: Some("a".to_string().."z".to_string()),
+        },
+        Window {
+            span: -5..-5,
+            bounds: 2.0..=2.0,
+            gaps: vec![],
+            optional: None,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Window> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
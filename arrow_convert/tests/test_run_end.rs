@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{ArrowField, RunEnd, RUN_ENDS_FIELD_NAME, RUN_VALUES_FIELD_NAME};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_run_end_schema() {
+    assert_eq!(
+        <RunEnd<i32, String> as ArrowField>::data_type(),
+        DataType::RunEndEncoded(
+            Arc::new(Field::new(RUN_ENDS_FIELD_NAME, DataType::Int32, false)),
+            Arc::new(Field::new(RUN_VALUES_FIELD_NAME, DataType::Utf8, true)),
+        )
+    );
+}
+
+#[test]
+fn test_run_end_round_trip() {
+    let original: Vec<String> = ["ok", "ok", "ok", "failed", "ok", "ok"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let b: ArrayRef = original.try_into_arrow_as_type::<RunEnd<i32, String>>().unwrap();
+    let run_array = b.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+    assert_eq!(run_array.len(), 6);
+    assert_eq!(run_array.run_ends().values(), &[3, 4, 6]);
+    assert_eq!(run_array.values().len(), 3);
+
+    let round_trip: Vec<String> = b.try_into_collection_as_type::<RunEnd<i32, String>>().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![Some(1u8), Some(1), None, None, Some(2)];
+    let b: ArrayRef = original.try_into_arrow_as_type::<Option<RunEnd<i16, u8>>>().unwrap();
+    let round_trip: Vec<Option<u8>> = b.try_into_collection_as_type::<Option<RunEnd<i16, u8>>>().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<half::f16> = [0.5, 0.5, 1.5].into_iter().map(half::f16::from_f32).collect();
+    let b: ArrayRef = original.try_into_arrow_as_type::<RunEnd<i32, half::f16>>().unwrap();
+    let round_trip: Vec<half::f16> = b.try_into_collection_as_type::<RunEnd<i32, half::f16>>().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_run_end_sliced() {
+    let original: Vec<i64> = vec![1, 1, 2, 2, 2, 3];
+    let b: ArrayRef = original.try_into_arrow_as_type::<RunEnd<i64, i64>>().unwrap();
+    let sliced = b.slice(1, 4);
+    let round_trip: Vec<i64> = sliced.try_into_collection_as_type::<RunEnd<i64, i64>>().unwrap();
+    assert_eq!(round_trip, vec![1, 2, 2, 2]);
+}
+
+#[test]
+fn test_run_end_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Reading {
+        #[arrow_field(type = "RunEnd<i32, String>")]
+        sensor: String,
+        #[arrow_field(type = "Option<RunEnd<i32, f64>>")]
+        calibration: Option<f64>,
+        value: f64,
+    }
+
+    let original: Vec<Reading> = (0..4)
+        .map(|i| Reading {
+            sensor: if i < 3 { "a" } else { "b" }.to_string(),
+            calibration: if i % 2 == 0 { Some(1.5) } else { None },
+            value: i as f64,
+        })
+        .collect();
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Reading> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_run_end_encoded_attribute() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Reading {
+        #[arrow_field(run_end_encoded)]
+        sensor: String,
+        #[arrow_field(run_end_encoded = "i16")]
+        calibration: Option<f64>,
+        #[arrow_field(run_end_encoded = "i64", large)]
+        site: String,
+        value: f64,
+    }
+
+    assert_eq!(
+        <Reading as ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new(
+                "sensor",
+                <RunEnd<i32, String> as ArrowField>::data_type(),
+                false
+            ),
+            Field::new(
+                "calibration",
+                <RunEnd<i16, f64> as ArrowField>::data_type(),
+                true
+            ),
+            Field::new(
+                "site",
+                <RunEnd<i64, arrow_convert::field::LargeString> as ArrowField>::data_type(),
+                false
+            ),
+            Field::new("value", DataType::Float64, false),
+        ]))
+    );
+
+    let original: Vec<Reading> = (0..4)
+        .map(|i| Reading {
+            sensor: if i < 3 { "a" } else { "b" }.to_string(),
+            calibration: if i < 2 { Some(1.5) } else { None },
+            site: "x".to_string(),
+            value: i as f64,
+        })
+        .collect();
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let columns = b.as_struct().columns();
+    let sensor = columns[0].as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+    assert_eq!(sensor.run_ends().values(), &[3, 4]);
+    let site = columns[2].as_any().downcast_ref::<RunArray<Int64Type>>().unwrap();
+    assert_eq!(site.run_ends().values(), &[4]);
+    let round_trip: Vec<Reading> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
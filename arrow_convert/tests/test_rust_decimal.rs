@@ -66,3 +66,68 @@ fn test_decimal_edge_values() {
         .expect("Failed to convert from Arrow array");
     assert_eq!(original, roundtrip);
 }
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn test_decimal_attribute() {
+    use arrow::array::{ArrayRef, AsArray};
+    use arrow::datatypes::{DataType, Decimal128Type};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::*;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use rust_decimal::Decimal;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Price {
+        #[arrow_field(decimal(precision = 12, scale = 3))]
+        amount: Decimal,
+        #[arrow_field(decimal(precision = 5, scale = 0))]
+        quantity: Option<rust_decimal::Decimal>,
+    }
+
+    assert_eq!(
+        <Price as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(
+            vec![
+                arrow::datatypes::Field::new("amount", DataType::Decimal128(12, 3), false),
+                arrow::datatypes::Field::new("quantity", DataType::Decimal128(5, 0), true),
+            ]
+            .into()
+        )
+    );
+
+    let original = vec![
+        Price {
+            amount: Decimal::from_str_exact("123.45").unwrap(),
+            quantity: Some(Decimal::from(7)),
+        },
+        Price {
+            amount: Decimal::from_str_exact("-0.001").unwrap(),
+            quantity: None,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let amounts = b.as_struct().column(0).as_primitive::<Decimal128Type>();
+    assert_eq!(amounts.value(0), 123_450);
+    assert_eq!(amounts.value(1), -1);
+    let round_trip: Vec<Price> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    // extra digits are truncated
+    let b: ArrayRef = [Price {
+        amount: Decimal::from_str_exact("1.23456").unwrap(),
+        quantity: None,
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let round_trip: Vec<Price> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip[0].amount, Decimal::from_str_exact("1.234").unwrap());
+
+    // values that don't fit in the precision return an error
+    let result: arrow::error::Result<ArrayRef> = [Price {
+        amount: Decimal::from(1_000_000_000),
+        quantity: None,
+    }]
+    .try_into_arrow();
+    assert!(result.is_err());
+}
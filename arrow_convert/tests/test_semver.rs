@@ -0,0 +1,100 @@
+#[cfg(feature = "semver")]
+#[test]
+fn test_semver_schema() {
+    use arrow::datatypes::{DataType, Field, Fields};
+    use arrow_convert::field::{ArrowField, VersionStruct};
+    use pretty_assertions::assert_eq;
+    use semver::Version;
+
+    assert_eq!(<Version as ArrowField>::data_type(), DataType::Utf8);
+    assert_eq!(
+        <VersionStruct as ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("major", DataType::UInt64, false),
+            Field::new("minor", DataType::UInt64, false),
+            Field::new("patch", DataType::UInt64, false),
+            Field::new("pre", DataType::Utf8, false),
+            Field::new("build", DataType::Utf8, false),
+        ]))
+    );
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn test_semver_roundtrip() {
+    use arrow::array::{Array, ArrayRef, StructArray, UInt64Array};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::VersionStruct;
+    use arrow_convert::serialize::TryIntoArrow;
+    use pretty_assertions::assert_eq;
+    use semver::Version;
+
+    let original = vec![
+        Some(Version::parse("1.2.3").unwrap()),
+        None,
+        Some(Version::parse("2.0.0-rc.1+build.5").unwrap()),
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Version>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let b: ArrayRef = original.try_into_arrow_as_type::<Option<VersionStruct>>().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    let major = struct_array
+        .column_by_name("major")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(major.value(2), 2);
+    let round_trip: Vec<Option<Version>> = b.try_into_collection_as_type::<Option<VersionStruct>>().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "semver")]
+#[test]
+#[should_panic(expected = "Expected a valid version deserializing `semver::Version`")]
+fn test_semver_invalid() {
+    use arrow::array::{ArrayRef, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use semver::Version;
+    use std::sync::Arc;
+
+    let b: ArrayRef = Arc::new(StringArray::from(vec!["1.2"]));
+    let _: Vec<Version> = b.try_into_collection().unwrap();
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn test_semver_in_struct() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+    use semver::Version;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Release {
+        name: String,
+        version: Version,
+        #[arrow_field(type = "arrow_convert::field::VersionStruct")]
+        parsed: Version,
+        #[arrow_field(type = "Vec<arrow_convert::field::VersionStruct>")]
+        yanked: Vec<Version>,
+    }
+
+    let original = vec![Release {
+        name: "serde".to_string(),
+        version: Version::parse("1.0.200").unwrap(),
+        parsed: Version::parse("1.0.200-alpha").unwrap(),
+        yanked: vec![Version::parse("1.0.199").unwrap()],
+    }];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Release> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
@@ -1,8 +1,10 @@
-use arrow::array::{Array, ArrayRef};
+use arrow::array::{Array, ArrayRef, AsArray};
 use arrow::buffer::{Buffer, ScalarBuffer};
+use arrow::datatypes::Int64Type;
 use arrow::record_batch::RecordBatch;
-use arrow_convert::field::{ArrowField, FixedSizeBinary};
+use arrow_convert::field::{ArrowField, FixedSizeBinary, I128};
 use arrow_convert::serialize::*;
+use arrow_convert::{ArrowField, ArrowSerialize};
 
 #[test]
 fn test_error_exceed_fixed_size_binary() {
@@ -29,6 +31,417 @@ fn test_record_batch() {
     );
 }
 
+#[test]
+fn test_try_into_record_batch() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    #[arrow_field(schema_metadata(source = "test"))]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+    }
+
+    let rows = vec![
+        Row {
+            id: 1,
+            name: Some("a".to_string()),
+        },
+        Row { id: 2, name: None },
+    ];
+    let batch = rows.try_into_record_batch().unwrap();
+    assert_eq!(batch.schema(), Row::arrow_schema());
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(
+        batch.column(0).as_primitive::<Int64Type>().values().to_vec(),
+        vec![1, 2]
+    );
+    assert_eq!(batch.column(1).null_count(), 1);
+
+    let empty: Vec<Row> = vec![];
+    assert_eq!(empty.try_into_record_batch().unwrap().num_rows(), 0);
+
+    // only non-null structs have columns
+    let strs = [b"abc".to_vec()];
+    assert!(strs.try_into_record_batch().is_err());
+    assert!([None::<Row>].try_into_record_batch().is_err());
+    assert_eq!(
+        [Some(Row { id: 3, name: None })]
+            .try_into_record_batch_as_type::<Option<Row>>()
+            .unwrap()
+            .num_rows(),
+        1
+    );
+}
+
+#[test]
+fn test_try_into_arrow_with_schema() {
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Trade {
+        price: i32,
+        symbol: Option<String>,
+        at: chrono::NaiveDateTime,
+    }
+
+    let at = chrono::DateTime::from_timestamp_millis(1_700_000_000_123)
+        .unwrap()
+        .naive_utc();
+    let trades = vec![
+        Trade {
+            price: 10,
+            symbol: Some("ABC".to_string()),
+            at,
+        },
+        Trade {
+            price: -3,
+            symbol: Some("XYZ".to_string()),
+            at,
+        },
+    ];
+
+    // the columns are reordered and cast to the types of an existing dataset
+    let schema = Schema::new(vec![
+        Field::new("at", DataType::Timestamp(TimeUnit::Millisecond, None), false),
+        Field::new("symbol", DataType::LargeUtf8, true),
+        Field::new("price", DataType::Int64, false),
+    ]);
+    let batch = trades.try_into_arrow_with_schema(&schema).unwrap();
+    assert_eq!(batch.schema().as_ref(), &schema);
+    assert_eq!(
+        batch
+            .column(0)
+            .as_primitive::<arrow::datatypes::TimestampMillisecondType>()
+            .values()
+            .to_vec(),
+        vec![1_700_000_000_123; 2]
+    );
+    assert_eq!(
+        batch.column(1).as_string::<i64>().iter().collect::<Vec<_>>(),
+        vec![Some("ABC"), Some("XYZ")]
+    );
+    assert_eq!(
+        batch.column(2).as_primitive::<Int64Type>().values().to_vec(),
+        vec![10, -3]
+    );
+
+    // the symbol has no nulls, so it can be written to a non-nullable field
+    let schema = Schema::new(vec![
+        Field::new("price", DataType::Int32, false),
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("at", DataType::Timestamp(TimeUnit::Nanosecond, None), false),
+    ]);
+    assert!(trades.try_into_arrow_with_schema(&schema).is_ok());
+
+    // every difference is listed
+    let schema = Schema::new(vec![
+        Field::new("price", DataType::Int16, false),
+        Field::new("venue", DataType::Utf8, false),
+        Field::new("symbol", DataType::Utf8, false),
+    ]);
+    let mut trades = trades;
+    trades[1].symbol = None;
+    let err = trades.try_into_arrow_with_schema(&schema).unwrap_err().to_string();
+    assert_eq!(
+        err,
+        "Invalid argument error: The serialized columns don't match the target schema: column `price` of type Int32 \
+         can't be cast to Int16, missing column `venue` of type Utf8, column `symbol` has nulls but isn't nullable, \
+         unexpected column `at` of type Timestamp(Nanosecond, None)"
+    );
+}
+
+#[test]
+fn test_serialize_chunked() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+    }
+
+    // the rows are generated as they're serialized
+    let batches = serialize_chunked::<Row, _>((0..7).map(|id| Row { id }), 3)
+        .collect::<arrow::error::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        vec![3, 3, 1]
+    );
+    assert!(batches.iter().all(|b| b.schema() == Row::arrow_schema()));
+    assert_eq!(
+        batches[1].column(0).as_primitive::<Int64Type>().values().to_vec(),
+        vec![3, 4, 5]
+    );
+
+    let rows = vec![Row { id: 1 }, Row { id: 2 }];
+    assert_eq!(serialize_chunked::<Row, _>(&rows, 2).count(), 1);
+    assert_eq!(serialize_chunked::<Row, _>(&rows[..0], 2).count(), 0);
+
+    // non-struct types can't be converted, and the iterator stops after the error
+    let mut batches = serialize_chunked::<i32, _>([1, 2, 3], 2);
+    assert!(batches.next().unwrap().is_err());
+    assert!(batches.next().is_none());
+}
+
+#[test]
+fn test_typed_batch_serializer() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+        name: String,
+    }
+
+    let mut serializer = TypedBatchSerializer::<Row>::new();
+    assert!(serializer.is_empty());
+    for id in 0..3 {
+        serializer
+            .push(&Row {
+                id,
+                name: id.to_string(),
+            })
+            .unwrap();
+    }
+    assert_eq!(serializer.len(), 3);
+
+    let batch = serializer.flush().unwrap();
+    assert_eq!(batch.schema(), Row::arrow_schema());
+    assert_eq!(
+        batch.column(0).as_primitive::<Int64Type>().values().to_vec(),
+        vec![0, 1, 2]
+    );
+    assert!(serializer.is_empty());
+
+    // the next batch only holds the rows pushed after the flush
+    serializer
+        .push(&Row {
+            id: 3,
+            name: "3".to_string(),
+        })
+        .unwrap();
+    assert_eq!(serializer.flush().unwrap().num_rows(), 1);
+    assert_eq!(serializer.flush().unwrap().num_rows(), 0);
+
+    // a reset discards the current batch
+    serializer
+        .push(&Row {
+            id: 4,
+            name: "4".to_string(),
+        })
+        .unwrap();
+    serializer.reset();
+    assert!(serializer.is_empty());
+    serializer
+        .push(&Row {
+            id: 5,
+            name: "5".to_string(),
+        })
+        .unwrap();
+    let batch = serializer.flush().unwrap();
+    assert_eq!(
+        batch.column(0).as_primitive::<Int64Type>().values().to_vec(),
+        vec![5]
+    );
+
+    // a failed row discards the current batch, even if some of its columns were appended
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Keyed {
+        id: i64,
+        #[arrow_field(type = "FixedSizeBinary<2>")]
+        key: Vec<u8>,
+    }
+
+    let mut serializer = TypedBatchSerializer::<Keyed>::new();
+    serializer
+        .push(&Keyed {
+            id: 1,
+            key: b"ab".to_vec(),
+        })
+        .unwrap();
+    assert!(serializer
+        .push(&Keyed {
+            id: 2,
+            key: b"abc".to_vec()
+        })
+        .is_err());
+    assert!(serializer.is_empty());
+    serializer
+        .push(&Keyed {
+            id: 3,
+            key: b"cd".to_vec(),
+        })
+        .unwrap();
+    assert_eq!(serializer.flush().unwrap().num_rows(), 1);
+}
+
+#[test]
+fn test_typed_batch_serializer_extend() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+    }
+
+    let rows = (0..3).map(|id| Row { id }).collect::<Vec<_>>();
+
+    // rows can be extended by reference or by value
+    let mut serializer = TypedBatchSerializer::<Row>::new();
+    serializer.extend(&rows);
+    serializer.extend((3..5).map(|id| Row { id }));
+    let batch = serializer.flush().unwrap();
+    assert_eq!(
+        batch.column(0).as_primitive::<Int64Type>().values().to_vec(),
+        vec![0, 1, 2, 3, 4]
+    );
+
+    let mut serializer = rows.iter().collect::<TypedBatchSerializer<Row>>();
+    assert_eq!(serializer.len(), 3);
+    assert_eq!(serializer.flush().unwrap().num_rows(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Row 1")]
+fn test_typed_batch_serializer_extend_panics() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Keyed {
+        #[arrow_field(type = "FixedSizeBinary<2>")]
+        key: Vec<u8>,
+    }
+
+    let _ = [b"ab".to_vec(), b"abc".to_vec()]
+        .into_iter()
+        .map(|key| Keyed { key })
+        .collect::<TypedBatchSerializer<Keyed>>();
+}
+
+#[test]
+fn test_serialize_options() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+        #[arrow_field(type = "FixedSizeBinary<2>")]
+        key: Vec<u8>,
+    }
+
+    let options = SerializeOptions::new(100).with_approx_bytes_per_string(16);
+    assert!(<i64 as ArrowSerialize>::new_array_with_capacity(&options).capacity() >= 100);
+
+    let rows = (0..100)
+        .map(|id| Row {
+            id,
+            name: (id % 2 == 0).then(|| id.to_string()),
+            key: vec![1, 2],
+        })
+        .collect::<Vec<_>>();
+    let presized: ArrayRef = rows.try_into_arrow_with_options(&options).unwrap();
+    let array: ArrayRef = rows.try_into_arrow().unwrap();
+    assert_eq!(&presized, &array);
+
+    // the hints are a lower bound, so serializing more values grows the builders
+    let strs: ArrayRef = vec!["a".repeat(100); 3]
+        .try_into_arrow_with_options(&SerializeOptions::new(1).with_approx_bytes_per_string(1))
+        .unwrap();
+    assert_eq!(strs.len(), 3);
+
+    let mut serializer = TypedBatchSerializer::<Row>::with_options(SerializeOptions::new(64));
+    for row in &rows[..10] {
+        serializer.push(row).unwrap();
+    }
+    assert_eq!(serializer.flush().unwrap().num_rows(), 10);
+    serializer.push(&rows[10]).unwrap();
+    assert_eq!(serializer.flush().unwrap().num_rows(), 1);
+}
+
+#[test]
+fn test_serialize_references() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+        name: String,
+    }
+
+    let row = |id: i64| Row {
+        id,
+        name: id.to_string(),
+    };
+    let expected: ArrayRef = vec![row(1), row(3)].try_into_arrow().unwrap();
+
+    // the values of a map
+    let map = std::collections::BTreeMap::from([("a", row(1)), ("b", row(3))]);
+    let array: ArrayRef = map.values().try_into_arrow().unwrap();
+    assert_eq!(&array, &expected);
+
+    // a filtered iterator
+    let rows = (0..5).map(row).collect::<Vec<_>>();
+    let array: ArrayRef = rows.iter().filter(|r| r.id % 2 == 1).try_into_arrow().unwrap();
+    assert_eq!(&array, &expected);
+
+    // rows held elsewhere, such as in an arena
+    let arena = rows.iter().map(|r| Box::new(r.clone())).collect::<Vec<_>>();
+    let refs = [&*arena[1], &*arena[3]];
+    let array: ArrayRef = refs.into_iter().try_into_arrow().unwrap();
+    assert_eq!(&array, &expected);
+    let batch = refs.into_iter().try_into_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 2);
+    let batches = serialize_chunked::<Row, _>(refs, 1).collect::<arrow::error::Result<Vec<_>>>();
+    assert_eq!(batches.unwrap().len(), 2);
+}
+
+#[test]
+fn test_serialize_error_row() {
+    let strs = [b"ab".to_vec(), b"cd".to_vec(), b"efg".to_vec()];
+    let r: arrow::error::Result<ArrayRef> = strs.try_into_arrow_as_type::<FixedSizeBinary<2>>();
+    assert!(r.unwrap_err().to_string().contains("Row 2: "));
+
+    // decimals that don't fit the precision are rejected rather than producing an invalid array
+    let values = [99i128, 100];
+    let r: arrow::error::Result<ArrayRef> = values.try_into_arrow_as_type::<I128<2, 0>>();
+    assert!(r.unwrap_err().to_string().contains("Row 1: "));
+
+    // batches name the row in the whole collection
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Price {
+        #[arrow_field(type = "I128<2, 0>")]
+        cents: i128,
+    }
+
+    let prices = [1, 2, 3, 400].map(|cents| Price { cents });
+    let batches = serialize_chunked::<Price, _>(&prices, 2).collect::<Vec<_>>();
+    assert_eq!(batches.len(), 2);
+    assert!(batches[1].as_ref().unwrap_err().to_string().contains("Row 3: "));
+
+    let mut serializer = TypedBatchSerializer::<I128<2, 0>>::new();
+    assert!(serializer.push(&100).unwrap_err().to_string().contains("Row 0: "));
+}
+
+#[test]
+fn test_serialize_extend() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+        #[arrow_field(type = "FixedSizeBinary<2>")]
+        key: Vec<u8>,
+    }
+
+    let row = |id: i64| Row {
+        id,
+        key: vec![id as u8; 2],
+    };
+    let first = vec![row(1), row(2)];
+    let second = std::collections::VecDeque::from([row(3)]);
+
+    // the rows of several sources are appended to the same builder
+    let mut builder = <Row as ArrowSerialize>::new_array();
+    arrow_serialize_extend::<Row, Row, _>(&first, &mut builder).unwrap();
+    arrow_serialize_extend::<Row, Row, _>(&second, &mut builder).unwrap();
+    let array: ArrayRef = arrow::array::ArrayBuilder::finish(&mut builder);
+    let expected: ArrayRef = [row(1), row(2), row(3)].try_into_arrow().unwrap();
+    assert_eq!(&array, &expected);
+
+    // errors count the rows already in the builder
+    let mut builder = <FixedSizeBinary<2> as ArrowSerialize>::new_array();
+    let keys = [b"ab".to_vec(), b"abc".to_vec()];
+    arrow_serialize_extend::<Vec<u8>, FixedSizeBinary<2>, _>(&keys[..1], &mut builder).unwrap();
+    let r = arrow_serialize_extend::<Vec<u8>, FixedSizeBinary<2>, _>(&keys, &mut builder);
+    assert!(r.unwrap_err().to_string().contains("Row 2: "));
+}
+
 #[test]
 fn test_array() {
     let strs = [b"abc".to_vec()];
@@ -106,3 +519,201 @@ fn test_buffer() {
 //     let r: arrow::error::Result<ArrayRef> = arr.try_into_arrow();
 //     assert!(r.is_err())
 // }
+
+#[test]
+fn test_check_offsets() {
+    use arrow::array::{Int32Builder, LargeListBuilder, LargeStringBuilder, ListBuilder, MapBuilder, StringBuilder};
+    use arrow_convert::serialize::check_map_offsets;
+
+    // more than 2GB of values can't be appended to arrays with i32 offsets
+    let mut strings = StringBuilder::new();
+    strings.append_value("abc");
+    assert!(strings.check_offsets(i32::MAX as usize - 3).is_ok());
+    assert_eq!(
+        strings.check_offsets(i32::MAX as usize - 2).unwrap_err().to_string(),
+        "Invalid argument error: Appending 2147483645 bytes to the 3 bytes of a Utf8 array overflows its offsets, use \
+         a large type such as LargeString or LargeBinary"
+    );
+    assert!(LargeStringBuilder::new().check_offsets(i32::MAX as usize + 1).is_ok());
+
+    let mut lists = ListBuilder::new(Int32Builder::new());
+    lists.values().append_value(1);
+    lists.append(true);
+    assert!(lists.check_offsets(i32::MAX as usize - 1).is_ok());
+    assert!(lists.check_offsets(i32::MAX as usize).is_err());
+    assert!(LargeListBuilder::new(Int32Builder::new())
+        .check_offsets(i32::MAX as usize + 1)
+        .is_ok());
+
+    let mut maps = MapBuilder::new(None, Int32Builder::new(), Int32Builder::new());
+    maps.keys().append_value(1);
+    maps.values().append_value(1);
+    maps.append(true).unwrap();
+    assert!(check_map_offsets(&mut maps, i32::MAX as usize - 1).is_ok());
+    assert_eq!(
+        check_map_offsets(&mut maps, i32::MAX as usize).unwrap_err().to_string(),
+        "Invalid argument error: Appending 2147483647 entries to the 1 entries of a Map array overflows its offsets"
+    );
+}
+
+#[test]
+fn test_no_validity_without_nulls() {
+    use arrow::array::ArrayData;
+    use arrow_convert::ArrowDeserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Inner {
+        a: Option<i32>,
+        b: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    enum Shape {
+        Circle(f64),
+        Square { side: Option<f64> },
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        flag: Option<bool>,
+        name: Option<String>,
+        bytes: Option<Vec<u8>>,
+        fixed: [u8; 2],
+        list: Option<Vec<Option<i32>>>,
+        inner: Option<Inner>,
+        inners: Vec<Inner>,
+        pair: Option<(i32, String)>,
+        map: HashMap<String, Option<i32>>,
+        shape: Shape,
+        #[arrow_field(type = "Option<arrow_convert::field::Dictionary<i32, String>>")]
+        category: Option<String>,
+        #[arrow_field(type = "Option<arrow_convert::field::FixedSizeVec<i64, 2>>")]
+        point: Option<Vec<i64>>,
+    }
+
+    // arrays without nulls carry no validity buffer, including nested arrays
+    fn assert_no_validity(data: &ArrayData, path: &str) {
+        assert_eq!(data.null_count(), 0, "{path}");
+        assert!(data.nulls().is_none(), "{path} has a validity buffer");
+        for (i, child) in data.child_data().iter().enumerate() {
+            assert_no_validity(child, &format!("{path}.{i}"));
+        }
+    }
+
+    let row = Row {
+        id: 1,
+        flag: Some(true),
+        name: Some("a".to_string()),
+        bytes: Some(vec![1, 2]),
+        fixed: [3, 4],
+        list: Some(vec![Some(1), Some(2)]),
+        inner: Some(Inner {
+            a: Some(1),
+            b: Some("b".to_string()),
+        }),
+        inners: vec![Inner {
+            a: Some(2),
+            b: Some("c".to_string()),
+        }],
+        pair: Some((1, "d".to_string())),
+        map: HashMap::from([("k".to_string(), Some(1))]),
+        shape: Shape::Square { side: Some(1.0) },
+        category: Some("e".to_string()),
+        point: Some(vec![1, 2]),
+    };
+    let rows = vec![row.clone(), Row { id: 2, ..row }];
+    let array: ArrayRef = rows.try_into_arrow().unwrap();
+    assert_no_validity(&array.to_data(), "row");
+
+    // a batch with nulls doesn't leave a validity buffer on the next batch of the same builders
+    let mut builder = arrow_serialize_to_mutable_array::<Option<i32>, Option<i32>, _>(&[None, Some(1)]).unwrap();
+    assert_eq!(builder.finish().null_count(), 1);
+    arrow_serialize_extend::<Option<i32>, Option<i32>, _>(&[Some(2)], &mut builder).unwrap();
+    assert!(builder.finish().nulls().is_none());
+}
+
+#[test]
+fn test_typed_writer() {
+    use arrow::record_batch::RecordBatchWriter;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Row {
+        id: i64,
+    }
+
+    #[derive(Default)]
+    struct Collect {
+        batches: Rc<RefCell<Vec<RecordBatch>>>,
+        closed: Rc<RefCell<bool>>,
+    }
+
+    impl RecordBatchWriter for Collect {
+        fn write(&mut self, batch: &RecordBatch) -> arrow::error::Result<()> {
+            self.batches.borrow_mut().push(batch.clone());
+            Ok(())
+        }
+
+        fn close(self) -> arrow::error::Result<()> {
+            *self.closed.borrow_mut() = true;
+            Ok(())
+        }
+    }
+
+    fn ids(batches: &RefCell<Vec<RecordBatch>>) -> Vec<Vec<i64>> {
+        batches
+            .borrow()
+            .iter()
+            .map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect()
+    }
+
+    let collect = Collect::default();
+    let (batches, closed) = (collect.batches.clone(), collect.closed.clone());
+    let mut writer = TypedWriter::<Row, _>::new(collect).with_max_rows_per_batch(2);
+    writer.write(&Row { id: 0 }).unwrap();
+    assert!(batches.borrow().is_empty());
+    writer.write_all((1..4).map(|id| Row { id })).unwrap();
+    assert_eq!(ids(&batches), vec![vec![0, 1], vec![2, 3]]);
+    writer.write_all(&vec![Row { id: 4 }]).unwrap();
+    assert_eq!(writer.rows(), 5);
+    writer.close().unwrap();
+    assert_eq!(ids(&batches), vec![vec![0, 1], vec![2, 3], vec![4]]);
+    assert_eq!(batches.borrow()[0].schema(), Row::arrow_schema());
+    assert!(*closed.borrow());
+
+    // dropping the writer writes the pending rows and closes it
+    let collect = Collect::default();
+    let (batches, closed) = (collect.batches.clone(), collect.closed.clone());
+    let mut writer = TypedWriter::<Row, _>::new(collect);
+    writer.write(&Row { id: 7 }).unwrap();
+    drop(writer);
+    assert_eq!(ids(&batches), vec![vec![7]]);
+    assert!(*closed.borrow());
+
+    // a row that fails to serialize discards the pending rows, which aren't counted
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize)]
+    struct Pair {
+        id: i64,
+        #[arrow_field(type = "arrow_convert::field::FixedSizeVec<i64, 2>")]
+        pair: Vec<i64>,
+    }
+
+    let collect = Collect::default();
+    let batches = collect.batches.clone();
+    let mut writer = TypedWriter::<Pair, _>::new(collect).with_max_rows_per_batch(2);
+    let row = |id| Pair { id, pair: vec![id, id] };
+    writer.write_all([row(0), row(1), row(2)]).unwrap();
+    let err = writer.write(&Pair { id: 3, pair: vec![3] }).unwrap_err();
+    assert!(err.to_string().contains("Row 3: "), "{err}");
+    assert_eq!(writer.rows(), 2);
+    writer.flush().unwrap();
+    assert_eq!(ids(&batches), vec![vec![0, 1]]);
+    writer.write(&row(4)).unwrap();
+    assert_eq!(writer.rows(), 3);
+    writer.close().unwrap();
+    assert_eq!(ids(&batches), vec![vec![0, 1], vec![4]]);
+}
@@ -0,0 +1,80 @@
+use std::collections::{BTreeSet, HashSet};
+
+use arrow::array::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::ArrowField;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_set_schema() {
+    assert_eq!(
+        <HashSet<String> as ArrowField>::data_type(),
+        <Vec<String> as ArrowField>::data_type()
+    );
+    assert_eq!(
+        <BTreeSet<i32> as ArrowField>::data_type(),
+        <Vec<i32> as ArrowField>::data_type()
+    );
+}
+
+#[test]
+fn test_hash_set_round_trip() {
+    let original: Vec<Option<HashSet<String>>> = vec![
+        Some(HashSet::from(["a".to_string(), "b".to_string()])),
+        None,
+        Some(HashSet::new()),
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<HashSet<String>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_btree_set_sorted() {
+    let original = vec![BTreeSet::from([3, 1, 2]), BTreeSet::from([10])];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let list = b.as_any().downcast_ref::<ListArray>().unwrap();
+    let values = list.values().as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(values.values().to_vec(), vec![1, 2, 3, 10]);
+
+    let round_trip: Vec<BTreeSet<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_set_dedup() {
+    // Lists with duplicate values lose the duplicates when deserialized into a set,
+    // so the round-trip through a set is not lossless for such data.
+    let original = vec![vec![1, 2, 2, 3, 1]];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+
+    let hash_sets: Vec<HashSet<i32>> = b.clone().try_into_collection().unwrap();
+    assert_eq!(hash_sets, vec![HashSet::from([1, 2, 3])]);
+
+    let btree_sets: Vec<BTreeSet<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(btree_sets, vec![BTreeSet::from([1, 2, 3])]);
+}
+
+#[test]
+fn test_set_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        tags: HashSet<String>,
+        ids: BTreeSet<u64>,
+        nested: Vec<BTreeSet<i16>>,
+    }
+
+    let original = vec![S {
+        tags: HashSet::from(["x".to_string()]),
+        ids: BTreeSet::from([5, 4]),
+        nested: vec![BTreeSet::from([1, -1]), BTreeSet::new()],
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
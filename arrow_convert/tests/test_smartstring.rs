@@ -0,0 +1,46 @@
+#[cfg(feature = "smartstring")]
+#[test]
+fn test_smartstring_roundtrip() {
+    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use smartstring::alias::String as SmartString;
+    use smartstring::{Compact, SmartString as GenericSmartString};
+
+    let original = vec![
+        SmartString::from("short"),
+        SmartString::from("a string that is too long to be stored inline"),
+        SmartString::new(),
+    ];
+
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let string_array = arrow_array.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(string_array.value(0), "short");
+
+    let roundtrip: Vec<SmartString> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Tagged {
+        name: SmartString,
+        alias: Option<GenericSmartString<Compact>>,
+        tags: Vec<SmartString>,
+    }
+
+    let original = vec![
+        Tagged {
+            name: "a".into(),
+            alias: None,
+            tags: vec!["x".into(), "y".into()],
+        },
+        Tagged {
+            name: "b".into(),
+            alias: Some("bee".into()),
+            tags: vec![],
+        },
+    ];
+    let arrow_array: ArrayRef = original.try_into_arrow().unwrap();
+    let roundtrip: Vec<Tagged> = arrow_array.try_into_collection().unwrap();
+    assert_eq!(original, roundtrip);
+}
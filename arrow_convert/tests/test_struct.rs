@@ -90,3 +90,310 @@ fn test_nested_slice() {
         assert_eq!(round_trip, original_slice);
     }
 }
+
+#[test]
+fn test_generic_struct() {
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Point<T: arrow_convert::field::ArrowField> {
+        x: T,
+        y: T,
+    }
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Series<K, V>
+    where
+        V: Clone,
+    {
+        key: K,
+        values: Vec<V>,
+        last: Option<V>,
+    }
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    #[arrow_field(transparent)]
+    struct Id<T>(T);
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Shape {
+        id: Id<u32>,
+        origin: Point<f64>,
+        corners: Vec<Point<i32>>,
+    }
+
+    assert_eq!(
+        *Point::<i16>::arrow_schema(),
+        Schema::new(vec![
+            Field::new("x", DataType::Int16, false),
+            Field::new("y", DataType::Int16, false),
+        ])
+    );
+    assert_eq!(
+        Point::<i16>::arrow_fields(),
+        vec![
+            std::sync::Arc::new(Field::new("x", DataType::Int16, false)),
+            std::sync::Arc::new(Field::new("y", DataType::Int16, false)),
+        ]
+    );
+    assert_eq!(
+        <Shape as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Shape::arrow_schema().fields().clone())
+    );
+    assert_eq!(
+        <Id<String> as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Utf8
+    );
+
+    let original = vec![Point { x: 1u8, y: 2 }, Point { x: 3, y: 4 }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Point<u8>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        Series {
+            key: "a".to_string(),
+            values: vec![1.5, 2.5],
+            last: Some(2.5),
+        },
+        Series {
+            key: "b".to_string(),
+            values: vec![],
+            last: None,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Series<String, f64>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Shape {
+        id: Id(7),
+        origin: Point { x: 0.5, y: -0.5 },
+        corners: vec![Point { x: 0, y: 0 }, Point { x: 1, y: 1 }],
+    }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Shape> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_struct_with_lifetimes() {
+    use arrow::datatypes::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct Row<'a> {
+        name: &'a str,
+        data: &'a [u8],
+        n: i32,
+    }
+
+    assert_eq!(
+        *Row::arrow_schema(),
+        Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("data", DataType::Binary, false),
+            Field::new("n", DataType::Int32, false),
+        ])
+    );
+
+    let names = ["a".to_string(), "bc".to_string()];
+    let data = [vec![1u8, 2], vec![]];
+    let rows = names
+        .iter()
+        .zip(data.iter())
+        .enumerate()
+        .map(|(n, (name, data))| Row {
+            name,
+            data,
+            n: n as i32,
+        })
+        .collect::<Vec<_>>();
+
+    let b: ArrayRef = rows.try_into_arrow().unwrap();
+    let b = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(b.len(), 2);
+    let expected_names: ArrayRef = Arc::new(StringArray::from(vec!["a", "bc"]));
+    let expected_data: ArrayRef = Arc::new(BinaryArray::from(vec![&[1u8, 2][..], &[][..]]));
+    let expected_n: ArrayRef = Arc::new(Int32Array::from(vec![0, 1]));
+    assert_eq!(b.column(0), &expected_names);
+    assert_eq!(b.column(1), &expected_data);
+    assert_eq!(b.column(2), &expected_n);
+}
+
+#[test]
+fn test_tuple_struct() {
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Record(i32, Option<String>, #[arrow_field(name = "valid")] bool);
+
+    assert_eq!(
+        *Record::arrow_schema(),
+        Schema::new(vec![
+            Field::new("0", DataType::Int32, false),
+            Field::new("1", DataType::Utf8, true),
+            Field::new("valid", DataType::Boolean, false),
+        ])
+    );
+
+    let original = vec![Record(1, Some("a".to_string()), true), Record(2, None, false)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Record> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    // a tuple struct has the same layout as the tuple of its fields
+    let tuples = vec![(1, Some("a".to_string()), true)];
+    let b: ArrayRef = tuples.try_into_arrow().unwrap();
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(arr.column_names(), vec!["0", "1", "2"]);
+}
+
+#[test]
+fn test_unit_struct() {
+    use arrow::datatypes::{DataType, Field, Fields};
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Marker;
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    #[arrow_field(mode = "struct")]
+    struct Empty {}
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Row {
+        marker: Marker,
+        empty: Option<Empty>,
+    }
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    enum Event {
+        Tick(Marker),
+        Value(i32),
+    }
+
+    assert_eq!(
+        <Marker as arrow_convert::field::ArrowField>::field("m"),
+        Field::new("m", DataType::Null, true)
+    );
+    assert_eq!(
+        <Empty as arrow_convert::field::ArrowField>::field("e"),
+        Field::new("e", DataType::Struct(Fields::empty()), false)
+    );
+
+    let original = vec![Marker, Marker];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Null);
+    assert_eq!(b.len(), 2);
+    let round_trip: Vec<Marker> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Some(Empty {}), None, Some(Empty {})];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.len(), 3);
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Empty>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        Row {
+            marker: Marker,
+            empty: Some(Empty {}),
+        },
+        Row {
+            marker: Marker,
+            empty: None,
+        },
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Event::Tick(Marker), Event::Value(3), Event::Tick(Marker)];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_independent_derives() {
+    // only serializable, the field type doesn't implement `ArrowDeserialize`
+    #[derive(ArrowField, ArrowSerialize)]
+    struct Output {
+        id: i32,
+        name: &'static str,
+    }
+
+    // only deserializable
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Input {
+        id: i32,
+        name: String,
+    }
+
+    let original = [Output { id: 1, name: "a" }, Output { id: 2, name: "b" }];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Input> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Input {
+                id: 1,
+                name: "a".to_string()
+            },
+            Input {
+                id: 2,
+                name: "b".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::vec_box)]
+fn test_boxed_fields() {
+    use arrow::datatypes::*;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Node {
+        value: i64,
+        position: Box<Point>,
+        parent: Option<Box<Point>>,
+        children: Vec<Box<Point>>,
+    }
+
+    let point_type = <Point as arrow_convert::field::ArrowField>::data_type();
+    assert_eq!(
+        <Node as arrow_convert::field::ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("value", DataType::Int64, false),
+            Field::new("position", point_type.clone(), false),
+            Field::new("parent", point_type.clone(), true),
+            Field::new_list("children", Field::new("item", point_type, false), false),
+        ]))
+    );
+
+    let nodes = vec![
+        Node {
+            value: 1,
+            position: Box::new(Point { x: 0, y: 0 }),
+            parent: None,
+            children: vec![Box::new(Point { x: 1, y: 2 })],
+        },
+        Node {
+            value: 2,
+            position: Box::new(Point { x: 1, y: 2 }),
+            parent: Some(Box::new(Point { x: 0, y: 0 })),
+            children: vec![],
+        },
+    ];
+    let b: ArrayRef = nodes.try_into_arrow().unwrap();
+    let round_trip: Vec<Node> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, nodes);
+}
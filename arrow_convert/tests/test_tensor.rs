@@ -0,0 +1,101 @@
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::{FixedShapeTensor, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY};
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_tensor_schema() {
+    type Tensor = FixedShapeTensor<[[f32; 3]; 2]>;
+
+    assert_eq!(
+        <Tensor as arrow_convert::field::ArrowField>::data_type(),
+        DataType::FixedSizeList(Field::new("item", DataType::Float32, false).into(), 6)
+    );
+
+    let field = <Tensor as arrow_convert::field::ArrowField>::field("tensor");
+    assert_eq!(
+        field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str),
+        Some("arrow.fixed_shape_tensor")
+    );
+    assert_eq!(
+        field.metadata().get(EXTENSION_METADATA_KEY).map(String::as_str),
+        Some(r#"{"shape":[2,3]}"#)
+    );
+
+    let field = <Option<FixedShapeTensor<[u8; 4]>> as arrow_convert::field::ArrowField>::field("tensor");
+    assert!(field.is_nullable());
+    assert_eq!(
+        field.metadata().get(EXTENSION_METADATA_KEY).map(String::as_str),
+        Some(r#"{"shape":[4]}"#)
+    );
+}
+
+#[test]
+fn test_tensor_round_trip() {
+    let original: Vec<[[f32; 3]; 2]> = vec![[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], [[0.0; 3]; 2]];
+
+    let b: ArrayRef = original
+        .try_into_arrow_as_type::<FixedShapeTensor<[[f32; 3]; 2]>>()
+        .unwrap();
+    let list = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    assert_eq!(list.value_length(), 6);
+    let values = list.values().as_any().downcast_ref::<Float32Array>().unwrap();
+    assert_eq!(values.values()[..6], [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let round_trip: Vec<[[f32; 3]; 2]> = b
+        .try_into_collection_as_type::<FixedShapeTensor<[[f32; 3]; 2]>>()
+        .unwrap();
+    assert_eq!(round_trip, original);
+
+    let original: Vec<Option<[[[u8; 2]; 2]; 2]>> = vec![Some([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]), None];
+    let b: ArrayRef = original
+        .try_into_arrow_as_type::<Option<FixedShapeTensor<[[[u8; 2]; 2]; 2]>>>()
+        .unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<[[[u8; 2]; 2]; 2]>> = b
+        .try_into_collection_as_type::<Option<FixedShapeTensor<[[[u8; 2]; 2]; 2]>>>()
+        .unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tensor_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Tile {
+        #[arrow_field(type = "FixedShapeTensor<[[f32; 3]; 2]>")]
+        pixels: [[f32; 3]; 2],
+        #[arrow_field(type = "Option<FixedShapeTensor<[i64; 4]>>")]
+        embedding: Option<[i64; 4]>,
+        #[arrow_field(type = "Vec<FixedShapeTensor<[bool; 2]>>")]
+        masks: Vec<[bool; 2]>,
+    }
+
+    let fields = match <Tile as arrow_convert::field::ArrowField>::data_type() {
+        DataType::Struct(fields) => fields,
+        _ => panic!("expected struct"),
+    };
+    assert_eq!(
+        fields[0].metadata().get(EXTENSION_METADATA_KEY).map(String::as_str),
+        Some(r#"{"shape":[2,3]}"#)
+    );
+
+    let original = vec![
+        Tile {
+            pixels: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            embedding: Some([1, 2, 3, 4]),
+            masks: vec![[true, false], [false, true]],
+        },
+        Tile {
+            pixels: [[0.5; 3]; 2],
+            embedding: None,
+            masks: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Tile> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
@@ -0,0 +1,114 @@
+#[cfg(feature = "time")]
+#[test]
+fn test_time_schema() {
+    use arrow::datatypes::{DataType, TimeUnit};
+    use arrow_convert::field::ArrowField;
+    use pretty_assertions::assert_eq;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    assert_eq!(<Date as ArrowField>::data_type(), DataType::Date32);
+    assert_eq!(
+        <Time as ArrowField>::data_type(),
+        DataType::Time64(TimeUnit::Nanosecond)
+    );
+    assert_eq!(
+        <PrimitiveDateTime as ArrowField>::data_type(),
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    );
+    assert_eq!(
+        <OffsetDateTime as ArrowField>::data_type(),
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_roundtrip() {
+    use arrow::array::{Array, ArrayRef, StructArray, TimestampNanosecondArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        date: Date,
+        time: Time,
+        local: PrimitiveDateTime,
+        instant: OffsetDateTime,
+        maybe: Option<Date>,
+        history: Vec<PrimitiveDateTime>,
+    }
+
+    let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+    let time = Time::from_hms_nano(23, 59, 58, 123_456_789).unwrap();
+    let local = PrimitiveDateTime::new(date, time);
+    let before_epoch = PrimitiveDateTime::new(
+        Date::from_calendar_date(1969, Month::July, 20).unwrap(),
+        Time::from_hms(20, 17, 40).unwrap(),
+    );
+
+    let original = vec![
+        Event {
+            date,
+            time,
+            local,
+            instant: local.assume_utc(),
+            maybe: Some(date),
+            history: vec![local, before_epoch],
+        },
+        Event {
+            date: Date::from_calendar_date(1900, Month::January, 1).unwrap(),
+            time: Time::MIDNIGHT,
+            local: before_epoch,
+            instant: before_epoch.assume_utc(),
+            maybe: None,
+            history: vec![],
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    let instants = struct_array
+        .column_by_name("instant")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert_eq!(
+        instants.value(0),
+        local.assume_utc().unix_timestamp_nanos() as i64
+    );
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // offsets are normalized to UTC
+    let offset = local.assume_offset(UtcOffset::from_hms(10, 0, 0).unwrap());
+    let b: ArrayRef = vec![offset].try_into_arrow().unwrap();
+    let round_trip: Vec<OffsetDateTime> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![offset]);
+    assert_eq!(round_trip[0].offset(), UtcOffset::UTC);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_timestamp_overflow() {
+    use arrow::array::ArrayRef;
+    use arrow_convert::serialize::TryIntoArrow;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    // nanosecond timestamps only cover the years 1677 to 2262
+    let far = PrimitiveDateTime::new(
+        Date::from_calendar_date(2300, Month::January, 1).unwrap(),
+        Time::MIDNIGHT,
+    );
+    let r: arrow::error::Result<ArrayRef> = [far].try_into_arrow();
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "Invalid argument error: Row 0: Value of `time::primitive_date_time::PrimitiveDateTime` overflows an Arrow \
+         `Timestamp(Nanosecond)`"
+    );
+    let r: arrow::error::Result<ArrayRef> = [Some(far.assume_utc())].try_into_arrow();
+    assert!(r.is_err());
+}
@@ -0,0 +1,82 @@
+use arrow::array::*;
+use arrow::datatypes::*;
+use arrow_convert::deserialize::TryIntoCollection;
+use arrow_convert::field::ArrowField;
+use arrow_convert::serialize::TryIntoArrow;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_tuple_schema() {
+    assert_eq!(
+        <(i32, Option<String>) as ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![
+            Field::new("0", DataType::Int32, false),
+            Field::new("1", DataType::Utf8, true),
+        ]))
+    );
+    assert_eq!(
+        <(bool,) as ArrowField>::data_type(),
+        DataType::Struct(Fields::from(vec![Field::new("0", DataType::Boolean, false)]))
+    );
+}
+
+#[test]
+fn test_tuple_round_trip() {
+    let original: Vec<(i32, String)> = vec![(1, "one".to_string()), (2, "two".to_string())];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.column_names(), vec!["0", "1"]);
+    let round_trip: Vec<(i32, String)> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    type Triple = (u8, Option<f64>, Vec<i64>);
+
+    let original: Vec<Option<Triple>> = vec![Some((1, Some(1.5), vec![1, 2])), None, Some((3, None, vec![]))];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Triple>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tuple_max_arity() {
+    type Wide = (u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool, String);
+
+    let original: Vec<Wide> = vec![(1, 2, 3, 4, -1, -2, -3, -4, 1.0, 2.0, true, "a".to_string())];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Wide> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_tuple_in_struct() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Root {
+        point: (f64, f64),
+        range: Option<(i32, i32)>,
+        pairs: Vec<(String, Option<i64>)>,
+        nested: ((u8, u8), String),
+    }
+
+    let original = vec![
+        Root {
+            point: (1.0, 2.0),
+            range: Some((0, 10)),
+            pairs: vec![("a".to_string(), Some(1)), ("b".to_string(), None)],
+            nested: ((1, 2), "x".to_string()),
+        },
+        Root {
+            point: (3.0, 4.0),
+            range: None,
+            pairs: vec![],
+            nested: ((3, 4), "y".to_string()),
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Root> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
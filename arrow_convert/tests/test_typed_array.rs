@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Int32Array, StructArray};
+use arrow_convert::deserialize::TypedArray;
+use arrow_convert::field::LargeString;
+use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Foo {
+    id: i64,
+    name: Option<String>,
+}
+
+#[test]
+fn test_typed_array() {
+    let rows = (0..3)
+        .map(|id| Foo {
+            id,
+            name: (id != 1).then(|| id.to_string()),
+        })
+        .collect::<Vec<_>>();
+
+    // values and references to values can be collected
+    let array = rows.clone().into_iter().collect::<TypedArray<Foo>>();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.iter().collect::<Vec<_>>(), rows);
+    assert_eq!(array.get(1), Some(rows[1].clone()));
+    assert_eq!(array.get(3), None);
+
+    let array: ArrayRef = rows.iter().collect::<TypedArray<Foo>>().into();
+    assert_eq!(array.len(), 3);
+    assert!(array.as_any().downcast_ref::<StructArray>().is_some());
+
+    // arrays are checked when wrapped
+    let array = TypedArray::<Foo>::try_new(array).unwrap();
+    assert_eq!(array.get(2).unwrap().name.as_deref(), Some("2"));
+    assert!(TypedArray::<Foo>::try_new(Arc::new(Int32Array::from(vec![1]))).is_err());
+
+    // the Arrow type may differ from the type of the values
+    let array = ["a", "b"].map(String::from).iter().collect::<TypedArray<LargeString>>();
+    assert_eq!(
+        array.as_array().data_type(),
+        &arrow::datatypes::DataType::LargeUtf8
+    );
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+
+    let empty = std::iter::empty::<i32>().collect::<TypedArray<i32>>();
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Row 1")]
+fn test_typed_array_panics() {
+    use arrow_convert::field::FixedSizeBinary;
+
+    let _ = [b"ab".to_vec(), b"abc".to_vec()]
+        .iter()
+        .collect::<TypedArray<FixedSizeBinary<2>>>();
+}
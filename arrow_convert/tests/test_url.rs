@@ -0,0 +1,92 @@
+#[cfg(feature = "url")]
+#[test]
+fn test_url_schema() {
+    use arrow::datatypes::DataType;
+    use arrow_convert::field::{ArrowField, LenientUrl};
+    use pretty_assertions::assert_eq;
+    use url::Url;
+
+    assert_eq!(<Url as ArrowField>::data_type(), DataType::Utf8);
+    assert!(!<Url as ArrowField>::is_nullable());
+    assert_eq!(<LenientUrl as ArrowField>::data_type(), DataType::Utf8);
+    assert!(<LenientUrl as ArrowField>::is_nullable());
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn test_url_roundtrip() {
+    use arrow::array::{Array, ArrayRef};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::serialize::TryIntoArrow;
+    use pretty_assertions::assert_eq;
+    use url::Url;
+
+    let original = vec![
+        Some(Url::parse("https://example.com/path?q=1").unwrap()),
+        None,
+        Some(Url::parse("mailto:someone@example.com").unwrap()),
+    ];
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Url>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[cfg(feature = "url")]
+#[test]
+#[should_panic(expected = "Expected a valid URL deserializing `url::Url`")]
+fn test_url_invalid() {
+    use arrow::array::{ArrayRef, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use std::sync::Arc;
+    use url::Url;
+
+    let b: ArrayRef = Arc::new(StringArray::from(vec!["not a url"]));
+    let _: Vec<Url> = b.try_into_collection().unwrap();
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn test_url_lenient() {
+    use arrow::array::{ArrayRef, StringArray};
+    use arrow_convert::deserialize::TryIntoCollection;
+    use arrow_convert::field::LenientUrl;
+    use arrow_convert::serialize::TryIntoArrow;
+    use arrow_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+    use url::Url;
+
+    let b: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("https://example.com/"),
+        Some("not a url"),
+        None,
+    ]));
+    let round_trip: Vec<Option<Url>> = b.try_into_collection_as_type::<LenientUrl>().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Some(Url::parse("https://example.com/").unwrap()), None, None]
+    );
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct PageView {
+        page: Url,
+        #[arrow_field(type = "LenientUrl")]
+        referrer: Option<Url>,
+    }
+
+    let original = vec![
+        PageView {
+            page: Url::parse("https://example.com/a").unwrap(),
+            referrer: Some(Url::parse("https://search.example.org/").unwrap()),
+        },
+        PageView {
+            page: Url::parse("https://example.com/b").unwrap(),
+            referrer: None,
+        },
+    ];
+
+    let b: ArrayRef = original.try_into_arrow().unwrap();
+    let round_trip: Vec<PageView> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
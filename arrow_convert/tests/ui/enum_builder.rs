@@ -0,0 +1,10 @@
+use arrow_convert::{ArrowField, ArrowSerialize};
+
+#[derive(ArrowField, ArrowSerialize)]
+#[arrow_field(builder)]
+enum E {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(deny_unknown_fields)]
+enum E {
+    A,
+    B,
+}
+
+fn main() {}
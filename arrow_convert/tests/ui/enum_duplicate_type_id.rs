@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+enum E {
+    #[arrow_field(type_id = 1)]
+    A(i32),
+    B(String),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+enum E<T> {
+    A(T),
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(remote = "other::E")]
+enum E {
+    A(i32),
+}
+
+fn main() {}
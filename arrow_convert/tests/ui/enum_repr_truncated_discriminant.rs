@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(repr = "u8")]
+enum E {
+    A = 1,
+    B = 257,
+}
+
+fn main() {}
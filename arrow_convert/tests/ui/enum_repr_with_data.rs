@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(repr = "i8")]
+enum E {
+    A,
+    B(i32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(schema_metadata(table = "events"))]
+enum E {
+    A,
+    B,
+}
+
+fn main() {}
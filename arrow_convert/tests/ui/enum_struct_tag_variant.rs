@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(mode = "struct", rename_all = "lowercase")]
+enum E {
+    Value(i32),
+    Tag(String),
+}
+
+fn main() {}
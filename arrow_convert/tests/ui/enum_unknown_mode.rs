@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(mode = "packed")]
+enum E {
+    A(i32),
+    B,
+}
+
+fn main() {}
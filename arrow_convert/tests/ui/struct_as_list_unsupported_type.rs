@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(as_list)]
+    a: Vec<i32>,
+}
+
+fn main() {}
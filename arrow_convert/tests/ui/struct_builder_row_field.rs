@@ -0,0 +1,10 @@
+use arrow_convert::{ArrowField, ArrowSerialize};
+
+#[derive(ArrowField, ArrowSerialize)]
+#[arrow_field(builder)]
+struct S {
+    id: i32,
+    row: i64,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(computed(with = "key"))]
+struct S {
+    a: i32,
+}
+
+fn key(s: &S) -> String {
+    s.a.to_string()
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(decimal(precision = 10, scale = 2))]
+    price: f64,
+}
+
+fn main() {}
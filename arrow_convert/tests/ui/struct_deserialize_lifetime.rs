@@ -0,0 +1,8 @@
+use arrow_convert::{ArrowDeserialize, ArrowField};
+
+#[derive(ArrowField, ArrowDeserialize)]
+struct Row<'a> {
+    name: &'a str,
+}
+
+fn main() {}
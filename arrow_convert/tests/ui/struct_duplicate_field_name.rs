@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    a: i32,
+    #[arrow_field(name = "a")]
+    b: i32,
+}
+
+fn main() {}
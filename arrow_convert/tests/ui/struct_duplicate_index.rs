@@ -0,0 +1,11 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(index = 1)]
+    a: i32,
+    #[arrow_field(index = 1)]
+    b: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(metadata(unit = "ms", unit = "s"))]
+    a: i64,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use arrow_convert::ArrowField;
+
+struct NotArrow;
+
+#[derive(ArrowField)]
+struct S {
+    a: i32,
+    b: NotArrow,
+}
+
+fn main() {}
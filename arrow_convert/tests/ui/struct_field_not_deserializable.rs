@@ -0,0 +1,9 @@
+use arrow_convert::{ArrowDeserialize, ArrowField};
+
+#[derive(ArrowField, ArrowDeserialize)]
+struct S {
+    a: i32,
+    b: &'static str,
+}
+
+fn main() {}
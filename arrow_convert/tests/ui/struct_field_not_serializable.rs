@@ -0,0 +1,19 @@
+use arrow_convert::{ArrowField, ArrowSerialize};
+
+struct NotArrow;
+
+impl arrow_convert::field::ArrowField for NotArrow {
+    type Type = Self;
+
+    fn data_type() -> arrow::datatypes::DataType {
+        arrow::datatypes::DataType::Null
+    }
+}
+
+#[derive(ArrowField, ArrowSerialize)]
+struct S {
+    a: i32,
+    b: NotArrow,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(fixed_size = 3)]
+    a: [i32; 2],
+}
+
+fn main() {}
@@ -1,7 +1,9 @@
 use arrow_convert::ArrowField;
 
 #[derive(ArrowField)]
-struct S {}
+struct S {
+    #[arrow_field(skip)]
+    a: i32,
+}
 
-fn main() 
-{}
\ No newline at end of file
+fn main() {}
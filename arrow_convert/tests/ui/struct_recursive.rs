@@ -0,0 +1,15 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct Tree {
+    value: i64,
+    children: Vec<Box<Tree>>,
+}
+
+#[derive(ArrowField)]
+enum Expr {
+    Value(i64),
+    Neg(Box<Self>),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(run_end_encoded = "u8")]
+    a: String,
+}
+
+fn main() {}
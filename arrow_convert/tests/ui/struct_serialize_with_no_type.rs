@@ -0,0 +1,13 @@
+use arrow_convert::ArrowField;
+
+fn double(v: &i32) -> i32 {
+    v * 2
+}
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(serialize_with = "double")]
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(timestamp(unit = "minutes"))]
+    t: chrono::NaiveDateTime,
+}
+
+fn main() {}
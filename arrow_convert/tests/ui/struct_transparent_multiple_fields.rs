@@ -0,0 +1,10 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(transparent)]
+struct S {
+    a: i32,
+    b: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(deny_unkown_fields)]
+struct S {
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use arrow_convert::ArrowField;
+
+fn positive(v: &i32) -> Result<(), String> {
+    if *v > 0 {
+        Ok(())
+    } else {
+        Err(format!("{v} is not positive"))
+    }
+}
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(valdate = "positive")]
+    a: i32,
+}
+
+fn main() {}
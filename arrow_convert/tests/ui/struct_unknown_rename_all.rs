@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(rename_all = "Title Case")]
+struct S {
+    a: i32,
+}
+
+fn main() {}
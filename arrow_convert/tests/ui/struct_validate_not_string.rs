@@ -0,0 +1,9 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S {
+    #[arrow_field(validate = 5)]
+    a: i32,
+}
+
+fn main() {}
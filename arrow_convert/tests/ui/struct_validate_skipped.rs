@@ -0,0 +1,14 @@
+use arrow_convert::ArrowField;
+
+fn check(_v: &i32) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(ArrowField)]
+struct S {
+    a: i32,
+    #[arrow_field(skip, validate = "check")]
+    b: i32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use arrow_convert::ArrowField;
+
+#[derive(ArrowField)]
+#[arrow_field(view)]
+struct S {
+    a: i32,
+    #[arrow_field(default = "default_name")]
+    name: String,
+}
+
+fn default_name() -> String {
+    "unknown".to_string()
+}
+
+fn main() {}
@@ -0,0 +1,111 @@
+//! Case conversion for the `rename_all` container attribute.
+
+use self::RenameRule::*;
+
+/// The naming convention applied to all field or variant names of a container.
+#[allow(clippy::enum_variant_names)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum RenameRule {
+    /// Rename to lowercase.
+    LowerCase,
+    /// Rename to UPPERCASE.
+    UpperCase,
+    /// Rename to PascalCase.
+    PascalCase,
+    /// Rename to camelCase.
+    CamelCase,
+    /// Rename to snake_case.
+    SnakeCase,
+    /// Rename to SCREAMING_SNAKE_CASE.
+    ScreamingSnakeCase,
+    /// Rename to kebab-case.
+    KebabCase,
+    /// Rename to SCREAMING-KEBAB-CASE.
+    ScreamingKebabCase,
+}
+
+static RENAME_RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", LowerCase),
+    ("UPPERCASE", UpperCase),
+    ("PascalCase", PascalCase),
+    ("camelCase", CamelCase),
+    ("snake_case", SnakeCase),
+    ("SCREAMING_SNAKE_CASE", ScreamingSnakeCase),
+    ("kebab-case", KebabCase),
+    ("SCREAMING-KEBAB-CASE", ScreamingKebabCase),
+];
+
+impl RenameRule {
+    pub fn from_str(rule: &str) -> Option<RenameRule> {
+        RENAME_RULES
+            .iter()
+            .find(|(name, _)| *name == rule)
+            .map(|(_, rule)| *rule)
+    }
+
+    /// The supported rule names, used in error messages.
+    pub fn names() -> String {
+        RENAME_RULES
+            .iter()
+            .map(|(name, _)| format!("\"{name}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Applies the rule to a struct field name, which is expected to be in snake_case.
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            LowerCase | SnakeCase => field.to_owned(),
+            UpperCase | ScreamingSnakeCase => field.to_ascii_uppercase(),
+            PascalCase => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            CamelCase => lowercase_first(&PascalCase.apply_to_field(field)),
+            KebabCase => field.replace('_', "-"),
+            ScreamingKebabCase => ScreamingSnakeCase.apply_to_field(field).replace('_', "-"),
+        }
+    }
+
+    /// Applies the rule to an enum variant name, which is expected to be in PascalCase.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            PascalCase => variant.to_owned(),
+            LowerCase => variant.to_ascii_lowercase(),
+            UpperCase => variant.to_ascii_uppercase(),
+            CamelCase => lowercase_first(variant),
+            SnakeCase => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            ScreamingSnakeCase => SnakeCase.apply_to_variant(variant).to_ascii_uppercase(),
+            KebabCase => SnakeCase.apply_to_variant(variant).replace('_', "-"),
+            ScreamingKebabCase => ScreamingSnakeCase.apply_to_variant(variant).replace('_', "-"),
+        }
+    }
+}
+
+fn lowercase_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -3,7 +3,10 @@ use proc_macro_error2::abort;
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 
-use crate::input::{DeriveEnum, DeriveVariant};
+use crate::input::{DeriveEnum, DeriveVariant, ENUM_MODE_STRUCT};
+
+/// The name of the column holding the variant names of enums with `mode = "struct"`
+const STRUCT_TAG: &str = "tag";
 
 struct Common<'a> {
     original_name: &'a proc_macro2::Ident,
@@ -14,7 +17,19 @@ struct Common<'a> {
     variant_names: Vec<proc_macro2::Ident>,
     variant_names_str: Vec<syn::LitStr>,
     variant_indices: Vec<syn::LitInt>,
+    /// The union type id of each variant
+    variant_type_ids: Vec<syn::LitInt>,
     variant_types: Vec<&'a syn::Type>,
+    /// The union child field of each variant
+    variant_fields: Vec<TokenStream>,
+    /// The expression creating the child array builder of each variant
+    variant_new_arrays: Vec<TokenStream>,
+    /// The pattern matching each variant, binding its fields to `__f0`, `__f1`, ...
+    variant_patterns: Vec<TokenStream>,
+    /// The bindings of the fields of each variant, which are empty for unit variants
+    variant_bindings: Vec<Vec<proc_macro2::Ident>>,
+    /// The child field names of the struct and multi-field tuple variants, which are empty for other variants
+    variant_field_names: Vec<Vec<syn::LitStr>>,
 }
 
 impl<'a> From<&'a DeriveEnum> for Common<'a> {
@@ -37,27 +52,152 @@ impl<'a> From<&'a DeriveEnum> for Common<'a> {
             abort!(original_name.span(), "Expected enum to have at least one field");
         }
 
-        let variant_names_str = variant_names
+        let variant_names_str = variants
             .iter()
-            .map(|v| syn::LitStr::new(&format!("{v}"), proc_macro2::Span::call_site()))
+            .map(|v| {
+                let name = v.field_name.clone().unwrap_or_else(|| {
+                    let name = format!("{}", v.syn.ident);
+                    match input.rename_all {
+                        Some(rule) => rule.apply_to_variant(&name),
+                        None => name,
+                    }
+                });
+                syn::LitStr::new(&name, proc_macro2::Span::call_site())
+            })
             .collect::<Vec<_>>();
 
+        for (idx, name) in variant_names_str.iter().enumerate() {
+            if variant_names_str[..idx].iter().any(|n| n.value() == name.value()) {
+                abort!(
+                    variants[idx].syn.span(),
+                    "Duplicate field name `{}`",
+                    name.value()
+                );
+            }
+        }
+
+        if input.is_struct {
+            if let Some(idx) = variant_names_str.iter().position(|n| n.value() == STRUCT_TAG) {
+                abort!(
+                    variants[idx].syn.span(),
+                    "The variant name `{}` is used by the tag column of `mode = \"{}\"`",
+                    STRUCT_TAG,
+                    ENUM_MODE_STRUCT
+                );
+            }
+        }
+
         let variant_indices = variant_names
             .iter()
             .enumerate()
             .map(|(idx, _ident)| syn::LitInt::new(&format!("{idx}"), proc_macro2::Span::call_site()))
             .collect::<Vec<_>>();
 
+        let variant_type_ids = variants
+            .iter()
+            .zip(&variant_indices)
+            .map(|(v, idx)| {
+                v.type_id
+                    .clone()
+                    .unwrap_or_else(|| syn::LitInt::new(idx.base10_digits(), v.syn.ident.span()))
+            })
+            .collect::<Vec<_>>();
+        for (i, id) in variant_type_ids.iter().enumerate() {
+            let value = id.base10_parse::<i8>().unwrap();
+            if variant_type_ids[..i]
+                .iter()
+                .any(|other| other.base10_parse::<i8>().unwrap() == value)
+            {
+                abort!(id.span(), "Duplicate union type id {}", value);
+            }
+        }
+
         let variant_types: Vec<&syn::Type> = variants
             .iter()
             .map(|v| match &v.field_type {
                 syn::Type::Path(_) => &v.field_type,
                 syn::Type::Array(_) => &v.field_type,
                 syn::Type::Reference(_) => &v.field_type,
-                _ => panic!("Only `Path`, `Array`, `Reference` types are supported atm"),
+                syn::Type::Tuple(_) => &v.field_type,
+                _ => panic!("Only `Path`, `Array`, `Reference`, `Tuple` types are supported atm"),
             })
             .collect::<Vec<&syn::Type>>();
 
+        let mut variant_fields = vec![];
+        let mut variant_new_arrays = vec![];
+        let mut variant_patterns = vec![];
+        let mut variant_bindings = vec![];
+        let mut variant_field_names = vec![];
+        for ((v, name_str), variant_type) in variants.iter().zip(&variant_names_str).zip(&variant_types) {
+            let name = &v.syn.ident;
+            if v.is_unit {
+                variant_patterns.push(quote!(#original_name::#name));
+                variant_bindings.push(vec![]);
+            } else {
+                let members = match &v.syn.fields {
+                    syn::Fields::Named(f) => f
+                        .named
+                        .iter()
+                        .map(|f| syn::Member::Named(f.ident.clone().unwrap()))
+                        .collect::<Vec<_>>(),
+                    _ => (0..v.syn.fields.len())
+                        .map(|i| syn::Member::Unnamed(i.into()))
+                        .collect::<Vec<_>>(),
+                };
+                let bindings = (0..members.len())
+                    .map(|i| syn::Ident::new(&format!("__f{i}"), proc_macro2::Span::call_site()))
+                    .collect::<Vec<_>>();
+                variant_patterns.push(quote!(#original_name::#name { #(#members: #bindings),* }));
+                variant_bindings.push(bindings);
+            }
+
+            if v.fields.is_empty() {
+                variant_fields.push(quote!(<#variant_type as arrow_convert::field::ArrowField>::field(#name_str)));
+                variant_new_arrays
+                    .push(quote!(<#variant_type as arrow_convert::serialize::ArrowSerialize>::new_array()));
+                variant_field_names.push(vec![]);
+            } else {
+                // struct and multi-field tuple variants are stored as a struct of their fields
+                let field_types = v.fields.iter().map(|f| &f.field_type).collect::<Vec<_>>();
+                let field_names = v
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| {
+                        let name = f.field_name.clone().unwrap_or_else(|| match &f.syn.ident {
+                            Some(ident) => ident.to_string(),
+                            None => format!("{i}"),
+                        });
+                        syn::LitStr::new(&name, proc_macro2::Span::call_site())
+                    })
+                    .collect::<Vec<_>>();
+                for (idx, name) in field_names.iter().enumerate() {
+                    if field_names[..idx].iter().any(|n| n.value() == name.value()) {
+                        abort!(
+                            v.fields[idx].syn.span(),
+                            "Duplicate field name `{}`",
+                            name.value()
+                        );
+                    }
+                }
+                let struct_fields = quote! {
+                    arrow::datatypes::Fields::from(vec![
+                        #(<#field_types as arrow_convert::field::ArrowField>::field(#field_names),)*
+                    ])
+                };
+                variant_fields.push(quote! {
+                    arrow::datatypes::Field::new(#name_str, arrow::datatypes::DataType::Struct(#struct_fields), false)
+                });
+                variant_new_arrays.push(quote! {
+                    arrow_convert::serialize::TupleBuilder::new(
+                        #struct_fields,
+                        (#(<#field_types as arrow_convert::serialize::ArrowSerialize>::new_array(),)*),
+                    )
+                });
+                variant_field_names.push(field_names);
+            }
+        }
+
         Self {
             original_name,
             original_name_str,
@@ -67,25 +207,36 @@ impl<'a> From<&'a DeriveEnum> for Common<'a> {
             variant_names,
             variant_names_str,
             variant_indices,
+            variant_type_ids,
             variant_types,
+            variant_fields,
+            variant_new_arrays,
+            variant_patterns,
+            variant_bindings,
+            variant_field_names,
         }
     }
 }
 
 pub fn expand_field(input: DeriveEnum) -> TokenStream {
+    if input.is_dictionary {
+        return expand_dictionary_field(input);
+    }
+    if input.is_struct {
+        return expand_struct_field(&input);
+    }
+    if let Some(repr) = &input.repr {
+        return expand_repr_field(&input, repr);
+    }
+
     let Common {
         original_name,
         union_type,
-        variant_names_str,
-        variant_types,
+        variant_type_ids,
+        variant_fields,
         ..
     } = (&input).into();
 
-    let num_variants = syn::LitInt::new(
-        &format!("{}", variant_types.len()),
-        proc_macro2::Span::call_site(),
-    );
-
     quote! {
         impl arrow_convert::field::ArrowField for #original_name {
             type Type = Self;
@@ -93,10 +244,10 @@ pub fn expand_field(input: DeriveEnum) -> TokenStream {
             fn data_type() -> arrow::datatypes::DataType {
                 arrow::datatypes::DataType::Union(
                     arrow::datatypes::UnionFields::new(
-                      0..#num_variants, // basically union tag id or here called type_id
+                      [#(#variant_type_ids),*],
                       vec![
                           #(
-                              <#variant_types as arrow_convert::field::ArrowField>::field(#variant_names_str),
+                              #variant_fields,
                           )*
                       ]
                     ),
@@ -110,13 +261,26 @@ pub fn expand_field(input: DeriveEnum) -> TokenStream {
 }
 
 pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
+    if input.is_dictionary {
+        return expand_dictionary_serialize(input);
+    }
+    if input.is_struct {
+        return expand_struct_serialize(&input);
+    }
+    if let Some(repr) = &input.repr {
+        return expand_repr_serialize(&input, repr);
+    }
+
     let Common {
         original_name,
         visibility,
         variants,
         variant_names,
-        variant_indices,
+        variant_type_ids,
         variant_types,
+        variant_new_arrays,
+        variant_patterns,
+        variant_bindings,
         ..
     } = (&input).into();
 
@@ -150,85 +314,42 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
     };
 
     let try_push_match_blocks = variants
-            .iter()
-            .enumerate()
-            .zip(&variant_indices)
-            .zip(&variant_types)
-            .map(|(((idx, v), lit_idx), variant_type)| {
-                let name = &v.syn.ident;
-                // - For dense unions, update the mutable array of the matched variant and also the offset.
-                // - For sparse unions, update the mutable array of the matched variant, and push null for all
-                //   the other variants. This unfortunately results in some large code blocks per match arm.
-                //   There might be a better way of doing this.
-                if is_dense {
-                    let update_offset = quote! {
-                        self.type_ids.push(#lit_idx);
-                        self.offsets.push((self.#name.len() - 1) as i32);
-                    };
-                    if v.is_unit {
-                        quote! {
-                            #original_name::#name => {
-                                <#variant_type as ArrowSerialize>::arrow_serialize(&true, &mut self.#name)?;
-                                #update_offset
-                            }
-                        }
-                    }
-                    else {
-                        quote! {
-                            #original_name::#name(v) => {
-                                <#variant_type as ArrowSerialize>::arrow_serialize(v, &mut self.#name)?;
-                                #update_offset
-                            }
-                        }
+        .iter()
+        .enumerate()
+        .zip(&variant_type_ids)
+        .zip(&variant_types)
+        .zip(variant_patterns.iter().zip(&variant_bindings))
+        .map(|((((idx, v), type_id), variant_type), (pattern, bindings))| {
+            let name = &v.syn.ident;
+            let serialize = variant_serialize(v, variant_type, bindings);
+
+            // - For dense unions, update the mutable array of the matched variant and also the offset.
+            // - For sparse unions, update the mutable array of the matched variant, and push null for all
+            //   the other variants. This unfortunately results in some large code blocks per match arm.
+            //   There might be a better way of doing this.
+            if is_dense {
+                quote! {
+                    #pattern => {
+                        #serialize
+                        self.type_ids.push(#type_id);
+                        self.offsets.push((arrow::array::ArrayBuilder::len(&self.#name) - 1) as i32);
                     }
                 }
-                else {
-                    let push_none = variants
-                        .iter()
-                        .enumerate()
-                        .zip(&variant_types)
-                        .map(|((nested_idx,y), variant_type)| {
-                            let name = &y.syn.ident;
-                            if nested_idx != idx {
-                                quote! {
-                                    <<#variant_type as ArrowSerialize>::ArrayBuilderType as PushNull>::push_null(&mut self.#name);
-                                }
-                            }
-                            else {
-                                quote!{}
-                            }
-                        })
-                        .collect::<Vec<TokenStream>>();
-
-                    let update_offset = quote! {
-                        self.type_ids.push(#lit_idx);
-                    };
-
-                    if v.is_unit {
-                        quote! {
-                            #original_name::#name => {
-                                <#variant_type as ArrowSerialize>::arrow_serialize(&true, &mut self.#name)?;
-                                #(
-                                    #push_none
-                                )*
-                                #update_offset
-                            }
-                        }
-                    }
-                    else {
-                        quote! {
-                            #original_name::#name(v) => {
-                                <#variant_type as ArrowSerialize>::arrow_serialize(v, &mut self.#name)?;
-                                #(
-                                    #push_none
-                                )*
-                                #update_offset
-                            }
-                        }
+            } else {
+                let push_none = push_null_except(variants, &variant_types, idx);
+
+                quote! {
+                    #pattern => {
+                        #serialize
+                        #(
+                            #push_none
+                        )*
+                        self.type_ids.push(#type_id);
                     }
                 }
-            })
-            .collect::<Vec<TokenStream>>();
+            }
+        })
+        .collect::<Vec<TokenStream>>();
 
     let array_decl = quote! {
         #[allow(non_snake_case)]
@@ -247,7 +368,7 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
         impl #mutable_array_name {
             pub fn new() -> Self {
                 Self {
-                    #(#variant_names: <#variant_types as arrow_convert::serialize::ArrowSerialize>::new_array(),)*
+                    #(#variant_names: #variant_new_arrays,)*
                     data_type: <#original_name as arrow_convert::field::ArrowField>::data_type(),
                     type_ids: vec![],
                     #offsets_init
@@ -305,17 +426,18 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
         }
     };
 
+    let first_type_id = &variant_type_ids[0];
     let push_null_impl = if is_dense {
         let first_array_type = &mutable_variant_array_types[0];
         let first_name = &variant_names[0];
         quote! {
-            self.type_ids.push(0);
-            self.offsets.push((self.#first_name.len()) as i32);
+            self.type_ids.push(#first_type_id);
+            self.offsets.push(arrow::array::ArrayBuilder::len(&self.#first_name) as i32);
             <#first_array_type as PushNull>::push_null(&mut self.#first_name);
         }
     } else {
         quote! {
-            self.type_ids.push(0);
+            self.type_ids.push(#first_type_id);
             #(
                 <#mutable_variant_array_types as PushNull>::push_null(&mut self.#variant_names);
             )*
@@ -429,49 +551,61 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
 }
 
 pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
+    if input.is_dictionary {
+        return expand_dictionary_deserialize(input);
+    }
+    if input.is_struct {
+        return expand_struct_deserialize(&input);
+    }
+    if let Some(repr) = &input.repr {
+        return expand_repr_deserialize(&input, repr);
+    }
+
     let Common {
         original_name,
         original_name_str,
         visibility,
         variants,
-        variant_indices,
+        variant_type_ids,
+        variant_names_str,
         variant_types,
+        variant_patterns,
+        variant_bindings,
+        variant_field_names,
         ..
     } = (&input).into();
 
     let array_name = &input.common.array_name();
     let iterator_name = &input.common.iterator_name();
+    let num_variants = syn::LitInt::new(&format!("{}", variants.len()), proc_macro2::Span::call_site());
 
-    // For unit variants, return the variant directly. For non-unit variants, get the slice of the underlying field array
-    // and deserialize to the variant type.
-    let iter_next_match_block = {
-        let candidates = variants.iter()
-                    .zip(&variant_indices)
-                    .zip(&variant_types)
-                    .map(|((v, lit_idx), variant_type)| {
-                        let name = &v.syn.ident;
-                        if v.is_unit {
-                            quote! {
-                                #lit_idx => {
-                                    Some(Some(#original_name::#name))
-                                }
-                            }
-                        }
-                        else {
-                            quote! {
-                                #lit_idx => {
-                                    let mut slice_iter = <<#variant_type as arrow_convert::deserialize::ArrowDeserialize> ::ArrayType as arrow_convert::deserialize::ArrowArray> ::iter_from_array_ref(slice.deref());
-                                    let v = slice_iter
-                                        .next()
-                                        .unwrap_or_else(|| panic!("Invalid offset for {}", #lit_idx));
-                                    Some(<#variant_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(|v| #original_name::#name(v)))
-                                }
-                            }
-                        }
-                    })
-                    .collect::<Vec<TokenStream>>();
-        quote! { #(#candidates)* }
-    };
+    let variant_checks = variant_checks(variants, &variant_types);
+    let validate_impl = validate_impl(
+        variants,
+        &variant_names_str,
+        &variant_types,
+        &variant_patterns,
+        &variant_bindings,
+        &variant_field_names,
+    );
+    let validate_children = validate_children(
+        variants,
+        &variant_names_str,
+        &variant_types,
+        &variant_field_names,
+        |idx| {
+            let type_id = &variant_type_ids[idx];
+            quote!(array.child(#type_id))
+        },
+    );
+    let iter_next_match_block = variant_deserialize_arms(
+        false,
+        variants,
+        &variant_type_ids,
+        &variant_types,
+        &variant_patterns,
+        &variant_bindings,
+    );
 
     let array_decl = quote! {
         #visibility struct #array_name
@@ -526,10 +660,13 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
                 let next_index = self.index_iter.next()?;
                 let type_idx = self.arr.type_id(next_index);
                 let offset = self.arr.value_offset(next_index);
+                if ![#(#variant_type_ids),*].contains(&type_idx) {
+                    panic!("Invalid type id {} deserializing `{}`", type_idx, #original_name_str);
+                }
                 let slice = self.arr.child(type_idx).slice(offset, 1);
                 match type_idx {
                     #iter_next_match_block
-                    _ => panic!("Invalid type for {}", #original_name_str)
+                    _ => unreachable!(),
                 }
             }
         }
@@ -543,6 +680,42 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
             fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
                 v
             }
+
+            fn is_deserializable_from(data_type: &arrow::datatypes::DataType) -> bool {
+                let arrow::datatypes::DataType::Union(fields, mode) = data_type else {
+                    return false;
+                };
+                let arrow::datatypes::DataType::Union(expected_fields, expected_mode) =
+                    <Self as arrow_convert::field::ArrowField>::data_type()
+                else {
+                    unreachable!()
+                };
+                let checks: [fn(&arrow::datatypes::Field, &arrow::datatypes::Field) -> bool; #num_variants] = [
+                    #(#variant_checks,)*
+                ];
+                // the type ids select the variant when deserializing
+                mode == &expected_mode
+                    && fields.len() == expected_fields.len()
+                    && fields
+                        .iter()
+                        .zip(expected_fields.iter())
+                        .zip(checks)
+                        .all(|(((type_id, field), (expected_type_id, expected)), check)| {
+                            type_id == expected_type_id && field.name() == expected.name() && check(field, expected)
+                        })
+            }
+
+            fn validate_array(array: &dyn arrow::array::Array) -> std::result::Result<(), String> {
+                use arrow::array::AsArray;
+
+                let Some(array) = array.as_union_opt() else {
+                    return Ok(());
+                };
+                #(#validate_children)*
+                Ok(())
+            }
+
+            #validate_impl
         }
     };
 
@@ -555,3 +728,767 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
         field_arrow_deserialize_impl,
     ])
 }
+
+// Returns the serialization of the bound fields of `v` to the child array builder of the variant.
+fn variant_serialize(v: &DeriveVariant, variant_type: &syn::Type, bindings: &[proc_macro2::Ident]) -> TokenStream {
+    let name = &v.syn.ident;
+    if v.is_unit {
+        quote! {
+            <#variant_type as ArrowSerialize>::arrow_serialize(&true, &mut self.#name)?;
+        }
+    } else if v.fields.is_empty() {
+        let binding = &bindings[0];
+        quote! {
+            <#variant_type as ArrowSerialize>::arrow_serialize(#binding, &mut self.#name)?;
+        }
+    } else {
+        let field_types = v.fields.iter().map(|f| &f.field_type);
+        let field_indices = (0..bindings.len()).map(syn::Index::from);
+        quote! {
+            let values = self.#name.values();
+            #(
+                <#field_types as ArrowSerialize>::arrow_serialize(#bindings, &mut values.#field_indices)?;
+            )*
+            self.#name.append(true);
+        }
+    }
+}
+
+// Returns the statements pushing a null to the child array builders of all the variants except the one at `idx`.
+fn push_null_except(variants: &[DeriveVariant], variant_types: &[&syn::Type], idx: usize) -> Vec<TokenStream> {
+    variants
+        .iter()
+        .enumerate()
+        .zip(variant_types)
+        .filter(|((nested_idx, _), _)| *nested_idx != idx)
+        .map(|((_, v), variant_type)| {
+            let name = &v.syn.ident;
+            quote! {
+                <<#variant_type as ArrowSerialize>::ArrayBuilderType as PushNull>::push_null(&mut self.#name);
+            }
+        })
+        .collect()
+}
+
+// Returns the checks of the child fields of the variants. Variants holding a single value delegate to the value type,
+// others must match exactly.
+fn variant_checks(variants: &[DeriveVariant], variant_types: &[&syn::Type]) -> Vec<TokenStream> {
+    variants
+        .iter()
+        .zip(variant_types)
+        .map(|(v, variant_type)| {
+            if v.is_unit || !v.fields.is_empty() {
+                quote!(|field, expected| field.data_type() == expected.data_type())
+            } else {
+                quote!(|field, _| <#variant_type as arrow_convert::deserialize::ArrowDeserialize>::is_deserializable_from(field.data_type()))
+            }
+        })
+        .collect()
+}
+
+// Returns the implementation of `ArrowDeserialize::validate`, which validates the values of the variants, prefixing
+// violations with the variant name, and with the field name for struct and multi-field tuple variants.
+fn validate_impl(
+    variants: &[DeriveVariant],
+    variant_names_str: &[syn::LitStr],
+    variant_types: &[&syn::Type],
+    variant_patterns: &[TokenStream],
+    variant_bindings: &[Vec<proc_macro2::Ident>],
+    variant_field_names: &[Vec<syn::LitStr>],
+) -> TokenStream {
+    let arms = variants
+        .iter()
+        .zip(variant_names_str)
+        .zip(variant_types)
+        .zip(variant_patterns.iter().zip(variant_bindings).zip(variant_field_names))
+        .map(
+            |(((v, name_str), variant_type), ((pattern, bindings), field_names))| {
+                if v.is_unit {
+                    quote!(#pattern => Ok(()),)
+                } else if v.fields.is_empty() {
+                    let binding = &bindings[0];
+                    quote! {
+                        #pattern => <#variant_type as arrow_convert::deserialize::ArrowDeserialize>::validate(#binding)
+                            .map_err(|e| format!("{}: {}", #name_str, e)),
+                    }
+                } else {
+                    let field_types = v.fields.iter().map(|f| &f.field_type);
+                    quote! {
+                        #pattern => {
+                            #(
+                                <#field_types as arrow_convert::deserialize::ArrowDeserialize>::validate(#bindings)
+                                    .map_err(|e| format!("{}: {}: {}", #name_str, #field_names, e))?;
+                            )*
+                            Ok(())
+                        }
+                    }
+                }
+            },
+        );
+
+    quote! {
+        fn validate(v: &Self) -> std::result::Result<(), String> {
+            match v {
+                #(#arms)*
+            }
+        }
+    }
+}
+
+// Returns the statements checking the child array of each variant, which is bound to `child` by `child_array`,
+// prefixing violations like `validate_impl`. Unit variants are skipped as their children only hold nulls and `true`.
+fn validate_children(
+    variants: &[DeriveVariant],
+    variant_names_str: &[syn::LitStr],
+    variant_types: &[&syn::Type],
+    variant_field_names: &[Vec<syn::LitStr>],
+    child_array: impl Fn(usize) -> TokenStream,
+) -> Vec<TokenStream> {
+    variants
+        .iter()
+        .enumerate()
+        .zip(variant_names_str)
+        .zip(variant_types.iter().zip(variant_field_names))
+        .filter(|(((_, v), _), _)| !v.is_unit)
+        .map(|(((idx, v), name_str), (variant_type, field_names))| {
+            let child = child_array(idx);
+            if v.fields.is_empty() {
+                quote! {
+                    let child = #child;
+                    <#variant_type as arrow_convert::deserialize::ArrowDeserialize>::validate_array(child.as_ref())
+                        .map_err(|e| format!("{}: {}", #name_str, e))?;
+                }
+            } else {
+                // multi-field variants are stored as a struct of their fields
+                let field_types = v.fields.iter().map(|f| &f.field_type);
+                let field_indices = 0..v.fields.len();
+                quote! {
+                    let child = #child;
+                    if let Some(child) = child.as_struct_opt() {
+                        #(
+                            <#field_types as arrow_convert::deserialize::ArrowDeserialize>::validate_array(child.column(#field_indices).as_ref())
+                                .map_err(|e| format!("{}: {}: {}", #name_str, #field_names, e))?;
+                        )*
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+// Returns the implementation of `ArrowDeserialize::validate_array` for enums stored as the codes of their variants,
+// which rejects unknown codes unless there's a fallback variant. `codes` is the type deserializing the codes.
+fn validate_codes_impl(input: &DeriveEnum, codes: TokenStream, known: &[TokenStream]) -> TokenStream {
+    if input.variants.iter().any(|v| v.is_fallback) {
+        return quote! {};
+    }
+    let original_name_str = input.common.name.to_string();
+    quote! {
+        fn validate_array(array: &dyn arrow::array::Array) -> std::result::Result<(), String> {
+            use arrow_convert::deserialize::ArrowArray;
+
+            let codes = <<#codes as arrow_convert::deserialize::ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(array);
+            for code in codes.flatten() {
+                if !(#(code == #known)||*) {
+                    return Err(format!("Unknown variant `{}` deserializing `{}`", code, #original_name_str));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+// Returns the match arms deserializing the variant at each index from `slice`, the single value slice of its child
+// array. Unit variants are returned directly, other variants are deserialized from the variant type.
+//
+// Null values of union children are deserialized to a null enum, as nulls are pushed to the first child. With
+// `nullable_values`, the nulls are stored separately and a null value is passed to the variant instead, so that
+// variants holding an `Option` can be read back.
+fn variant_deserialize_arms(
+    nullable_values: bool,
+    variants: &[DeriveVariant],
+    variant_indices: &[syn::LitInt],
+    variant_types: &[&syn::Type],
+    variant_patterns: &[TokenStream],
+    variant_bindings: &[Vec<proc_macro2::Ident>],
+) -> TokenStream {
+    let candidates = variants
+        .iter()
+        .zip(variant_indices)
+        .zip(variant_types)
+        .zip(variant_patterns.iter().zip(variant_bindings))
+        .map(|(((v, lit_idx), variant_type), (pattern, bindings))| {
+            if v.is_unit {
+                quote! {
+                    #lit_idx => {
+                        Some(Some(#pattern))
+                    }
+                }
+            } else {
+                // multi-field variants are deserialized from a tuple of their fields
+                let mapper = if v.fields.is_empty() {
+                    quote!(|#(#bindings)*| #pattern)
+                } else {
+                    quote!(|(#(#bindings,)*)| #pattern)
+                };
+                let deserialize = if nullable_values {
+                    quote!(Some(Some((#mapper)(<#variant_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(v)))))
+                } else {
+                    quote!(Some(<#variant_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(#mapper)))
+                };
+                quote! {
+                    #lit_idx => {
+                        let mut slice_iter = <<#variant_type as arrow_convert::deserialize::ArrowDeserialize> ::ArrayType as arrow_convert::deserialize::ArrowArray> ::iter_from_array_ref(slice.deref());
+                        let v = slice_iter
+                            .next()
+                            .unwrap_or_else(|| panic!("Invalid offset for {}", #lit_idx));
+                        #deserialize
+                    }
+                }
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+    quote! { #(#candidates)* }
+}
+
+// Unit-only enums with `mode = "dictionary"` are stored as a `Dictionary<Int32, Utf8>` of their variant names.
+
+fn expand_dictionary_field(input: DeriveEnum) -> TokenStream {
+    let Common { original_name, .. } = (&input).into();
+
+    quote! {
+        impl arrow_convert::field::ArrowField for #original_name {
+            type Type = Self;
+
+            fn data_type() -> arrow::datatypes::DataType {
+                <arrow_convert::field::Dictionary<i32, String> as arrow_convert::field::ArrowField>::data_type()
+            }
+        }
+
+        arrow_convert::arrow_enable_vec_for_type!(#original_name);
+    }
+}
+
+fn expand_dictionary_serialize(input: DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        variant_patterns,
+        variant_names_str,
+        ..
+    } = (&input).into();
+
+    quote! {
+        impl arrow_convert::serialize::ArrowSerialize for #original_name {
+            type ArrayBuilderType = <arrow_convert::field::Dictionary<i32, String> as arrow_convert::serialize::ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                <arrow_convert::field::Dictionary<i32, String> as arrow_convert::serialize::ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                let name = match v {
+                    #(#variant_patterns => #variant_names_str,)*
+                };
+                array.inner().append(name)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+fn expand_dictionary_deserialize(input: DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        variant_patterns,
+        variant_names_str,
+        ..
+    } = (&input).into();
+
+    let unknown = unknown_variant(&input, quote!(name));
+    let known = variant_names_str.iter().map(|name| quote!(#name)).collect::<Vec<_>>();
+    let validate_array_impl = validate_codes_impl(
+        &input,
+        quote!(arrow_convert::field::Dictionary<i32, String>),
+        &known,
+    );
+
+    quote! {
+        impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
+            type ArrayType = <arrow_convert::field::Dictionary<i32, String> as arrow_convert::deserialize::ArrowDeserialize>::ArrayType;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<String>) -> Option<Self> {
+                v.map(|name| match name.as_str() {
+                    #(#variant_names_str => #variant_patterns,)*
+                    _ => #unknown,
+                })
+            }
+
+            #validate_array_impl
+        }
+    }
+}
+
+// Returns the value of unknown names or codes, which is either the fallback variant or a panic. Without a fallback
+// variant, `validate_array` rejects the unknown names and codes before deserializing.
+fn unknown_variant(input: &DeriveEnum, value: TokenStream) -> TokenStream {
+    let original_name = &input.common.name;
+    let original_name_str = original_name.to_string();
+    match input.variants.iter().find(|v| v.is_fallback) {
+        Some(v) => {
+            let name = &v.syn.ident;
+            quote!(#original_name::#name)
+        }
+        None => quote!(panic!("Unknown variant `{}` deserializing `{}`", #value, #original_name_str)),
+    }
+}
+
+// Unit-only enums with `repr` are stored as the integer value of their discriminants.
+
+fn expand_repr_field(input: &DeriveEnum, repr: &syn::Type) -> TokenStream {
+    let original_name = &input.common.name;
+
+    // a discriminant that doesn't fit in the repr type would be truncated, and could be read as another variant
+    let discriminant_checks = input.variants.iter().map(|variant| {
+        let name = &variant.syn.ident;
+        let message = format!(
+            "The discriminant of `{}::{}` doesn't fit in the `repr` type `{}`",
+            original_name,
+            name,
+            quote!(#repr)
+        );
+        quote_spanned! {variant.syn.span() =>
+            const _: () = assert!(
+                #original_name::#name as i128 == (#original_name::#name as #repr) as i128,
+                #message
+            );
+        }
+    });
+
+    quote! {
+        #(#discriminant_checks)*
+
+        impl arrow_convert::field::ArrowField for #original_name {
+            type Type = Self;
+
+            fn data_type() -> arrow::datatypes::DataType {
+                <#repr as arrow_convert::field::ArrowField>::data_type()
+            }
+        }
+
+        arrow_convert::arrow_enable_vec_for_type!(#original_name);
+    }
+}
+
+fn expand_repr_serialize(input: &DeriveEnum, repr: &syn::Type) -> TokenStream {
+    let Common {
+        original_name,
+        variant_patterns,
+        ..
+    } = input.into();
+
+    quote! {
+        impl arrow_convert::serialize::ArrowSerialize for #original_name {
+            type ArrayBuilderType = <#repr as arrow_convert::serialize::ArrowSerialize>::ArrayBuilderType;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                <#repr as arrow_convert::serialize::ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                let code = match v {
+                    #(#variant_patterns => #variant_patterns as #repr,)*
+                };
+                <#repr as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(&code, array)
+            }
+        }
+    }
+}
+
+fn expand_repr_deserialize(input: &DeriveEnum, repr: &syn::Type) -> TokenStream {
+    let Common {
+        original_name,
+        variant_patterns,
+        ..
+    } = input.into();
+
+    let unknown = unknown_variant(input, quote!(code));
+    let known = variant_patterns
+        .iter()
+        .map(|pattern| quote!(#pattern as #repr))
+        .collect::<Vec<_>>();
+    let validate_array_impl = validate_codes_impl(input, quote!(#repr), &known);
+
+    quote! {
+        impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
+            type ArrayType = <#repr as arrow_convert::deserialize::ArrowDeserialize>::ArrayType;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<#repr>) -> Option<Self> {
+                v.map(|code| match code {
+                    #(code if code == #variant_patterns as #repr => #variant_patterns,)*
+                    _ => #unknown,
+                })
+            }
+
+            #validate_array_impl
+        }
+    }
+}
+
+// Enums with `mode = "struct"` are stored as a struct of a tag column with the name of the variant, and a nullable
+// column per variant with the same type as its union child, which is null for the rows of the other variants.
+
+fn expand_struct_field(input: &DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        variant_fields,
+        ..
+    } = input.into();
+
+    quote! {
+        impl arrow_convert::field::ArrowField for #original_name {
+            type Type = Self;
+
+            fn data_type() -> arrow::datatypes::DataType {
+                arrow::datatypes::DataType::Struct(arrow::datatypes::Fields::from(vec![
+                    arrow::datatypes::Field::new(#STRUCT_TAG, arrow::datatypes::DataType::Utf8, false),
+                    #(
+                        (#variant_fields).with_nullable(true),
+                    )*
+                ]))
+            }
+        }
+
+        arrow_convert::arrow_enable_vec_for_type!(#original_name);
+    }
+}
+
+fn expand_struct_serialize(input: &DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        visibility,
+        variants,
+        variant_names,
+        variant_names_str,
+        variant_types,
+        variant_new_arrays,
+        variant_patterns,
+        variant_bindings,
+        ..
+    } = input.into();
+
+    let mutable_array_name = &input.common.mutable_array_name();
+    let mutable_variant_array_types = variant_types
+        .iter()
+        .map(|field_type| quote_spanned!( field_type.span() => <#field_type as arrow_convert::serialize::ArrowSerialize>::ArrayBuilderType))
+        .collect::<Vec<TokenStream>>();
+
+    let try_push_match_blocks = variants
+        .iter()
+        .enumerate()
+        .zip(&variant_names_str)
+        .zip(&variant_types)
+        .zip(variant_patterns.iter().zip(&variant_bindings))
+        .map(|((((idx, v), name_str), variant_type), (pattern, bindings))| {
+            let serialize = variant_serialize(v, variant_type, bindings);
+            let push_none = push_null_except(variants, &variant_types, idx);
+            quote! {
+                #pattern => {
+                    #serialize
+                    #(
+                        #push_none
+                    )*
+                    self.tag.append_value(#name_str);
+                }
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    quote! {
+        #[allow(non_snake_case)]
+        #[derive(Debug)]
+        #visibility struct #mutable_array_name {
+            tag: arrow::array::StringBuilder,
+            #(
+                #variant_names: #mutable_variant_array_types,
+            )*
+            validity: arrow::array::NullBufferBuilder,
+        }
+
+        impl #mutable_array_name {
+            pub fn new() -> Self {
+                Self {
+                    tag: arrow::array::StringBuilder::new(),
+                    #(#variant_names: #variant_new_arrays,)*
+                    validity: arrow::array::NullBufferBuilder::new(0),
+                }
+            }
+
+            fn try_push(&mut self, item: Option<impl std::borrow::Borrow<#original_name>>) -> arrow::error::Result<()> {
+                use arrow_convert::serialize::{ArrowSerialize, PushNull};
+                match item {
+                    Some(i) => {
+                        match i.borrow() {
+                            #(
+                                #try_push_match_blocks
+                            )*
+                        }
+                        self.validity.append_non_null();
+                    },
+                    None => {
+                        <Self as arrow_convert::serialize::PushNull>::push_null(self);
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl arrow_convert::serialize::PushNull for #mutable_array_name {
+            fn push_null(&mut self) {
+                use arrow_convert::serialize::PushNull;
+                self.tag.append_null();
+                #(
+                    <#mutable_variant_array_types as PushNull>::push_null(&mut self.#variant_names);
+                )*
+                self.validity.append_null();
+            }
+        }
+
+        impl Default for #mutable_array_name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl arrow::array::ArrayBuilder for #mutable_array_name {
+            fn len(&self) -> usize {
+                self.tag.len()
+            }
+
+            fn finish(&mut self) -> arrow::array::ArrayRef {
+                let arrow::datatypes::DataType::Struct(fields) =
+                    <#original_name as arrow_convert::field::ArrowField>::data_type()
+                else {
+                    panic!("datatype is not struct")
+                };
+
+                let children: Vec<arrow::array::ArrayRef> = vec![
+                    std::sync::Arc::new(self.tag.finish()),
+                    #(
+                        <#mutable_variant_array_types as arrow::array::ArrayBuilder>::finish(&mut self.#variant_names),
+                    )*
+                ];
+
+                std::sync::Arc::new(arrow::array::StructArray::new(fields, children, self.validity.finish()))
+            }
+
+            fn finish_cloned(&self) -> arrow::array::ArrayRef {
+                let arrow::datatypes::DataType::Struct(fields) =
+                    <#original_name as arrow_convert::field::ArrowField>::data_type()
+                else {
+                    panic!("datatype is not struct")
+                };
+
+                let children: Vec<arrow::array::ArrayRef> = vec![
+                    std::sync::Arc::new(self.tag.finish_cloned()),
+                    #(
+                        <#mutable_variant_array_types as arrow::array::ArrayBuilder>::finish_cloned(&self.#variant_names),
+                    )*
+                ];
+
+                std::sync::Arc::new(arrow::array::StructArray::new(fields, children, self.validity.finish_cloned()))
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+                self
+            }
+
+            fn into_box_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+                self
+            }
+        }
+
+        impl arrow_convert::serialize::ArrowSerialize for #original_name {
+            type ArrayBuilderType = #mutable_array_name;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::default()
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                array.try_push(Some(v))
+            }
+        }
+    }
+}
+
+fn expand_struct_deserialize(input: &DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        original_name_str,
+        visibility,
+        variants,
+        variant_names_str,
+        variant_indices,
+        variant_types,
+        variant_patterns,
+        variant_bindings,
+        variant_field_names,
+        ..
+    } = input.into();
+
+    let array_name = &input.common.array_name();
+    let iterator_name = &input.common.iterator_name();
+    let num_variants = syn::LitInt::new(&format!("{}", variants.len()), proc_macro2::Span::call_site());
+    let variant_checks = variant_checks(variants, &variant_types);
+    let validate_impl = validate_impl(
+        variants,
+        &variant_names_str,
+        &variant_types,
+        &variant_patterns,
+        &variant_bindings,
+        &variant_field_names,
+    );
+    // the first column holds the tags
+    let validate_children = validate_children(
+        variants,
+        &variant_names_str,
+        &variant_types,
+        &variant_field_names,
+        |idx| quote!(array.column(#idx + 1)),
+    );
+    let iter_next_match_block = variant_deserialize_arms(
+        true,
+        variants,
+        &variant_indices,
+        &variant_types,
+        &variant_patterns,
+        &variant_bindings,
+    );
+
+    quote! {
+        #visibility struct #array_name
+        {}
+
+        impl arrow_convert::deserialize::ArrowArray for #array_name
+        {
+            type BaseArrayType = arrow::array::StructArray;
+
+            #[inline]
+            fn iter_from_array_ref<'a>(b: &'a dyn arrow::array::Array)  -> <Self as arrow_convert::deserialize::ArrowArrayIterable>::Iter<'a>
+            {
+                let arr = b.as_any().downcast_ref::<arrow::array::StructArray>().unwrap();
+                let tags = arr.column(0).as_any().downcast_ref::<arrow::array::StringArray>().unwrap();
+
+                #iterator_name {
+                    arr,
+                    tags,
+                    index_iter: 0..arrow::array::Array::len(&arr),
+                }
+            }
+        }
+
+        impl arrow_convert::deserialize::ArrowArrayIterable for #array_name
+        {
+            type Item<'a> = Option<#original_name>;
+            type Iter<'a> = #iterator_name<'a>;
+
+            fn iter(&self) -> Self::Iter<'_> {
+                unimplemented!("Use iter_from_array_ref");
+            }
+        }
+
+        #visibility struct #iterator_name<'a> {
+            arr: &'a arrow::array::StructArray,
+            tags: &'a arrow::array::StringArray,
+            index_iter: std::ops::Range<usize>,
+        }
+
+        impl<'a> Iterator for #iterator_name<'a> {
+            type Item = Option<#original_name>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                use arrow::array::Array;
+                use core::ops::Deref;
+                let next_index = self.index_iter.next()?;
+                if self.arr.is_null(next_index) || self.tags.is_null(next_index) {
+                    return Some(None);
+                }
+                let type_idx = match self.tags.value(next_index) {
+                    #(#variant_names_str => #variant_indices,)*
+                    name => panic!("Unknown variant `{}` deserializing `{}`", name, #original_name_str),
+                };
+                // the first column holds the tags
+                let slice = self.arr.column(type_idx + 1).slice(next_index, 1);
+                match type_idx {
+                    #iter_next_match_block
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
+            type ArrayType = #array_name;
+
+            #[inline]
+            fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
+                v
+            }
+
+            fn is_deserializable_from(data_type: &arrow::datatypes::DataType) -> bool {
+                let arrow::datatypes::DataType::Struct(fields) = data_type else {
+                    return false;
+                };
+                let arrow::datatypes::DataType::Struct(expected_fields) =
+                    <Self as arrow_convert::field::ArrowField>::data_type()
+                else {
+                    unreachable!()
+                };
+                let checks: [fn(&arrow::datatypes::Field, &arrow::datatypes::Field) -> bool; #num_variants] = [
+                    #(#variant_checks,)*
+                ];
+                // the columns of the variants are read by position after the tag column
+                fields.len() == expected_fields.len()
+                    && fields[0].name() == expected_fields[0].name()
+                    && fields[0].data_type() == &arrow::datatypes::DataType::Utf8
+                    && fields
+                        .iter()
+                        .skip(1)
+                        .zip(expected_fields.iter().skip(1))
+                        .zip(checks)
+                        .all(|((field, expected), check)| field.name() == expected.name() && check(field, expected))
+            }
+
+            fn validate_array(array: &dyn arrow::array::Array) -> std::result::Result<(), String> {
+                use arrow::array::{Array, AsArray};
+
+                let Some(array) = array.as_struct_opt() else {
+                    return Ok(());
+                };
+                let tags = array.column(0).as_string::<i32>();
+                for idx in (0..array.len()).filter(|idx| array.is_valid(*idx) && tags.is_valid(*idx)) {
+                    let name = tags.value(idx);
+                    if ![#(#variant_names_str),*].contains(&name) {
+                        return Err(format!("Unknown variant `{}` deserializing `{}`", name, #original_name_str));
+                    }
+                }
+                #(#validate_children)*
+                Ok(())
+            }
+
+            #validate_impl
+        }
+    }
+}
@@ -2,195 +2,939 @@ use proc_macro2::TokenStream;
 use proc_macro_error2::abort;
 use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
 
 use super::input::*;
 
-struct Common<'a> {
-    original_name: &'a proc_macro2::Ident,
-    visibility: &'a syn::Visibility,
-    field_members: Vec<syn::Member>,
-    field_idents: Vec<syn::Ident>,
-    skipped_field_names: Vec<syn::Member>,
-    field_indices: Vec<syn::LitInt>,
-    field_types: Vec<&'a syn::Type>,
-    field_names: Vec<String>,
+/// A subset of the struct fields
+#[derive(Default)]
+struct FieldSet<'a> {
+    members: Vec<syn::Member>,
+    idents: Vec<syn::Ident>,
+    types: Vec<&'a syn::Type>,
+    names: Vec<String>,
+    fields: Vec<&'a DeriveField>,
 }
 
-impl<'a> From<&'a DeriveStruct> for Common<'a> {
-    fn from(input: &'a DeriveStruct) -> Self {
-        let original_name = &input.common.name;
-        let visibility = &input.common.visibility;
+impl<'a> FieldSet<'a> {
+    fn push(&mut self, member: syn::Member, field: &'a DeriveField, field_type: &'a syn::Type, name: String) {
+        self.idents.push(match &member {
+            // computed columns are named after their function, which may also be the name of a field
+            syn::Member::Named(ident) if field.computed.is_some() => format_ident!("computed_{}", ident),
+            // `Member` doesn't impl `IdentFragment` in a way that preserves the "r#" prefix stripping of `Ident`, so we go one level inside.
+            syn::Member::Named(ident) => format_ident!("field_{}", ident),
+            syn::Member::Unnamed(index) => format_ident!("field_{}", index),
+        });
+        self.members.push(member);
+        self.types.push(field_type);
+        self.names.push(name);
+        self.fields.push(field);
+    }
 
-        let (skipped_fields, fields): (Vec<_>, Vec<_>) = input.fields.iter().partition(|field| field.skip);
-        if fields.is_empty() {
-            abort!(
-                original_name.span(),
-                "Expected struct to have at least one field"
-            );
+    /// Returns the fields at the positions in `order`.
+    fn reordered(&self, order: &[usize]) -> Self {
+        Self {
+            members: order.iter().map(|&i| self.members[i].clone()).collect(),
+            idents: order.iter().map(|&i| self.idents[i].clone()).collect(),
+            types: order.iter().map(|&i| self.types[i]).collect(),
+            names: order.iter().map(|&i| self.names[i].clone()).collect(),
+            fields: order.iter().map(|&i| self.fields[i]).collect(),
         }
+    }
 
-        let field_members = fields
+    /// Returns the expressions converting each field to a reference to the `Type` of the field type, given an
+    /// expression referencing the struct.
+    fn serialize_values(&self, value: TokenStream) -> Vec<TokenStream> {
+        self.fields
             .iter()
-            .enumerate()
-            .map(|(id, field)| {
-                field
-                    .syn
-                    .ident
-                    .as_ref()
-                    .cloned()
-                    .map_or_else(|| syn::Member::Unnamed(id.into()), syn::Member::Named)
-            })
-            .collect::<Vec<_>>();
+            .zip(&self.members)
+            .zip(&self.types)
+            .map(
+                |((field, member), ty)| match (&field.computed, &field.serialize_with) {
+                    (Some(f), _) => quote!(&#f(#value)),
+                    (None, Some(f)) => quote!(&#f(&#value.#member)),
+                    (None, None) => quote!(
+                        std::borrow::Borrow::<<#ty as arrow_convert::field::ArrowField>::Type>::borrow(&#value.#member)
+                    ),
+                },
+            )
+            .collect()
+    }
 
-        let field_idents = field_members
+    /// Returns the bounds of generic structs needed by [`Self::serialize_values`].
+    fn serialize_bounds(&self) -> Vec<(&'a syn::Type, TokenStream)> {
+        self.fields
             .iter()
-            .map(|f| match f {
-                // `Member` doesn't impl `IdentFragment` in a way that preserves the "r#" prefix stripping of `Ident`, so we go one level inside.
-                syn::Member::Named(ident) => format_ident!("field_{}", ident),
-                syn::Member::Unnamed(index) => format_ident!("field_{}", index),
+            .zip(&self.types)
+            .filter(|(field, _)| field.serialize_with.is_none() && field.computed.is_none())
+            .map(|(field, ty)| {
+                (
+                    &field.syn.ty,
+                    quote!(std::borrow::Borrow<<#ty as arrow_convert::field::ArrowField>::Type>),
+                )
             })
-            .collect::<Vec<_>>();
+            .collect()
+    }
 
-        let skipped_field_names = skipped_fields
+    /// Returns the bounds of generic structs needed by [`Self::deserialize_values`].
+    fn deserialize_bounds(&self) -> Vec<(&'a syn::Type, TokenStream)> {
+        self.fields
             .iter()
-            .enumerate()
-            .map(|(id, field)| {
-                field
-                    .syn
-                    .ident
-                    .as_ref()
-                    .cloned()
-                    .map_or_else(|| syn::Member::Unnamed(id.into()), syn::Member::Named)
+            .zip(&self.types)
+            .filter(|(field, _)| field.deserialize_with.is_none())
+            .map(|(field, ty)| {
+                let field_ty = &field.syn.ty;
+                (*ty, quote!(arrow_convert::field::ArrowField<Type = #field_ty>))
             })
-            .collect::<Vec<_>>();
+            .collect()
+    }
 
-        let field_indices = field_members
+    /// Returns the expressions converting a deserialized value of each field type to the field.
+    fn deserialize_values(&self, values: &[TokenStream]) -> Vec<TokenStream> {
+        self.fields
             .iter()
-            .enumerate()
-            .map(|(idx, _ident)| syn::LitInt::new(&format!("{idx}"), proc_macro2::Span::call_site()))
-            .collect::<Vec<_>>();
+            .zip(values)
+            .map(|(field, value)| match &field.deserialize_with {
+                Some(f) => quote!(#f(#value)),
+                None => value.clone(),
+            })
+            .collect()
+    }
+}
 
-        let field_types: Vec<&syn::Type> = fields
-            .iter()
-            .map(|field| match &field.field_type {
+struct Common<'a> {
+    original_name: &'a proc_macro2::Ident,
+    visibility: &'a syn::Visibility,
+    /// The fields written to Arrow, in schema order
+    serialized: FieldSet<'a>,
+    /// The fields read from Arrow, which must be present
+    required: FieldSet<'a>,
+    /// The fields read from Arrow if present, and otherwise set to their default value
+    optional: FieldSet<'a>,
+    /// The functions returning the default values of the optional fields
+    optional_defaults: Vec<syn::Path>,
+    /// The fields that are always set to their default value when deserializing
+    defaulted_members: Vec<syn::Member>,
+    /// The functions returning the default values of the defaulted fields
+    defaulted_defaults: Vec<syn::Path>,
+}
+
+impl<'a> From<&'a DeriveStruct> for Common<'a> {
+    fn from(input: &'a DeriveStruct) -> Self {
+        let original_name = &input.common.name;
+        let visibility = &input.common.visibility;
+
+        let mut serialized = FieldSet::default();
+        let mut required = FieldSet::default();
+        let mut optional = FieldSet::default();
+        let mut optional_defaults = vec![];
+        let mut defaulted_members = vec![];
+        let mut defaulted_defaults = vec![];
+        let mut names: Vec<String> = vec![];
+
+        for (id, field) in input.fields.iter().enumerate() {
+            // members are taken from the position in the original struct so that skipped fields of tuple
+            // structs don't shift the indices of the following fields
+            let member = field
+                .syn
+                .ident
+                .as_ref()
+                .cloned()
+                .map_or_else(|| syn::Member::Unnamed(id.into()), syn::Member::Named);
+
+            let default = field
+                .default
+                .clone()
+                .unwrap_or_else(|| syn::parse_quote!(std::default::Default::default));
+
+            if field.skip_serializing && field.skip_deserializing {
+                defaulted_members.push(member);
+                defaulted_defaults.push(default);
+                continue;
+            }
+
+            let field_type = match &field.field_type {
                 syn::Type::Path(_) => &field.field_type,
                 syn::Type::Array(_) => &field.field_type,
                 syn::Type::Reference(_) => &field.field_type,
-                _ => panic!("Only `Path`, `Array`, `Reference` types are supported atm"),
-            })
-            .collect::<Vec<&syn::Type>>();
+                syn::Type::Tuple(_) => &field.field_type,
+                _ => panic!("Only `Path`, `Array`, `Reference`, `Tuple` types are supported atm"),
+            };
+
+            let name = match (field.field_name.as_ref(), field.syn.ident.as_ref()) {
+                (Some(name), _) => name.to_owned(), // override enabled
+                (_, Some(ident)) => {
+                    // no override, named field
+                    let name = format_ident!("{}", ident).to_string();
+                    match input.rename_all {
+                        Some(rule) => rule.apply_to_field(&name),
+                        None => name,
+                    }
+                }
+                (_, None) => id.to_string(), // no override, unnamed field, named by its position
+            };
 
-        let field_names = fields
-            .iter()
-            .enumerate()
-            .map(
-                |(id, field)| match (field.field_name.as_ref(), field.syn.ident.as_ref()) {
-                    (Some(name), _) => name.to_owned(),                         // override enabled
-                    (_, Some(ident)) => format_ident!("{}", ident).to_string(), // no override, named field
-                    (_, None) => format!("field_{id}"),                         // no override, unnamed field
-                },
-            )
-            .collect::<Vec<_>>();
+            if names.contains(&name) {
+                abort!(field.syn.span(), "Duplicate field name `{}`", name);
+            }
+            names.push(name.clone());
+            for alias in &field.aliases {
+                if names.contains(&alias.value()) {
+                    abort!(alias.span(), "Duplicate field name `{}`", alias.value());
+                }
+                names.push(alias.value());
+            }
+
+            if !field.skip_serializing {
+                serialized.push(member.clone(), field, field_type, name.clone());
+            }
+            if field.skip_deserializing {
+                defaulted_members.push(member);
+                defaulted_defaults.push(default);
+            } else if field.skip_serializing || field.default.is_some() {
+                optional.push(member, field, field_type, name);
+                optional_defaults.push(default);
+            } else {
+                required.push(member, field, field_type, name);
+            }
+        }
+
+        if serialized.members.is_empty() || (required.members.is_empty() && optional.members.is_empty()) {
+            abort!(
+                original_name.span(),
+                "Expected struct to have at least one field that isn't skipped"
+            );
+        }
+
+        for field in &input.computed {
+            let name = field.field_name.clone().unwrap();
+            if names.contains(&name) {
+                abort!(field.syn.span(), "Duplicate field name `{}`", name);
+            }
+            names.push(name.clone());
+            let member = syn::Member::Named(field.syn.ident.clone().unwrap());
+            serialized.push(member, field, &field.field_type, name);
+        }
+
+        // fields with an `index` are moved to that position among the serialized columns, and the other fields
+        // fill the remaining positions in declaration order
+        if serialized.fields.iter().any(|f| f.index.is_some()) {
+            let len = serialized.fields.len();
+            let mut positions: Vec<Option<usize>> = vec![None; len];
+            for (i, field) in serialized.fields.iter().enumerate() {
+                if let Some(index) = &field.index {
+                    let position = index.base10_parse::<usize>().unwrap();
+                    if position >= len {
+                        abort!(
+                            index.span(),
+                            "`{}` must be less than the number of serialized fields, {}",
+                            FIELD_INDEX,
+                            len
+                        );
+                    }
+                    if positions[position].is_some() {
+                        abort!(index.span(), "Duplicate field index {}", position);
+                    }
+                    positions[position] = Some(i);
+                }
+            }
+            let mut unindexed = (0..len).filter(|&i| serialized.fields[i].index.is_none());
+            let order = positions
+                .into_iter()
+                .map(|p| p.or_else(|| unindexed.next()).unwrap())
+                .collect::<Vec<_>>();
+            serialized = serialized.reordered(&order);
+        }
 
         Self {
             original_name,
             visibility,
-            field_members,
-            field_idents,
-            skipped_field_names,
-            field_indices,
-            field_types,
-            field_names,
+            serialized,
+            required,
+            optional,
+            optional_defaults,
+            defaulted_members,
+            defaulted_defaults,
+        }
+    }
+}
+
+/// Returns the generics of a generic struct with each of `types` bounded by `bound`, and each type parameter
+/// bounded by `'static` since the generated arrays and builders are `'static`.
+///
+/// The generics of structs without type parameters are returned unchanged.
+fn bounded_generics<'a>(
+    generics: &syn::Generics,
+    bounds: impl IntoIterator<Item = (&'a syn::Type, TokenStream)>,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+    if generics.type_params().next().is_none() {
+        return generics;
+    }
+    let type_params = generics.type_params().map(|p| p.ident.clone()).collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause.predicates.push(syn::parse_quote!(#param: 'static));
+    }
+    for (ty, bound) in bounds {
+        where_clause.predicates.push(syn::parse_quote!(#ty: #bound));
+    }
+    generics
+}
+
+/// Replaces all lifetimes with `'static`, since the generated builders can't borrow from the serialized values.
+struct EraseLifetimes;
+
+impl VisitMut for EraseLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+        *lifetime = syn::parse_quote!('static);
+    }
+}
+
+/// Returns `generics` without its lifetime parameters, and with the remaining lifetimes replaced by `'static`.
+fn erase_lifetime_params(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    generics.params = generics
+        .params
+        .into_iter()
+        .filter(|p| !matches!(p, syn::GenericParam::Lifetime(_)))
+        .collect();
+    EraseLifetimes.visit_generics_mut(&mut generics);
+    generics
+}
+
+/// Returns the expression creating the Arrow field of `field`, with its nullability and metadata attached.
+fn field_expr(field: &DeriveField, ty: &syn::Type, name: &str) -> TokenStream {
+    let field_expr = quote_spanned!(ty.span() => <#ty as arrow_convert::field::ArrowField>::field(#name));
+    let field_expr = if field.nullable {
+        quote!(#field_expr.with_nullable(true))
+    } else {
+        field_expr
+    };
+    if field.metadata.is_empty() {
+        return field_expr;
+    }
+    // the metadata of the field type, such as the extension type of tensors, is kept
+    let (keys, values): (Vec<_>, Vec<_>) = field.metadata.iter().cloned().unzip();
+    quote!({
+        let field = #field_expr;
+        let mut metadata = field.metadata().clone();
+        metadata.extend([#((#keys.to_string(), #values.to_string())),*]);
+        field.with_metadata(metadata)
+    })
+}
+
+/// Returns the implementation of [`ArrowField::schema_metadata`] for the `schema_metadata` attribute.
+fn schema_metadata_impl(input: &DeriveStruct) -> TokenStream {
+    if input.schema_metadata.is_empty() {
+        return quote! {};
+    }
+    let (keys, values): (Vec<_>, Vec<_>) = input.schema_metadata.iter().cloned().unzip();
+    quote! {
+        fn schema_metadata() -> std::collections::HashMap<String, String> {
+            std::collections::HashMap::from([#((#keys.to_string(), #values.to_string())),*])
+        }
+    }
+}
+
+/// Returns the implementation of [`ArrowField::parquet_hints`], with the hints of the `parquet` attributes of the
+/// serialized fields followed by the hints of the fields of their types, so that the latter take precedence.
+fn parquet_hints_impl(input: &DeriveStruct, serialized: &FieldSet) -> TokenStream {
+    if input.is_transparent {
+        let ty = &serialized.types[0];
+        return quote! {
+            fn parquet_hints() -> Vec<arrow_convert::field::ParquetHint> {
+                <#ty as arrow_convert::field::ArrowField>::parquet_hints()
+            }
+        };
+    }
+    let hints = serialized
+        .fields
+        .iter()
+        .zip(&serialized.types)
+        .zip(&serialized.names)
+        .map(|((field, ty), name)| {
+            let own_hint = field.parquet.as_ref().map(|parquet| {
+                let compression = optional(parquet.compression.as_ref());
+                let encoding = optional(parquet.encoding.as_ref());
+                let dictionary = optional(parquet.dictionary);
+                let bloom_filter = optional(parquet.bloom_filter);
+                quote! {
+                    hints.push(arrow_convert::field::ParquetHint {
+                        path: vec![#name.to_string()],
+                        compression: #compression,
+                        encoding: #encoding,
+                        dictionary: #dictionary,
+                        bloom_filter: #bloom_filter,
+                    });
+                }
+            });
+            quote! {
+                #own_hint
+                hints.extend(
+                    <#ty as arrow_convert::field::ArrowField>::parquet_hints()
+                        .into_iter()
+                        .map(|mut hint| {
+                            hint.path.insert(0, #name.to_string());
+                            hint
+                        }),
+                );
+            }
+        });
+    quote! {
+        fn parquet_hints() -> Vec<arrow_convert::field::ParquetHint> {
+            let mut hints = vec![];
+            #(#hints)*
+            hints
+        }
+    }
+}
+
+/// Returns the expression of an `Option` holding the literal `value`.
+fn optional(value: Option<impl quote::ToTokens>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
+/// Returns the marker types of the timezones of the `timestamp` attributes, which are only generated by the
+/// `ArrowField` derive since the other derives require it.
+fn time_zone_markers(input: &DeriveStruct) -> TokenStream {
+    let visibility = &input.common.visibility;
+    let markers = input
+        .fields
+        .iter()
+        .filter_map(|f| f.time_zone.as_ref())
+        .map(|(marker, tz)| {
+            quote! {
+                #[doc(hidden)]
+                #visibility struct #marker;
+
+                impl arrow_convert::field::ArrowTimeZone for #marker {
+                    #[inline]
+                    fn time_zone() -> Option<std::sync::Arc<str>> {
+                        Some(#tz.into())
+                    }
+                }
+            }
+        });
+    quote!(#(#markers)*)
+}
+
+/// Returns the type the struct is serialized from and deserialized to: the struct itself, or the foreign type of the
+/// `remote` attribute, which takes the generics of the struct unless it has its own.
+fn value_type(input: &DeriveStruct, ty_generics: &syn::TypeGenerics) -> syn::Type {
+    let original_name = &input.common.name;
+    match &input.remote {
+        Some(remote) if remote.segments.last().is_some_and(|s| !s.arguments.is_empty()) => {
+            syn::parse_quote!(#remote)
+        }
+        Some(remote) => syn::parse_quote!(#remote #ty_generics),
+        None => syn::parse_quote!(#original_name #ty_generics),
+    }
+}
+
+/// Returns the path instantiating the deserialized values, without generic arguments.
+fn value_path(input: &DeriveStruct) -> syn::Path {
+    match &input.remote {
+        Some(remote) => {
+            let mut path = remote.clone();
+            if let Some(segment) = path.segments.last_mut() {
+                segment.arguments = syn::PathArguments::None;
+            }
+            path
+        }
+        None => input.common.name.clone().into(),
+    }
+}
+
+/// Returns a conversion of the struct to the foreign type of the `remote` attribute, which checks that the fields
+/// match and that the fields of the struct are used.
+fn remote_check(input: &DeriveStruct) -> TokenStream {
+    if input.remote.is_none() {
+        return quote! {};
+    }
+    let original_name = &input.common.name;
+    let (impl_generics, ty_generics, where_clause) = input.common.generics.split_for_impl();
+    let value_type = value_type(input, &ty_generics);
+    let value_path = value_path(input);
+    let members = input.fields.iter().enumerate().map(|(idx, f)| match &f.syn.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(idx.into()),
+    });
+    quote! {
+        impl #impl_generics #original_name #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            fn __into_remote(self) -> #value_type {
+                #value_path {
+                    #(#members: self.#members,)*
+                }
+            }
+        }
+    }
+}
+
+/// Returns the implementation of [`ArrowDeserialize::validate`], which validates the deserialized fields, prefixing
+/// violations with the field name, and calls the functions of the `validate` attributes.
+fn validate_impl(input: &DeriveStruct, deserialized: &[&FieldSet], value_type: &syn::Type) -> TokenStream {
+    let field_checks = deserialized.iter().flat_map(|set| {
+        set.fields
+            .iter()
+            .zip(&set.members)
+            .zip(&set.types)
+            .zip(&set.names)
+            .map(|(((field, member), ty), name)| {
+                // fields converted by `deserialize_with` don't have the type of the field type override
+                let nested = if field.deserialize_with.is_none() {
+                    quote! {
+                        <#ty as arrow_convert::deserialize::ArrowDeserialize>::validate(&v.#member)
+                            .map_err(|e| format!("{}: {}", #name, e))?;
+                    }
+                } else {
+                    quote! {}
+                };
+                let own = field.validate.as_ref().map(|f| {
+                    quote! {
+                        #f(&v.#member).map_err(|e| format!("{}: {}", #name, e))?;
+                    }
+                });
+                quote!(#nested #own)
+            })
+    });
+    let container_check = input.validate.as_ref().map(|f| {
+        quote! {
+            #f(v).map_err(|e| e.to_string())?;
+        }
+    });
+
+    quote! {
+        fn validate(v: &#value_type) -> std::result::Result<(), String> {
+            #(#field_checks)*
+            #container_check
+            Ok(())
+        }
+    }
+}
+
+/// Returns the borrowed view struct of the `view` attribute, its iterator and the `ArrowView` impl, given the
+/// deserialized fields and the names of their columns.
+fn view_impl(input: &DeriveStruct, required: &FieldSet, name_lists: &[TokenStream]) -> TokenStream {
+    let Some(view_name) = &input.view else {
+        return quote! {};
+    };
+    let original_name = &input.common.name;
+    let original_name_str = original_name.to_string();
+    let visibility = &input.common.visibility;
+    let iterator_name = format_ident!("{}Iter", view_name);
+    let FieldSet {
+        idents, types, names, ..
+    } = required;
+    let field_visibilities = required.fields.iter().map(|f| &f.syn.vis).collect::<Vec<_>>();
+    let view_types = types
+        .iter()
+        .map(|t| quote_spanned!(t.span() => <#t as arrow_convert::deserialize::ArrowView>::View<'a>))
+        .collect::<Vec<_>>();
+    let iter_types = types
+        .iter()
+        .map(|t| quote_spanned!(t.span() => <#t as arrow_convert::deserialize::ArrowView>))
+        .collect::<Vec<_>>();
+
+    // the fields that aren't deserialized are left out, so tuple structs are viewed as tuple structs of the
+    // remaining fields
+    let is_tuple = required.fields[0].syn.ident.is_none();
+    let (view_decl, view_inst) = if is_tuple {
+        (
+            quote! {
+                #visibility struct #view_name<'a>(#(#field_visibilities #view_types),*);
+            },
+            quote!(#view_name(#(#idents),*)),
+        )
+    } else {
+        let members = &required.members;
+        (
+            quote! {
+                #visibility struct #view_name<'a> {
+                    #(#field_visibilities #members: #view_types,)*
+                }
+            },
+            quote!(#view_name { #(#members: #idents,)* }),
+        )
+    };
+
+    quote! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #view_decl
+
+        #visibility struct #iterator_name<'a> {
+            #(#idents: #iter_types::ViewIter<'a>,)*
+            remaining: usize,
+            validity_iter: arrow::util::bit_iterator::BitIterator<'a>,
+            has_validity: bool,
+        }
+
+        impl<'a> Iterator for #iterator_name<'a> {
+            type Item = Option<#view_name<'a>>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    return None;
+                }
+                self.remaining -= 1;
+
+                #(let #idents = self.#idents.next().unwrap();)*
+                if !self.has_validity || self.validity_iter.next().unwrap_or(false) {
+                    #(let #idents = #idents.unwrap_or_else(|| panic!("Unexpected null in column `{}` viewing `{}`", #names, #original_name_str));)*
+                    Some(Some(#view_inst))
+                } else {
+                    Some(None)
+                }
+            }
+        }
+
+        impl arrow_convert::deserialize::ArrowView for #original_name {
+            type View<'a> = #view_name<'a>;
+            type ViewIter<'a> = #iterator_name<'a>;
+
+            fn iter_views(b: &dyn arrow::array::Array) -> Self::ViewIter<'_> {
+                use core::ops::Deref;
+                use arrow::array::Array;
+
+                let arr = b.as_any().downcast_ref::<arrow::array::StructArray>().unwrap();
+                let validity = arr.nulls();
+                #iterator_name {
+                    #(
+                        #idents: #iter_types::iter_views(
+                            #name_lists.iter().find_map(|name| arr.column_by_name(name))
+                                .unwrap_or_else(|| panic!("Missing column `{}` viewing `{}`", #names, #original_name_str))
+                                .deref()
+                        ),
+                    )*
+                    remaining: arr.len(),
+                    has_validity: validity.as_ref().is_some(),
+                    validity_iter: validity.as_ref().map(|x| x.iter()).unwrap_or_else(|| arrow::util::bit_iterator::BitIterator::new(&[], 0, 0)),
+                }
+            }
+        }
+    }
+}
+
+/// Unit structs are stored as `Null`, like `()`, or as a `Struct` without fields with `mode = "struct"`.
+fn expand_unit_field(input: &DeriveStruct) -> TokenStream {
+    let original_name = &input.common.name;
+    let (impl_generics, ty_generics, where_clause) = input.common.generics.split_for_impl();
+    let schema_metadata_impl = schema_metadata_impl(input);
+
+    let data_type_impl = if input.is_empty_struct {
+        quote! {
+            fn data_type() -> arrow::datatypes::DataType {
+                arrow::datatypes::DataType::Struct(arrow::datatypes::Fields::empty())
+            }
+        }
+    } else {
+        quote! {
+            fn data_type() -> arrow::datatypes::DataType {
+                arrow::datatypes::DataType::Null
+            }
+
+            fn is_nullable() -> bool {
+                true
+            }
+        }
+    };
+
+    quote! {
+        impl #impl_generics arrow_convert::field::ArrowField for #original_name #ty_generics #where_clause {
+            type Type = Self;
+
+            #data_type_impl
+
+            #schema_metadata_impl
+        }
+
+        impl #impl_generics arrow_convert::field::ArrowEnableVecForType for #original_name #ty_generics #where_clause {}
+    }
+}
+
+fn expand_unit_serialize(input: &DeriveStruct) -> TokenStream {
+    let original_name = &input.common.name;
+    let (impl_generics, ty_generics, where_clause) = input.common.generics.split_for_impl();
+
+    let (builder, append) = if input.is_empty_struct {
+        (
+            quote!(arrow_convert::serialize::EmptyStructBuilder),
+            quote!(array.append(true)),
+        )
+    } else {
+        (quote!(arrow::array::NullBuilder), quote!(array.append_null()))
+    };
+
+    quote! {
+        impl #impl_generics arrow_convert::serialize::ArrowSerialize for #original_name #ty_generics #where_clause {
+            type ArrayBuilderType = #builder;
+
+            #[inline]
+            fn new_array() -> Self::ArrayBuilderType {
+                Self::ArrayBuilderType::new()
+            }
+
+            #[inline]
+            fn arrow_serialize(_v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                #append;
+                Ok(())
+            }
+        }
+    }
+}
+
+fn expand_unit_deserialize(input: &DeriveStruct) -> TokenStream {
+    let original_name = &input.common.name;
+    let (impl_generics, ty_generics, where_clause) = input.common.generics.split_for_impl();
+
+    // every slot of a `NullArray` is null, so it always deserializes to the unit struct
+    let (array, deserialize_impl) = if input.is_empty_struct {
+        (
+            quote!(arrow_convert::deserialize::EmptyStructArray),
+            quote! {
+                fn arrow_deserialize(v: Option<()>) -> Option<Self> {
+                    v.map(|_| #original_name {})
+                }
+            },
+        )
+    } else {
+        (
+            quote!(arrow::array::NullArray),
+            quote! {
+                fn arrow_deserialize(_v: Option<()>) -> Option<Self> {
+                    Some(#original_name {})
+                }
+            },
+        )
+    };
+
+    quote! {
+        impl #impl_generics arrow_convert::deserialize::ArrowDeserialize for #original_name #ty_generics #where_clause {
+            type ArrayType = #array;
+
+            #[inline]
+            #deserialize_impl
         }
     }
 }
 
 pub fn expand_field(input: DeriveStruct) -> TokenStream {
+    if input.is_unit {
+        return expand_unit_field(&input);
+    }
+
     let Common {
         original_name,
-        field_types,
-        field_names,
+        serialized,
         ..
     } = (&input).into();
+    let field_types = &serialized.types;
+    let field_exprs = serialized
+        .fields
+        .iter()
+        .zip(field_types)
+        .zip(&serialized.names)
+        .map(|((field, ty), name)| field_expr(field, ty, name));
 
-    let arrow_schema_impl = if input.fields.len() == 1 && input.is_transparent {
+    let generics = bounded_generics(
+        &input.common.generics,
+        field_types
+            .iter()
+            .map(|t| (*t, quote!(arrow_convert::field::ArrowField))),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let schema_metadata_impl = schema_metadata_impl(&input);
+    let parquet_hints_impl = parquet_hints_impl(&input, &serialized);
+    let time_zone_markers = time_zone_markers(&input);
+    let remote_check = remote_check(&input);
+    let value_type = value_type(&input, &ty_generics);
+
+    let arrow_schema_impl = if input.is_transparent {
         quote! {}
     } else {
         quote! {
-          impl #original_name {
-            pub fn arrow_schema() -> arrow::datatypes::Schema {
-                arrow::datatypes::Schema::new(vec![
+          impl #impl_generics #original_name #ty_generics #where_clause {
+            /// The fields of the struct, in the order they are serialized.
+            pub fn arrow_fields() -> Vec<arrow::datatypes::FieldRef> {
+                vec![
                     #(
-                        <#field_types as arrow_convert::field::ArrowField>::field(#field_names),
+                        std::sync::Arc::new(#field_exprs),
                     )*
-                ])
+                ]
+            }
+
+            /// The schema of a `RecordBatch` holding the struct, available before any rows are serialized.
+            pub fn arrow_schema() -> arrow::datatypes::SchemaRef {
+                std::sync::Arc::new(
+                    arrow::datatypes::Schema::new(Self::arrow_fields())
+                        .with_metadata(<Self as arrow_convert::field::ArrowField>::schema_metadata()),
+                )
             }
           }
         }
     };
 
     let data_type_impl = {
-        if input.fields.len() == 1 && input.is_transparent {
+        if input.is_transparent {
             // Special case for single-field (tuple) structs
-            let field = &input.fields[0];
-            let ty = &field.field_type;
+            let ty = &field_types[0];
             quote! (
                 <#ty as arrow_convert::field::ArrowField>::data_type()
             )
         } else {
-            quote!(arrow::datatypes::DataType::Struct(Self::arrow_schema().fields))
+            quote!(arrow::datatypes::DataType::Struct(Self::arrow_fields().into()))
         }
     };
 
+    // a transparent wrapper around a nullable type is nullable as well
+    let is_nullable_impl = if input.is_transparent {
+        let ty = &field_types[0];
+        quote! {
+            fn is_nullable() -> bool {
+                <#ty as arrow_convert::field::ArrowField>::is_nullable()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     quote!(
+        #time_zone_markers
+
+        #remote_check
+
         #arrow_schema_impl
 
-        impl arrow_convert::field::ArrowField for #original_name {
-            type Type = Self;
+        impl #impl_generics arrow_convert::field::ArrowField for #original_name #ty_generics #where_clause {
+            type Type = #value_type;
 
             fn data_type() -> arrow::datatypes::DataType {
                 #data_type_impl
             }
+
+            #is_nullable_impl
+
+            #schema_metadata_impl
+
+            #parquet_hints_impl
         }
 
-        arrow_convert::arrow_enable_vec_for_type!(#original_name);
+        impl #impl_generics arrow_convert::field::ArrowEnableVecForType for #original_name #ty_generics #where_clause {}
     )
 }
 
 pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
+    if input.is_unit {
+        return expand_unit_serialize(&input);
+    }
+
     let Common {
         original_name,
         visibility,
-        field_members,
-        field_idents,
-        field_types,
+        serialized,
         ..
     } = (&input).into();
+    let field_values = serialized.serialize_values(quote!(i));
+    let first_value = &serialized.serialize_values(quote!(v))[0];
+    let serialized_type_bounds = serialized.serialize_bounds();
+    let FieldSet {
+        members: field_members,
+        idents: field_idents,
+        types: field_types,
+        names: field_names,
+        ..
+    } = serialized;
+
+    let mut generics = bounded_generics(
+        &input.common.generics,
+        field_types
+            .iter()
+            .map(|t| (*t, quote!(arrow_convert::serialize::ArrowSerialize)))
+            .chain(serialized_type_bounds),
+    );
+    if generics.type_params().next().is_some() {
+        // the builders of the fields are held by the generated builder
+        let where_clause = generics.make_where_clause();
+        for t in &field_types {
+            where_clause.predicates.push(syn::parse_quote!(
+                <#t as arrow_convert::serialize::ArrowSerialize>::ArrayBuilderType: arrow_convert::serialize::PushNull + std::fmt::Debug
+            ));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let original_type = quote!(#original_name #ty_generics);
+    let value_type = value_type(&input, &ty_generics);
+
+    // the builder is `'static`, so it drops the lifetimes of the struct and uses the `'static` versions of the types
+    let builder_generics = erase_lifetime_params(&generics);
+    let (builder_impl_generics, builder_ty_generics, builder_where_clause) = builder_generics.split_for_impl();
+    let mut original_static_type: syn::Type = syn::parse_quote!(#original_type);
+    EraseLifetimes.visit_type_mut(&mut original_static_type);
+    let mut value_static_type = value_type.clone();
+    EraseLifetimes.visit_type_mut(&mut value_static_type);
+    let static_field_types = field_types
+        .iter()
+        .map(|t| {
+            let mut t = (*t).clone();
+            EraseLifetimes.visit_type_mut(&mut t);
+            t
+        })
+        .collect::<Vec<_>>();
+    let lifetimes = input
+        .common
+        .generics
+        .lifetimes()
+        .map(|l| &l.lifetime)
+        .collect::<Vec<_>>();
+    let method_lifetimes = if lifetimes.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#lifetimes),*>)
+    };
 
     let mutable_array_name = &input.common.mutable_array_name();
-    let mutable_field_array_types = field_types
+    let mutable_field_array_types = static_field_types
         .iter()
         .map(|field_type| quote_spanned!( field_type.span() => <#field_type as arrow_convert::serialize::ArrowSerialize>::ArrayBuilderType))
         .collect::<Vec<TokenStream>>();
 
     let array_decl = quote! {
         #[derive(Debug)]
-        #visibility struct #mutable_array_name {
+        #visibility struct #mutable_array_name #builder_impl_generics #builder_where_clause {
             #(
                 #field_idents: #mutable_field_array_types,
             )*
             data_type: arrow::datatypes::DataType,
             validity: Option<arrow::array::BooleanBufferBuilder>,
+            _marker: std::marker::PhantomData<fn() -> #original_static_type>,
         }
     };
 
     let array_impl = quote! {
-        impl #mutable_array_name {
+        impl #builder_impl_generics #mutable_array_name #builder_ty_generics #builder_where_clause {
             pub fn new() -> Self {
                 Self {
-                    #(#field_idents: <#field_types as arrow_convert::serialize::ArrowSerialize>::new_array(),)*
-                    data_type: <#original_name as arrow_convert::field::ArrowField>::data_type(),
+                    #(#field_idents: <#static_field_types as arrow_convert::serialize::ArrowSerialize>::new_array(),)*
+                    data_type: <#original_static_type as arrow_convert::field::ArrowField>::data_type(),
+                    validity: None,
+                    _marker: std::marker::PhantomData,
+                }
+            }
+
+            /// Creates the builder with the builders of the fields pre-sized with the capacity hints of `options`.
+            pub fn with_capacity(options: &arrow_convert::serialize::SerializeOptions) -> Self {
+                Self {
+                    #(#field_idents: <#static_field_types as arrow_convert::serialize::ArrowSerialize>::new_array_with_capacity(options),)*
+                    data_type: <#original_static_type as arrow_convert::field::ArrowField>::data_type(),
                     validity: None,
+                    _marker: std::marker::PhantomData,
                 }
             }
 
@@ -207,22 +951,22 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             }
 
             fn append_null(&mut self) {
-                self.try_push(None::<&#original_name>).unwrap();
+                self.try_push(None::<&#value_static_type>).unwrap();
             }
 
             fn validity(&self) -> Option<&arrow::array::BooleanBufferBuilder> {
                 self.validity.as_ref()
             }
 
-            fn try_push(&mut self, item: Option<impl std::borrow::Borrow<#original_name>>) -> arrow::error::Result<()> {
+            fn try_push #method_lifetimes(&mut self, item: Option<impl std::borrow::Borrow<#value_type>>) -> arrow::error::Result<()> {
                 use arrow::array::ArrayBuilder;
                 use std::borrow::Borrow;
 
                 match item {
                     Some(i) =>  {
-                        let i = i.borrow() as &#original_name;
+                        let i = i.borrow() as &#value_type;
                         #(
-                            <#field_types as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(i.#field_members.borrow(), &mut self.#field_idents)?;
+                            <#field_types as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(#field_values, &mut self.#field_idents)?;
                         )*;
                         match &mut self.validity {
                             Some(validity) => validity.append(true),
@@ -236,7 +980,10 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 Ok(())
             }
 
-            fn try_extend<'a, I: IntoIterator<Item = Option<&'a #original_name>>>(&mut self, iter: I) -> arrow::error::Result<()> {
+            fn try_extend<'__a, #(#lifetimes,)* I: IntoIterator<Item = Option<&'__a #value_type>>>(&mut self, iter: I) -> arrow::error::Result<()>
+            where
+                #value_type: '__a,
+            {
                 for i in iter {
                     self.try_push(i)?;
                 }
@@ -246,7 +993,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
     };
 
     let array_default_impl = quote! {
-        impl Default for #mutable_array_name {
+        impl #builder_impl_generics Default for #mutable_array_name #builder_ty_generics #builder_where_clause {
             fn default() -> Self {
                 Self::new()
             }
@@ -254,7 +1001,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
     };
 
     let array_push_null_impl = quote! {
-        impl arrow_convert::serialize::PushNull for #mutable_array_name {
+        impl #builder_impl_generics arrow_convert::serialize::PushNull for #mutable_array_name #builder_ty_generics #builder_where_clause {
             fn push_null(&mut self) {
                 use arrow::array::ArrayBuilder;
                 use arrow_convert::serialize::{ArrowSerialize, PushNull};
@@ -262,7 +1009,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
 
                 #(
                     // #mutable_field_array_types::append_null(&mut self.#field_idents);
-                    <<#field_types as ArrowSerialize>::ArrayBuilderType as PushNull>::push_null(&mut self.#field_idents);
+                    <<#static_field_types as ArrowSerialize>::ArrayBuilderType as PushNull>::push_null(&mut self.#field_idents);
                     // self.#field_idents.append_null();
                 )*;
                 match &mut self.validity {
@@ -278,7 +1025,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
     let first_ident = &field_idents[0];
 
     let array_mutable_array_impl = quote! {
-        impl arrow::array::ArrayBuilder for #mutable_array_name {
+        impl #builder_impl_generics arrow::array::ArrayBuilder for #mutable_array_name #builder_ty_generics #builder_where_clause {
             fn len(&self) -> usize {
                 self.#first_ident.len()
             }
@@ -289,7 +1036,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 )*];
 
                 let arrow::datatypes::DataType::Struct(fields) =
-                  <#original_name as arrow_convert::field::ArrowField>::data_type()
+                  <#original_static_type as arrow_convert::field::ArrowField>::data_type()
                   .clone() else {
                     panic!("datatype is not struct")
                   };
@@ -307,7 +1054,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 )*];
 
                 let arrow::datatypes::DataType::Struct(fields) =
-                  <#original_name as arrow_convert::field::ArrowField>::data_type()
+                  <#original_static_type as arrow_convert::field::ArrowField>::data_type()
                   .clone() else {
                     panic!("datatype is not struct")
                   };
@@ -333,13 +1080,89 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
         }
     };
 
+    let typed_builder = input.builder.as_ref().map(|builder_name| {
+        let append_idents = field_members.iter().map(|member| match member {
+            syn::Member::Named(ident) => format_ident!("append_{}", ident),
+            syn::Member::Unnamed(index) => format_ident!("append_{}", index.index),
+        });
+
+        quote! {
+            #[derive(Debug)]
+            #visibility struct #builder_name #builder_impl_generics #builder_where_clause {
+                array: #mutable_array_name #builder_ty_generics,
+            }
+
+            impl #builder_impl_generics #builder_name #builder_ty_generics #builder_where_clause {
+                pub fn new() -> Self {
+                    Self {
+                        array: #mutable_array_name::new(),
+                    }
+                }
+
+                #(
+                    pub fn #append_idents #method_lifetimes(
+                        &mut self,
+                        value: impl std::borrow::Borrow<<#field_types as arrow_convert::field::ArrowField>::Type>,
+                    ) -> arrow::error::Result<()> {
+                        <#field_types as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(value.borrow(), &mut self.array.#field_idents)
+                    }
+                )*
+
+                pub fn append_row #method_lifetimes(&mut self, row: &#value_type) -> arrow::error::Result<()> {
+                    self.array.try_push(Some(row))
+                }
+
+                /// The number of rows whose columns have all been appended.
+                pub fn len(&self) -> usize {
+                    [#(arrow::array::ArrayBuilder::len(&self.array.#field_idents)),*]
+                        .into_iter()
+                        .min()
+                        .unwrap_or(0)
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.len() == 0
+                }
+
+                /// Returns the appended rows and resets the builder, or an error if the columns have different lengths.
+                pub fn finish(&mut self) -> arrow::error::Result<arrow::array::StructArray> {
+                    let len = arrow::array::ArrayBuilder::len(&self.array.#first_ident);
+                    #(
+                        let column_len = arrow::array::ArrayBuilder::len(&self.array.#field_idents);
+                        if column_len != len {
+                            return Err(arrow::error::ArrowError::InvalidArgumentError(format!(
+                                "Column `{}` has {} values, expected {}",
+                                #field_names,
+                                column_len,
+                                len
+                            )));
+                        }
+                    )*
+                    let array = arrow::array::ArrayBuilder::finish(&mut self.array);
+                    Ok(arrow::array::AsArray::as_struct(&array).clone())
+                }
+
+                /// Returns the appended rows as a `RecordBatch` with the schema of the struct and resets the builder.
+                pub fn finish_record_batch(&mut self) -> arrow::error::Result<arrow::record_batch::RecordBatch> {
+                    let (_, columns, _) = self.finish()?.into_parts();
+                    arrow::record_batch::RecordBatch::try_new(<#original_static_type>::arrow_schema(), columns)
+                }
+            }
+
+            impl #builder_impl_generics Default for #builder_name #builder_ty_generics #builder_where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    });
+
     // Special case for single-field (tuple) structs.
-    if input.fields.len() == 1 && input.is_transparent {
+    if input.is_transparent {
         let first_type = &field_types[0];
-        let first_field = &field_members[0];
         // Everything delegates to first field.
         quote! {
-            impl arrow_convert::serialize::ArrowSerialize for #original_name {
+            impl #impl_generics arrow_convert::serialize::ArrowSerialize for #original_name #ty_generics #where_clause {
                 type ArrayBuilderType = <#first_type as arrow_convert::serialize::ArrowSerialize>::ArrayBuilderType;
 
                 #[inline]
@@ -348,15 +1171,22 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 }
 
                 #[inline]
-                fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
-                    <#first_type as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(&v.#first_field, array)
+                fn new_array_with_capacity(options: &arrow_convert::serialize::SerializeOptions) -> Self::ArrayBuilderType {
+                    <#first_type as arrow_convert::serialize::ArrowSerialize>::new_array_with_capacity(options)
+                }
+
+                #[inline]
+                fn arrow_serialize(v: &#value_type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                    use std::borrow::Borrow;
+
+                    <#first_type as arrow_convert::serialize::ArrowSerialize>::arrow_serialize(#first_value, array)
                 }
             }
         }
     } else {
         let field_arrow_serialize_impl = quote! {
-            impl arrow_convert::serialize::ArrowSerialize for #original_name {
-                type ArrayBuilderType = #mutable_array_name;
+            impl #impl_generics arrow_convert::serialize::ArrowSerialize for #original_name #ty_generics #where_clause {
+                type ArrayBuilderType = #mutable_array_name #builder_ty_generics;
 
                 #[inline]
                 fn new_array() -> Self::ArrayBuilderType {
@@ -364,7 +1194,12 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 }
 
                 #[inline]
-                fn arrow_serialize(v: &Self, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
+                fn new_array_with_capacity(options: &arrow_convert::serialize::SerializeOptions) -> Self::ArrayBuilderType {
+                    Self::ArrayBuilderType::with_capacity(options)
+                }
+
+                #[inline]
+                fn arrow_serialize(v: &#value_type, array: &mut Self::ArrayBuilderType) -> arrow::error::Result<()> {
                     array.try_push(Some(v))
                 }
             }
@@ -376,33 +1211,190 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             array_push_null_impl,
             array_mutable_array_impl,
             field_arrow_serialize_impl,
+            typed_builder.unwrap_or_default(),
         ])
     }
 }
 
 pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
+    if input.is_unit {
+        return expand_unit_deserialize(&input);
+    }
+
+    if let Some(lifetime) = input.common.generics.lifetimes().next() {
+        abort!(
+            lifetime.span(),
+            "Deriving `ArrowDeserialize` is not supported on structs with lifetimes"
+        );
+    }
+
     let Common {
         original_name,
         visibility,
-        field_members,
-        field_idents,
-        skipped_field_names,
-        field_indices,
-        field_types,
-        ..
+        serialized,
+        required,
+        optional,
+        optional_defaults,
+        defaulted_members,
+        defaulted_defaults,
     } = (&input).into();
 
+    let original_name_str = original_name.to_string();
     let array_name = &input.common.array_name();
     let iterator_name = &input.common.iterator_name();
-    let is_tuple_struct = matches!(field_members[0], syn::Member::Unnamed(_));
+
+    let required_values = required.deserialize_values(
+        &required
+            .types
+            .iter()
+            .zip(&required.idents)
+            .map(|(t, ident)| quote_spanned!(t.span() => <#t as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#ident)))
+            .collect::<Vec<_>>(),
+    );
+    let optional_values = optional.deserialize_values(
+        &optional
+            .types
+            .iter()
+            .map(|t| quote_spanned!(t.span() => <#t as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(v)))
+            .collect::<Vec<_>>(),
+    );
+    let deserialized_type_bounds = required
+        .deserialize_bounds()
+        .into_iter()
+        .chain(optional.deserialize_bounds())
+        .collect::<Vec<_>>();
+    // columns of fields with the `nullable` attribute may be nullable or not
+    let deserialized_forced_nullable = required
+        .fields
+        .iter()
+        .chain(&optional.fields)
+        .map(|f| f.nullable)
+        .collect::<Vec<_>>();
+    let FieldSet {
+        members: required_members,
+        idents: required_idents,
+        types: required_types,
+        names: required_names,
+        ..
+    } = &required;
+    let FieldSet {
+        members: optional_members,
+        idents: optional_idents,
+        types: optional_types,
+        names: optional_names,
+        ..
+    } = &optional;
+    let deserialized_types = required_types.iter().chain(optional_types).collect::<Vec<_>>();
+    // the array types are spanned to the field types, so that errors about missing traits point at the fields
+    let array_types = |types: &[&syn::Type]| {
+        types
+            .iter()
+            .map(|t| quote_spanned!(t.span() => <#t as arrow_convert::deserialize::ArrowDeserialize>::ArrayType))
+            .collect::<Vec<_>>()
+    };
+    let required_array_types = array_types(required_types);
+    let optional_array_types = array_types(optional_types);
+    let deserialized_traits = deserialized_types
+        .iter()
+        .map(|t| quote_spanned!(t.span() => <#t as arrow_convert::deserialize::ArrowDeserialize>))
+        .collect::<Vec<_>>();
+
+    let mut generics = bounded_generics(
+        &input.common.generics,
+        serialized
+            .types
+            .iter()
+            .map(|t| (*t, quote!(arrow_convert::field::ArrowField)))
+            .chain(
+                deserialized_types
+                    .iter()
+                    .map(|t| (**t, quote!(arrow_convert::deserialize::ArrowDeserialize))),
+            )
+            .chain(deserialized_type_bounds),
+    );
+    if generics.type_params().next().is_some() {
+        // the arrays of the fields are iterated by the generated iterator
+        let where_clause = generics.make_where_clause();
+        for t in &deserialized_types {
+            where_clause.predicates.push(syn::parse_quote!(
+                <#t as arrow_convert::deserialize::ArrowDeserialize>::ArrayType: arrow_convert::deserialize::ArrowArray + 'static
+            ));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let original_type = quote!(#original_name #ty_generics);
+    let value_type = value_type(&input, &ty_generics);
+    let value_path = value_path(&input);
+    let validate_impl = validate_impl(&input, &[&required, &optional], &value_type);
+
+    // the iterator borrows the array for `'a`
+    let mut iterator_generics = generics.clone();
+    iterator_generics.params.insert(0, syn::parse_quote!('a));
+    let (iterator_impl_generics, iterator_ty_generics, _) = iterator_generics.split_for_impl();
+    // the columns of the deserialized fields are found by their name, or else by their aliases
+    let name_lists = |set: &FieldSet| {
+        set.fields
+            .iter()
+            .zip(&set.names)
+            .map(|(field, name)| {
+                let aliases = &field.aliases;
+                quote!([#name, #(#aliases),*])
+            })
+            .collect::<Vec<_>>()
+    };
+    let required_name_lists = name_lists(&required);
+    let optional_name_lists = name_lists(&optional);
+    let view_impl = view_impl(&input, &required, &required_name_lists);
+    let deserialized_name_lists = required_name_lists
+        .iter()
+        .chain(&optional_name_lists)
+        .collect::<Vec<_>>();
+    let deserialized_names = required_names.iter().chain(optional_names).collect::<Vec<_>>();
+    let aliases = required
+        .fields
+        .iter()
+        .chain(&optional.fields)
+        .flat_map(|f| f.aliases.iter().map(|alias| alias.value()))
+        .collect::<Vec<_>>();
+    let known_names = serialized
+        .names
+        .iter()
+        .chain(optional_names)
+        .chain(&aliases)
+        .collect::<Vec<_>>();
+    // columns that don't belong to a field are ignored, unless `deny_unknown_fields` is set
+    let deny_unknown_fields_check = if input.deny_unknown_fields {
+        quote! {
+            let known: &[&str] = &[#(#known_names),*];
+            if fields.iter().any(|f| !known.contains(&f.name().as_str())) {
+                return false;
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let deny_unknown_fields_impl = if input.deny_unknown_fields {
+        quote! {
+            let known: &[&str] = &[#(#known_names),*];
+            unknown.extend(
+                fields
+                    .iter()
+                    .filter(|f| !known.contains(&f.name().as_str()))
+                    .map(|f| f.name().clone()),
+            );
+        }
+    } else {
+        quote! {}
+    };
 
     let array_decl = quote! {
-        #visibility struct #array_name
-        {}
+        #visibility struct #array_name #impl_generics #where_clause {
+            _marker: std::marker::PhantomData<fn() -> #original_type>,
+        }
     };
 
     let array_impl = quote! {
-        impl arrow_convert::deserialize::ArrowArray for #array_name
+        impl #impl_generics arrow_convert::deserialize::ArrowArray for #array_name #ty_generics #where_clause
         {
             type BaseArrayType = arrow::array::StructArray;
 
@@ -413,25 +1405,34 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
                 use arrow::array::Array;
 
                 let arr = b.as_any().downcast_ref::<arrow::array::StructArray>().unwrap();
-                let values = arr.columns();
                 let validity = arr.nulls();
-                // for now do a straight comp
                 #iterator_name {
                     #(
-                        #field_idents: <<#field_types as arrow_convert::deserialize::ArrowDeserialize>::ArrayType as arrow_convert::deserialize::ArrowArray>::iter_from_array_ref(values[#field_indices].deref()),
+                        #required_idents: <#required_array_types as arrow_convert::deserialize::ArrowArray>::iter_from_array_ref(
+                            #required_name_lists.iter().find_map(|name| arr.column_by_name(name))
+                                .unwrap_or_else(|| panic!("Missing column `{}` deserializing `{}`", #required_names, #original_name_str))
+                                .deref()
+                        ),
+                    )*
+                    #(
+                        #optional_idents: #optional_name_lists.iter().find_map(|name| arr.column_by_name(name)).map(|c| {
+                            <#optional_array_types as arrow_convert::deserialize::ArrowArray>::iter_from_array_ref(c.deref())
+                        }),
                     )*
+                    remaining: arr.len(),
                     has_validity: validity.as_ref().is_some(),
-                    validity_iter: validity.as_ref().map(|x| x.iter()).unwrap_or_else(|| arrow::util::bit_iterator::BitIterator::new(&[], 0, 0))
+                    validity_iter: validity.as_ref().map(|x| x.iter()).unwrap_or_else(|| arrow::util::bit_iterator::BitIterator::new(&[], 0, 0)),
+                    _marker: std::marker::PhantomData,
                 }
             }
         }
     };
 
     let array_iterable_impl = quote! {
-        impl arrow_convert::deserialize::ArrowArrayIterable for #array_name
+        impl #impl_generics arrow_convert::deserialize::ArrowArrayIterable for #array_name #ty_generics #where_clause
         {
-            type Item<'a> = Option<#original_name>;
-            type Iter<'a> = #iterator_name<'a>;
+            type Item<'a> = Option<#value_type>;
+            type Iter<'a> = #iterator_name #iterator_ty_generics;
 
             fn iter(&self) -> Self::Iter<'_> {
                 unimplemented!("Use iter_from_array_ref");
@@ -440,99 +1441,190 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
     };
 
     let iterator_decl = quote! {
-        #visibility struct #iterator_name<'a> {
+        #visibility struct #iterator_name #iterator_impl_generics #where_clause {
+            #(
+                #required_idents: <#required_array_types as arrow_convert::deserialize::ArrowArrayIterable>::Iter<'a>,
+            )*
             #(
-                #field_idents: <<#field_types as arrow_convert::deserialize::ArrowDeserialize>::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Iter<'a>,
+                #optional_idents: Option<<#optional_array_types as arrow_convert::deserialize::ArrowArrayIterable>::Iter<'a>>,
             )*
+            remaining: usize,
             validity_iter: arrow::util::bit_iterator::BitIterator<'a>,
-            has_validity: bool
+            has_validity: bool,
+            _marker: std::marker::PhantomData<fn() -> #original_type>,
         }
     };
 
-    let struct_inst: syn::Pat = if is_tuple_struct {
-        // If the fields are unnamed, we create a tuple-struct
-        syn::parse_quote! {
-            #original_name (
-                #(<#field_types as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#field_idents),)*
-            )
-        }
-    } else {
-        syn::parse_quote! {
-            #original_name {
-                #(#field_members: <#field_types as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#field_idents),)*
-                #(#skipped_field_names: std::default::Default::default(),)*
-            }
+    // tuple structs are also instantiated with braces, using the field indices as members
+    let struct_inst: syn::Expr = syn::parse_quote! {
+        #value_path {
+            #(#required_members: #required_values,)*
+            #(#optional_members: #optional_idents.map(|v| #optional_values).unwrap_or_else(#optional_defaults),)*
+            #(#defaulted_members: #defaulted_defaults(),)*
         }
     };
 
     let iterator_impl = quote! {
-        impl<'a> #iterator_name<'a> {
+        impl #iterator_impl_generics #iterator_name #iterator_ty_generics #where_clause {
             #[inline]
-            fn return_next(&mut self) -> Option<#original_name> {
-                if let (#(
-                    Some(#field_idents),
-                )*) = (
-                    #(self.#field_idents.next(),)*
-                )
-                { Some(#struct_inst) }
-                else { None }
+            fn return_next(&mut self) -> #value_type {
+                #(let #required_idents = self.#required_idents.next().unwrap();)*
+                #(let #optional_idents = self.#optional_idents.as_mut().and_then(|i| i.next());)*
+                #struct_inst
             }
 
             #[inline]
             fn consume_next(&mut self) {
-                #(let _ = self.#field_idents.next();)*
+                #(let _ = self.#required_idents.next();)*
+                #(let _ = self.#optional_idents.as_mut().and_then(|i| i.next());)*
             }
         }
     };
 
     let iterator_iterator_impl = quote! {
-        impl<'a> Iterator for #iterator_name<'a> {
-            type Item = Option<#original_name>;
+        impl #iterator_impl_generics Iterator for #iterator_name #iterator_ty_generics #where_clause {
+            type Item = Option<#value_type>;
 
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                if !self.has_validity {
-                    self.return_next().map(|y| Some(y))
+                if self.remaining == 0 {
+                    return None;
                 }
-                else {
-                    let is_valid = self.validity_iter.next();
-                    is_valid.map(|x| if x { self.return_next() } else { self.consume_next(); None })
+                self.remaining -= 1;
+
+                if !self.has_validity || self.validity_iter.next().unwrap_or(false) {
+                    Some(Some(self.return_next()))
+                } else {
+                    self.consume_next();
+                    Some(None)
                 }
             }
         }
     };
 
     // Special case for single-field (tuple) structs.
-    if input.fields.len() == 1 && input.is_transparent {
-        let first_type = &field_types[0];
-
-        let deser_body_mapper = if is_tuple_struct {
-            quote! { #original_name }
-        } else {
-            let first_name = &field_members[0];
-            quote! { |v| #original_name { #first_name: v } }
+    if input.is_transparent {
+        let first_type = &serialized.types[0];
+
+        let first_name = &serialized.members[0];
+        let first_value = &serialized.deserialize_values(&[quote!(v)])[0];
+        let deser_body_mapper = quote! {
+            |v| #value_path {
+                #first_name: #first_value,
+                #(#defaulted_members: #defaulted_defaults(),)*
+            }
         };
 
         // Everything delegates to first field.
         quote! {
-            impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
+            impl #impl_generics arrow_convert::deserialize::ArrowDeserialize for #original_name #ty_generics #where_clause {
                 type ArrayType = <#first_type as arrow_convert::deserialize::ArrowDeserialize>::ArrayType;
 
                 #[inline]
-                fn arrow_deserialize<'a>(v: <Self::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Item<'a>) -> Option<Self> {
-                    <#first_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(#deser_body_mapper)
+                fn arrow_deserialize<'a>(v: <Self::ArrayType as arrow_convert::deserialize::ArrowArrayIterable>::Item<'a>) -> Option<#value_type> {
+                    // a nullable field maps nulls to a value of the wrapper rather than to a missing value
+                    if <#first_type as arrow_convert::field::ArrowField>::is_nullable() {
+                        Some(<#first_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(v)).map(#deser_body_mapper)
+                    } else {
+                        <#first_type as arrow_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(#deser_body_mapper)
+                    }
+                }
+
+                #[inline]
+                fn is_deserializable_from(data_type: &arrow::datatypes::DataType) -> bool {
+                    <#first_type as arrow_convert::deserialize::ArrowDeserialize>::is_deserializable_from(data_type)
+                }
+
+                #[inline]
+                fn unknown_fields(data_type: &arrow::datatypes::DataType) -> Vec<String> {
+                    <#first_type as arrow_convert::deserialize::ArrowDeserialize>::unknown_fields(data_type)
+                }
+
+                #[inline]
+                fn validate_array(array: &dyn arrow::array::Array) -> std::result::Result<(), String> {
+                    <#first_type as arrow_convert::deserialize::ArrowDeserialize>::validate_array(array)
                 }
+
+                #validate_impl
             }
         }
     } else {
         let field_arrow_deserialize_impl = quote! {
-            impl arrow_convert::deserialize::ArrowDeserialize for #original_name {
-                type ArrayType = #array_name;
+            impl #impl_generics arrow_convert::deserialize::ArrowDeserialize for #original_name #ty_generics #where_clause {
+                type ArrayType = #array_name #ty_generics;
 
                 #[inline]
-                fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
+                fn arrow_deserialize<'a>(v: Option<#value_type>) -> Option<#value_type> {
                     v
                 }
+
+                fn is_deserializable_from(data_type: &arrow::datatypes::DataType) -> bool {
+                    use arrow_convert::deserialize::ArrowDeserialize;
+                    use arrow_convert::field::ArrowField;
+
+                    let arrow::datatypes::DataType::Struct(fields) = data_type else {
+                        return false;
+                    };
+
+                    #deny_unknown_fields_check
+                    // the required columns are matched by name, so they may be in any order
+                    let required: &[&[&str]] = &[#(&#required_name_lists),*];
+                    if required.iter().any(|names| names.iter().all(|name| fields.find(name).is_none())) {
+                        return false;
+                    }
+
+                    #(
+                        if let Some((_, f)) = #deserialized_name_lists.iter().find_map(|name| fields.find(name)) {
+                            if (!#deserialized_forced_nullable && f.is_nullable() != <#deserialized_types as ArrowField>::is_nullable())
+                                || !#deserialized_traits::is_deserializable_from(f.data_type())
+                            {
+                                return false;
+                            }
+                        }
+                    )*
+
+                    true
+                }
+
+                fn unknown_fields(data_type: &arrow::datatypes::DataType) -> Vec<String> {
+                    use arrow_convert::deserialize::ArrowDeserialize;
+
+                    let arrow::datatypes::DataType::Struct(fields) = data_type else {
+                        return vec![];
+                    };
+
+                    let mut unknown: Vec<String> = vec![];
+                    #deny_unknown_fields_impl
+                    // the unknown columns of struct fields are reported as well
+                    #(
+                        if let Some((_, f)) = #deserialized_name_lists.iter().find_map(|name| fields.find(name)) {
+                            unknown.extend(
+                                #deserialized_traits::unknown_fields(f.data_type())
+                                    .into_iter()
+                                    .map(|name| format!("{}.{}", f.name(), name)),
+                            );
+                        }
+                    )*
+                    unknown
+                }
+
+                fn validate_array(array: &dyn arrow::array::Array) -> std::result::Result<(), String> {
+                    use arrow::array::AsArray;
+                    use arrow_convert::deserialize::ArrowDeserialize;
+
+                    let Some(array) = array.as_struct_opt() else {
+                        return Ok(());
+                    };
+                    #(
+                        if let Some(column) = #deserialized_name_lists.iter().find_map(|name| array.column_by_name(name)) {
+                            #deserialized_traits::validate_array(column.as_ref())
+                                .map_err(|e| format!("{}: {}", #deserialized_names, e))?;
+                        }
+                    )*
+                    Ok(())
+                }
+
+                #validate_impl
             }
         };
 
@@ -544,6 +1636,7 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
             iterator_impl,
             iterator_iterator_impl,
             field_arrow_deserialize_impl,
+            view_impl,
         ])
     }
 }
@@ -1,23 +1,102 @@
 use proc_macro2::Span;
-use proc_macro_error2::abort;
+use proc_macro_error2::{abort, ResultExt};
+use quote::format_ident;
 
 use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
 use syn::{DeriveInput, Ident, Lit, Meta, Visibility};
 
+use crate::case::RenameRule;
+
 pub const ARROW_FIELD: &str = "arrow_field";
 pub const FIELD_TYPE: &str = "type";
 pub const FIELD_NAME: &str = "name";
 pub const FIELD_SKIP: &str = "skip";
+pub const FIELD_SKIP_SERIALIZING: &str = "skip_serializing";
+pub const FIELD_SKIP_DESERIALIZING: &str = "skip_deserializing";
+pub const FIELD_DEFAULT: &str = "default";
+pub const FIELD_SERIALIZE_WITH: &str = "serialize_with";
+pub const FIELD_DESERIALIZE_WITH: &str = "deserialize_with";
+pub const FIELD_METADATA: &str = "metadata";
+pub const FIELD_NULLABLE: &str = "nullable";
+pub const FIELD_DICTIONARY: &str = "dictionary";
+pub const FIELD_LARGE: &str = "large";
+pub const FIELD_AS_LIST: &str = "as_list";
+pub const FIELD_RUN_END_ENCODED: &str = "run_end_encoded";
+pub const FIELD_FIXED_SIZE: &str = "fixed_size";
+pub const FIELD_TIMESTAMP: &str = "timestamp";
+pub const FIELD_DECIMAL: &str = "decimal";
+pub const DECIMAL_PRECISION: &str = "precision";
+pub const DECIMAL_SCALE: &str = "scale";
+pub const TIMESTAMP_UNIT: &str = "unit";
+pub const TIMESTAMP_TZ: &str = "tz";
+/// The values of the timestamp `unit` and their placeholder types
+pub const TIMESTAMP_UNITS: &[(&str, &str)] = &[
+    ("s", "TimestampSeconds"),
+    ("ms", "TimestampMilliseconds"),
+    ("us", "TimestampMicroseconds"),
+    ("ns", "TimestampNanoseconds"),
+];
 pub const UNION_TYPE: &str = "type";
+pub const UNION_MODE: &str = "mode";
 pub const UNION_TYPE_SPARSE: &str = "sparse";
 pub const UNION_TYPE_DENSE: &str = "dense";
+pub const ENUM_MODE_DICTIONARY: &str = "dictionary";
+pub const UNIT_MODE_NULL: &str = "null";
+pub const UNIT_MODE_STRUCT: &str = "struct";
+pub const ENUM_MODE_STRUCT: &str = "struct";
+pub const ENUM_REPR: &str = "repr";
+/// The integer types supported by `repr` and as dictionary keys
+pub const INTEGER_TYPES: &[&str] = &["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
+/// The integer types supported as run ends
+pub const RUN_END_TYPES: &[&str] = &["i16", "i32", "i64"];
+pub const VARIANT_FALLBACK: &str = "fallback";
+pub const VARIANT_TYPE_ID: &str = "type_id";
 pub const TRANSPARENT: &str = "transparent";
+pub const SCHEMA_METADATA: &str = "schema_metadata";
+pub const DENY_UNKNOWN_FIELDS: &str = "deny_unknown_fields";
+pub const RENAME_ALL: &str = "rename_all";
+pub const BUILDER: &str = "builder";
+pub const VIEW: &str = "view";
+pub const REMOTE: &str = "remote";
+pub const VALIDATE: &str = "validate";
+pub const FIELD_ALIAS: &str = "alias";
+pub const FIELD_INDEX: &str = "index";
+pub const COMPUTED: &str = "computed";
+pub const COMPUTED_WITH: &str = "with";
+pub const FIELD_PARQUET: &str = "parquet";
+pub const PARQUET_COMPRESSION: &str = "compression";
+pub const PARQUET_ENCODING: &str = "encoding";
+pub const PARQUET_DICTIONARY: &str = "dictionary";
+pub const PARQUET_BLOOM_FILTER: &str = "bloom_filter";
+/// The Parquet compression codecs, which may be followed by a level such as `zstd(3)`
+pub const PARQUET_CODECS: &[&str] = &[
+    "uncompressed",
+    "snappy",
+    "gzip",
+    "lzo",
+    "brotli",
+    "lz4",
+    "zstd",
+    "lz4_raw",
+];
+/// The Parquet encodings of the values that aren't dictionary encoded
+pub const PARQUET_ENCODINGS: &[&str] = &[
+    "plain",
+    "rle",
+    "delta_binary_packed",
+    "delta_length_byte_array",
+    "delta_byte_array",
+    "byte_stream_split",
+];
 
 pub struct DeriveCommon {
     /// The input name
     pub name: Ident,
     /// The overall visibility
     pub visibility: Visibility,
+    /// The generic parameters
+    pub generics: syn::Generics,
 }
 
 pub struct DeriveStruct {
@@ -25,6 +104,25 @@ pub struct DeriveStruct {
     /// The list of fields in the struct
     pub fields: Vec<DeriveField>,
     pub is_transparent: bool,
+    /// Whether the struct has no fields
+    pub is_unit: bool,
+    /// Whether the unit struct is stored as a `Struct` without fields rather than as `Null`
+    pub is_empty_struct: bool,
+    pub rename_all: Option<RenameRule>,
+    /// The key/value metadata of the schema of a `RecordBatch` of the struct
+    pub schema_metadata: Vec<(String, String)>,
+    /// Whether deserializing from arrays with columns that don't belong to a field returns an error
+    pub deny_unknown_fields: bool,
+    /// The name of the typed builder generated for the struct
+    pub builder: Option<Ident>,
+    /// The name of the borrowed view struct generated for the struct
+    pub view: Option<Ident>,
+    /// The foreign type the struct mirrors, which is the `Type` of its trait implementations
+    pub remote: Option<syn::Path>,
+    /// The function checking a deserialized struct
+    pub validate: Option<syn::Path>,
+    /// The columns of the `computed` attributes, which are serialized after the fields but aren't struct fields
+    pub computed: Vec<DeriveField>,
 }
 
 pub struct DeriveEnum {
@@ -32,12 +130,41 @@ pub struct DeriveEnum {
     /// The list of variants in the enum
     pub variants: Vec<DeriveVariant>,
     pub is_dense: bool,
+    /// Whether the enum is stored as a dictionary of its variant names
+    pub is_dictionary: bool,
+    /// Whether the enum is stored as a struct of a tag column and a nullable column per variant
+    pub is_struct: bool,
+    /// The integer type the discriminants of the enum are stored as
+    pub repr: Option<syn::Type>,
+    pub rename_all: Option<RenameRule>,
 }
 
 /// All container attributes
 pub struct ContainerAttrs {
     pub is_dense: Option<bool>,
+    pub is_dictionary: bool,
+    pub is_empty_struct: Option<bool>,
+    pub repr: Option<syn::Type>,
     pub transparent: Option<Span>,
+    pub rename_all: Option<RenameRule>,
+    pub schema_metadata: Vec<(String, String)>,
+    pub deny_unknown_fields: Option<Span>,
+    /// The span of the `builder` attribute and the name of the builder if it's set
+    pub builder: Option<(Span, Option<syn::LitStr>)>,
+    /// The span of the `view` attribute and the name of the view if it's set
+    pub view: Option<(Span, Option<syn::LitStr>)>,
+    pub remote: Option<syn::Path>,
+    pub validate: Option<syn::Path>,
+    pub computed: Vec<ComputedAttr>,
+}
+
+/// The `computed` container attribute
+pub struct ComputedAttr {
+    pub span: Span,
+    /// The function computing the value of the column from a reference to the struct
+    pub with: syn::Path,
+    pub field_type: syn::Type,
+    pub name: Option<syn::LitStr>,
 }
 
 /// All field attributes
@@ -45,19 +172,97 @@ pub struct FieldAttrs {
     pub field_type: Option<syn::Type>,
     pub field_name: Option<String>,
     pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    pub default: Option<syn::Path>,
+    pub serialize_with: Option<syn::Path>,
+    pub deserialize_with: Option<syn::Path>,
+    pub metadata: Vec<(String, String)>,
+    pub nullable: bool,
+    pub dictionary: Option<syn::Type>,
+    pub large: bool,
+    pub as_list: Option<Span>,
+    pub run_end_encoded: Option<syn::Type>,
+    pub fixed_size: Option<syn::LitInt>,
+    pub timestamp: Option<TimestampAttr>,
+    pub decimal: Option<DecimalAttr>,
+    pub fallback: bool,
+    pub type_id: Option<syn::LitInt>,
+    pub validate: Option<syn::Path>,
+    pub aliases: Vec<syn::LitStr>,
+    pub index: Option<syn::LitInt>,
+    pub parquet: Option<ParquetAttr>,
+}
+
+/// The `parquet` field attribute
+#[derive(Default)]
+pub struct ParquetAttr {
+    pub compression: Option<String>,
+    pub encoding: Option<String>,
+    pub dictionary: Option<bool>,
+    pub bloom_filter: Option<bool>,
+}
+
+/// The `decimal` field attribute
+pub struct DecimalAttr {
+    pub span: Span,
+    pub precision: syn::LitInt,
+    pub scale: syn::LitInt,
+}
+
+/// The `timestamp` field attribute
+pub struct TimestampAttr {
+    pub span: Span,
+    /// The placeholder type of the unit
+    pub placeholder: Ident,
+    pub tz: Option<syn::LitStr>,
 }
 
 pub struct DeriveField {
     pub syn: syn::Field,
     pub field_type: syn::Type,
     pub field_name: Option<String>,
-    pub skip: bool,
+    /// The field is not written to Arrow
+    pub skip_serializing: bool,
+    /// The field is not read from Arrow and is set to its default value
+    pub skip_deserializing: bool,
+    /// The function returning the default value of the field, if the column may be missing
+    pub default: Option<syn::Path>,
+    /// The function converting a reference to the field to the `Type` of the field type override
+    pub serialize_with: Option<syn::Path>,
+    /// The function converting the `Type` of the field type override to the field
+    pub deserialize_with: Option<syn::Path>,
+    /// The key/value metadata attached to the Arrow field
+    pub metadata: Vec<(String, String)>,
+    /// The Arrow field is nullable even though the field type isn't
+    pub nullable: bool,
+    /// The marker type and name of the timezone of the `timestamp` attribute, unless it has no timezone or is `UTC`
+    pub time_zone: Option<(Ident, String)>,
+    /// The function checking the deserialized field
+    pub validate: Option<syn::Path>,
+    /// The other names of the column the field is read from
+    pub aliases: Vec<syn::LitStr>,
+    /// The position of the column among the serialized columns
+    pub index: Option<syn::LitInt>,
+    /// The function computing the column from a reference to the struct, for the columns of the `computed`
+    /// attribute
+    pub computed: Option<syn::Path>,
+    /// The Parquet writer settings of the column
+    pub parquet: Option<ParquetAttr>,
 }
 
 pub struct DeriveVariant {
     pub syn: syn::Variant,
     pub field_type: syn::Type,
+    pub field_name: Option<String>,
     pub is_unit: bool,
+    /// Unknown values are deserialized to this variant
+    pub is_fallback: bool,
+    /// The union type id of the variant, which defaults to its index
+    pub type_id: Option<syn::LitInt>,
+    /// The fields of struct variants and of tuple variants with more than one field, which are stored
+    /// as a `Struct` child
+    pub fields: Vec<DeriveField>,
 }
 
 impl DeriveCommon {
@@ -65,6 +270,7 @@ impl DeriveCommon {
         DeriveCommon {
             name: input.ident.clone(),
             visibility: input.vis.clone(),
+            generics: input.generics.clone(),
         }
     }
 
@@ -84,86 +290,647 @@ impl DeriveCommon {
 impl ContainerAttrs {
     pub fn from_ast(attrs: &[syn::Attribute]) -> ContainerAttrs {
         let mut is_dense: Option<bool> = None;
+        let mut is_dictionary = false;
+        let mut is_empty_struct: Option<bool> = None;
+        let mut repr: Option<syn::Type> = None;
         let mut is_transparent: Option<Span> = None;
+        let mut rename_all: Option<RenameRule> = None;
+        let mut schema_metadata: Vec<(String, String)> = vec![];
+        let mut deny_unknown_fields: Option<Span> = None;
+        let mut builder: Option<(Span, Option<syn::LitStr>)> = None;
+        let mut view: Option<(Span, Option<syn::LitStr>)> = None;
+        let mut remote: Option<syn::Path> = None;
+        let mut validate: Option<syn::Path> = None;
+        let mut computed: Vec<ComputedAttr> = vec![];
 
         for attr in attrs {
             if attr.path().is_ident(ARROW_FIELD) {
-                let _ = attr.parse_nested_meta(|meta| {
-                    if let Meta::List(list) = &attr.meta {
-                        list.parse_nested_meta(|nested| {
-                            if nested.path.is_ident(TRANSPARENT) {
-                                is_transparent = Some(nested.path.span());
-                                Ok(())
-                            } else if nested.path.is_ident(UNION_TYPE) {
+                if let Meta::List(list) = &attr.meta {
+                    list.parse_nested_meta(|nested| {
+                        if nested.path.is_ident(TRANSPARENT) {
+                            is_transparent = Some(nested.path.span());
+                            Ok(())
+                        } else if nested.path.is_ident(DENY_UNKNOWN_FIELDS) {
+                            deny_unknown_fields = Some(nested.path.span());
+                            Ok(())
+                        } else if nested.path.is_ident(BUILDER) {
+                            // the name defaults to `<Name>Builder`
+                            let name = if nested.input.peek(syn::Token![=]) {
                                 let value = nested.value()?;
                                 let Lit::Str(string) = value.parse()? else {
-                                    return Err(nested.error("Unexpected value for mode"));
+                                    return Err(nested.error("Unexpected value for builder"));
                                 };
-
-                                match string.value().as_ref() {
-                                    UNION_TYPE_DENSE => {
-                                        is_dense = Some(true);
-                                        Ok(())
-                                    }
-                                    UNION_TYPE_SPARSE => {
-                                        is_dense = Some(false);
-                                        Ok(())
-                                    }
-                                    _ => Err(nested.error("Unexpected value for mode")),
-                                }
+                                Some(string)
                             } else {
-                                Err(meta.error("Unexpected attribute"))
+                                None
+                            };
+                            builder = Some((nested.path.span(), name));
+                            Ok(())
+                        } else if nested.path.is_ident(VIEW) {
+                            // the name defaults to `<Name>View`
+                            let name = if nested.input.peek(syn::Token![=]) {
+                                let value = nested.value()?;
+                                let Lit::Str(string) = value.parse()? else {
+                                    return Err(nested.error("Unexpected value for view"));
+                                };
+                                Some(string)
+                            } else {
+                                None
+                            };
+                            view = Some((nested.path.span(), name));
+                            Ok(())
+                        } else if nested.path.is_ident(REMOTE) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected value for remote"));
+                            };
+                            remote = Some(string.parse()?);
+                            Ok(())
+                        } else if nested.path.is_ident(VALIDATE) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected value for validate"));
+                            };
+                            validate = Some(string.parse()?);
+                            Ok(())
+                        } else if nested.path.is_ident(COMPUTED) {
+                            let mut with: Option<syn::Path> = None;
+                            let mut field_type: Option<syn::Type> = None;
+                            let mut name: Option<syn::LitStr> = None;
+                            nested.parse_nested_meta(|entry| {
+                                let value = entry.value()?;
+                                let Lit::Str(string) = value.parse()? else {
+                                    return Err(entry.error("Unexpected computed value"));
+                                };
+                                if entry.path.is_ident(COMPUTED_WITH) {
+                                    with = Some(string.parse()?);
+                                } else if entry.path.is_ident(FIELD_TYPE) {
+                                    field_type = Some(syn::parse_str(&string.value())?);
+                                } else if entry.path.is_ident(FIELD_NAME) {
+                                    name = Some(string);
+                                } else {
+                                    return Err(entry.error("Unexpected computed attribute"));
+                                }
+                                Ok(())
+                            })?;
+                            let (Some(with), Some(field_type)) = (with, field_type) else {
+                                abort!(
+                                    nested.path.span(),
+                                    "`{}` requires `{}` and `{}`",
+                                    COMPUTED,
+                                    COMPUTED_WITH,
+                                    FIELD_TYPE
+                                );
+                            };
+                            computed.push(ComputedAttr {
+                                span: nested.path.span(),
+                                with,
+                                field_type,
+                                name,
+                            });
+                            Ok(())
+                        } else if nested.path.is_ident(SCHEMA_METADATA) {
+                            parse_metadata(&nested, &mut schema_metadata)
+                        } else if nested.path.is_ident(RENAME_ALL) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected value for rename_all"));
+                            };
+                            rename_all = Some(RenameRule::from_str(&string.value()).unwrap_or_else(|| {
+                                abort!(
+                                    string.span(),
+                                    "Unknown value for rename_all, expected one of {}",
+                                    RenameRule::names()
+                                )
+                            }));
+                            Ok(())
+                        } else if nested.path.is_ident(ENUM_REPR) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected value for repr"));
+                            };
+                            if !INTEGER_TYPES.contains(&string.value().as_str()) {
+                                abort!(
+                                    string.span(),
+                                    "Unknown value for repr, expected one of {}",
+                                    INTEGER_TYPES.join(", ")
+                                );
                             }
-                        })?;
-                    };
+                            repr = Some(syn::parse_str(&string.value())?);
+                            Ok(())
+                        } else if nested.path.is_ident(UNION_TYPE) || nested.path.is_ident(UNION_MODE) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected value for mode"));
+                            };
 
-                    Ok(())
-                });
+                            match string.value().as_ref() {
+                                UNION_TYPE_DENSE => {
+                                    is_dense = Some(true);
+                                    Ok(())
+                                }
+                                UNION_TYPE_SPARSE => {
+                                    is_dense = Some(false);
+                                    Ok(())
+                                }
+                                ENUM_MODE_DICTIONARY => {
+                                    is_dictionary = true;
+                                    Ok(())
+                                }
+                                UNIT_MODE_NULL => {
+                                    is_empty_struct = Some(false);
+                                    Ok(())
+                                }
+                                UNIT_MODE_STRUCT => {
+                                    is_empty_struct = Some(true);
+                                    Ok(())
+                                }
+                                _ => abort!(
+                                    string.span(),
+                                    "Unknown value for mode, expected \"{}\", \"{}\", \"{}\", \"{}\" or \"{}\"",
+                                    UNION_TYPE_DENSE,
+                                    UNION_TYPE_SPARSE,
+                                    ENUM_MODE_DICTIONARY,
+                                    UNIT_MODE_NULL,
+                                    UNIT_MODE_STRUCT
+                                ),
+                            }
+                        } else {
+                            Err(nested.error("Unexpected attribute"))
+                        }
+                    })
+                    .unwrap_or_abort();
+                }
             }
         }
 
         ContainerAttrs {
             is_dense,
+            is_dictionary,
+            is_empty_struct,
+            repr,
             transparent: is_transparent,
+            rename_all,
+            schema_metadata,
+            deny_unknown_fields,
+            builder,
+            view,
+            remote,
+            validate,
+            computed,
+        }
+    }
+}
+
+/// Returns `ty` converted by `f`, or `Option<T>` with `T` converted by `f` if `ty` is an `Option`, so that type
+/// conversions keep the field nullable.
+fn map_option_inner(ty: &syn::Type, f: impl FnOnce(&syn::Type) -> syn::Type) -> syn::Type {
+    match type_argument(ty, "Option") {
+        Some(inner) => {
+            let inner = f(inner);
+            syn::parse_quote!(Option<#inner>)
+        }
+        None => f(ty),
+    }
+}
+
+/// Returns the single type argument of `ty` if it's a path to the type `name`, such as `Vec<T>`.
+fn type_argument<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.iter().collect::<Vec<_>>()[..] {
+        [syn::GenericArgument::Type(inner)] => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns the large offset type override of `ty`, if `ty` is `String`, `Vec<u8>` or `Vec<T>`.
+fn large_type(ty: &syn::Type) -> Option<syn::Type> {
+    if let syn::Type::Path(path) = ty {
+        if path.path.is_ident("String") {
+            return Some(syn::parse_quote!(arrow_convert::field::LargeString));
+        }
+    }
+    let inner = type_argument(ty, "Vec")?;
+    match inner {
+        syn::Type::Path(path) if path.path.is_ident("u8") => Some(syn::parse_quote!(arrow_convert::field::LargeBinary)),
+        _ => Some(syn::parse_quote!(arrow_convert::field::LargeVec<#inner>)),
+    }
+}
+
+/// Returns the `List<UInt8>` type override of `ty`, if `ty` is `Vec<u8>`.
+fn byte_list_type(ty: &syn::Type) -> Option<syn::Type> {
+    match type_argument(ty, "Vec")? {
+        syn::Type::Path(path) if path.path.is_ident("u8") => Some(syn::parse_quote!(arrow_convert::field::ByteList)),
+        _ => None,
+    }
+}
+
+/// Returns the fixed size type override of `ty` with `size` elements, if `ty` is `Vec<T>` or `[T; N]`.
+///
+/// Arrays are kept as is since they're already mapped to fixed size types, after checking that the sizes match.
+fn fixed_size_type(ty: &syn::Type, size: &syn::LitInt) -> Option<syn::Type> {
+    if let syn::Type::Array(array) = ty {
+        if let syn::Expr::Lit(syn::ExprLit { lit: Lit::Int(len), .. }) = &array.len {
+            if len.base10_parse::<i32>().ok() != size.base10_parse::<i32>().ok() {
+                abort!(
+                    size.span(),
+                    "`{}` doesn't match the length {} of the array",
+                    FIELD_FIXED_SIZE,
+                    len
+                );
+            }
         }
+        return Some(ty.clone());
+    }
+    let inner = type_argument(ty, "Vec")?;
+    match inner {
+        syn::Type::Path(path) if path.path.is_ident("u8") => {
+            Some(syn::parse_quote!(arrow_convert::field::FixedSizeBinary<#size>))
+        }
+        _ => Some(syn::parse_quote!(arrow_convert::field::FixedSizeVec<#inner, #size>)),
+    }
+}
+
+/// Returns the decimal type override of `ty`, if `ty` is `i128` or `Decimal`.
+fn decimal_type(ty: &syn::Type, precision: &syn::LitInt, scale: &syn::LitInt) -> Option<syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if !segment.arguments.is_empty() {
+        return None;
+    }
+    if segment.ident == "i128" {
+        Some(syn::parse_quote!(arrow_convert::field::I128<#precision, #scale>))
+    } else if segment.ident == "Decimal" {
+        Some(syn::parse_quote!(arrow_convert::field::ScaledDecimal<#precision, #scale>))
+    } else {
+        None
+    }
+}
+
+/// Finds the paths referring to the type `name` or `Self`.
+struct FindType<'a> {
+    name: &'a Ident,
+    found: bool,
+}
+
+impl VisitMut for FindType<'_> {
+    fn visit_type_path_mut(&mut self, ty: &mut syn::TypePath) {
+        if ty.qself.is_none() && ty.path.leading_colon.is_none() && ty.path.segments.len() == 1 {
+            let ident = &ty.path.segments[0].ident;
+            self.found |= ident == self.name || ident == "Self";
+        }
+        syn::visit_mut::visit_type_path_mut(self, ty);
+    }
+}
+
+/// Aborts if `ty` refers to the type `name` being derived, since Arrow data types can't be recursive.
+fn check_not_recursive(ty: &syn::Type, name: &Ident) {
+    let mut finder = FindType { name, found: false };
+    finder.visit_type_mut(&mut ty.clone());
+    if finder.found {
+        abort!(
+            ty.span(),
+            "Recursive types are not supported, since Arrow data types can't refer to themselves. Store the nested values \
+             in a separate column, for example as indices into a flat list, or skip the field"
+        );
     }
 }
 
+/// Parses the `key = "value"` entries of a metadata attribute into `metadata`.
+fn parse_metadata(meta: &syn::meta::ParseNestedMeta, metadata: &mut Vec<(String, String)>) -> syn::Result<()> {
+    meta.parse_nested_meta(|entry| {
+        let Some(key) = entry.path.get_ident().map(|i| i.to_string()) else {
+            return Err(entry.error("Unexpected metadata key"));
+        };
+        let value = entry.value()?;
+        let Lit::Str(string) = value.parse()? else {
+            return Err(entry.error("Unexpected metadata value"));
+        };
+        if metadata.iter().any(|(k, _)| *k == key) {
+            abort!(entry.path.span(), "Duplicate metadata key `{}`", key);
+        }
+        metadata.push((key, string.value()));
+        Ok(())
+    })
+}
+
+/// Parses the settings of a `parquet` attribute, checking the names of the codecs and encodings.
+fn parse_parquet(meta: &syn::meta::ParseNestedMeta) -> syn::Result<ParquetAttr> {
+    let mut parquet = ParquetAttr::default();
+    meta.parse_nested_meta(|entry| {
+        let value = entry.value()?;
+        if entry.path.is_ident(PARQUET_DICTIONARY) || entry.path.is_ident(PARQUET_BLOOM_FILTER) {
+            let Lit::Bool(enabled) = value.parse()? else {
+                return Err(entry.error("Expected a boolean"));
+            };
+            if entry.path.is_ident(PARQUET_DICTIONARY) {
+                parquet.dictionary = Some(enabled.value);
+            } else {
+                parquet.bloom_filter = Some(enabled.value);
+            }
+            return Ok(());
+        }
+        let Lit::Str(string) = value.parse()? else {
+            return Err(entry.error("Unexpected parquet value"));
+        };
+        let name = string.value().to_lowercase();
+        if entry.path.is_ident(PARQUET_COMPRESSION) {
+            let codec = name.split('(').next().unwrap_or_default();
+            if !PARQUET_CODECS.contains(&codec) {
+                abort!(
+                    string.span(),
+                    "Unknown compression, expected one of {}",
+                    PARQUET_CODECS.join(", ")
+                );
+            }
+            parquet.compression = Some(name);
+        } else if entry.path.is_ident(PARQUET_ENCODING) {
+            if !PARQUET_ENCODINGS.contains(&name.as_str()) {
+                abort!(
+                    string.span(),
+                    "Unknown encoding, expected one of {}",
+                    PARQUET_ENCODINGS.join(", ")
+                );
+            }
+            parquet.encoding = Some(name);
+        } else {
+            return Err(entry.error("Unexpected parquet attribute"));
+        }
+        Ok(())
+    })?;
+    Ok(parquet)
+}
+
 impl FieldAttrs {
     pub fn from_ast(input: &[syn::Attribute]) -> FieldAttrs {
         let mut field_type: Option<syn::Type> = None;
         let mut field_name: Option<String> = None;
         let mut skip = false;
+        let mut skip_serializing = false;
+        let mut skip_deserializing = false;
+        let mut default: Option<syn::Path> = None;
+        let mut serialize_with: Option<syn::Path> = None;
+        let mut deserialize_with: Option<syn::Path> = None;
+        let mut metadata: Vec<(String, String)> = vec![];
+        let mut nullable = false;
+        let mut dictionary: Option<syn::Type> = None;
+        let mut large = false;
+        let mut as_list: Option<Span> = None;
+        let mut run_end_encoded: Option<syn::Type> = None;
+        let mut fixed_size: Option<syn::LitInt> = None;
+        let mut timestamp: Option<TimestampAttr> = None;
+        let mut decimal: Option<DecimalAttr> = None;
+        let mut fallback = false;
+        let mut type_id: Option<syn::LitInt> = None;
+        let mut index: Option<syn::LitInt> = None;
+        let mut validate: Option<syn::Path> = None;
+        let mut aliases: Vec<syn::LitStr> = vec![];
+        let mut parquet: Option<ParquetAttr> = None;
 
         for attr in input {
             if attr.path().is_ident(ARROW_FIELD) {
-                attr.parse_nested_meta(|meta| {
-                    let Meta::List(list) = &attr.meta else {
-                        return Err(meta.error("Unexpected attribute"));
-                    };
-
+                if let Meta::List(list) = &attr.meta {
                     list.parse_nested_meta(|nested| {
                         if nested.path.is_ident(FIELD_SKIP) {
                             skip = true;
+                        } else if nested.path.is_ident(FIELD_SKIP_SERIALIZING) {
+                            skip_serializing = true;
+                        } else if nested.path.is_ident(FIELD_SKIP_DESERIALIZING) {
+                            skip_deserializing = true;
+                        } else if nested.path.is_ident(FIELD_DICTIONARY) {
+                            // the key type defaults to `i32`
+                            let key = if nested.input.peek(syn::Token![=]) {
+                                let value = nested.value()?;
+                                let Lit::Str(string) = value.parse()? else {
+                                    return Err(nested.error("Unexpected attribute"));
+                                };
+                                if !INTEGER_TYPES.contains(&string.value().as_str()) {
+                                    abort!(
+                                        string.span(),
+                                        "Unknown dictionary key type, expected one of {}",
+                                        INTEGER_TYPES.join(", ")
+                                    );
+                                }
+                                string.value()
+                            } else {
+                                "i32".to_string()
+                            };
+                            dictionary = Some(syn::parse_str(&key)?);
+                        } else if nested.path.is_ident(FIELD_FIXED_SIZE) {
+                            let value = nested.value()?;
+                            let Lit::Int(size) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            if size.base10_parse::<i32>().map_or(true, |size| size <= 0) {
+                                abort!(size.span(), "`{}` must be a positive `i32`", FIELD_FIXED_SIZE);
+                            }
+                            fixed_size = Some(size);
+                        } else if nested.path.is_ident(FIELD_TIMESTAMP) {
+                            // the unit defaults to nanoseconds, like `NaiveDateTime`
+                            let mut placeholder = Ident::new("TimestampNanoseconds", Span::call_site());
+                            let mut tz: Option<syn::LitStr> = None;
+                            nested.parse_nested_meta(|entry| {
+                                let value = entry.value()?;
+                                let Lit::Str(string) = value.parse()? else {
+                                    return Err(entry.error("Unexpected timestamp value"));
+                                };
+                                if entry.path.is_ident(TIMESTAMP_UNIT) {
+                                    let Some((_, name)) = TIMESTAMP_UNITS.iter().find(|(u, _)| *u == string.value())
+                                    else {
+                                        abort!(
+                                            string.span(),
+                                            "Unknown timestamp unit, expected one of {}",
+                                            TIMESTAMP_UNITS.iter().map(|(u, _)| *u).collect::<Vec<_>>().join(", ")
+                                        );
+                                    };
+                                    placeholder = Ident::new(name, Span::call_site());
+                                } else if entry.path.is_ident(TIMESTAMP_TZ) {
+                                    tz = Some(string);
+                                } else {
+                                    return Err(entry.error("Unexpected timestamp attribute"));
+                                }
+                                Ok(())
+                            })?;
+                            timestamp = Some(TimestampAttr {
+                                span: nested.path.span(),
+                                placeholder,
+                                tz,
+                            });
+                        } else if nested.path.is_ident(FIELD_DECIMAL) {
+                            let mut precision: Option<syn::LitInt> = None;
+                            let mut scale: Option<syn::LitInt> = None;
+                            nested.parse_nested_meta(|entry| {
+                                let value = entry.value()?;
+                                // the scale can be negative
+                                let negative = value.peek(syn::Token![-]);
+                                if negative {
+                                    value.parse::<syn::Token![-]>()?;
+                                }
+                                let Lit::Int(int) = value.parse()? else {
+                                    return Err(entry.error("Unexpected decimal value"));
+                                };
+                                let int = if negative {
+                                    syn::LitInt::new(&format!("-{int}"), int.span())
+                                } else {
+                                    int
+                                };
+                                if entry.path.is_ident(DECIMAL_PRECISION) {
+                                    if !matches!(int.base10_parse::<u8>(), Ok(1..=38)) {
+                                        abort!(int.span(), "`{}` must be between 1 and 38", DECIMAL_PRECISION);
+                                    }
+                                    precision = Some(int);
+                                } else if entry.path.is_ident(DECIMAL_SCALE) {
+                                    if int.base10_parse::<i8>().is_err() {
+                                        abort!(int.span(), "`{}` must be an `i8`", DECIMAL_SCALE);
+                                    }
+                                    scale = Some(int);
+                                } else {
+                                    return Err(entry.error("Unexpected decimal attribute"));
+                                }
+                                Ok(())
+                            })?;
+                            let (Some(precision), Some(scale)) = (precision, scale) else {
+                                abort!(
+                                    nested.path.span(),
+                                    "`{}` requires both a `{}` and a `{}`",
+                                    FIELD_DECIMAL,
+                                    DECIMAL_PRECISION,
+                                    DECIMAL_SCALE
+                                );
+                            };
+                            if scale.base10_parse::<i8>().unwrap() as i16
+                                > precision.base10_parse::<u8>().unwrap() as i16
+                            {
+                                abort!(
+                                    scale.span(),
+                                    "`{}` can't be greater than `{}`",
+                                    DECIMAL_SCALE,
+                                    DECIMAL_PRECISION
+                                );
+                            }
+                            decimal = Some(DecimalAttr {
+                                span: nested.path.span(),
+                                precision,
+                                scale,
+                            });
+                        } else if nested.path.is_ident(FIELD_LARGE) {
+                            large = true;
+                        } else if nested.path.is_ident(FIELD_RUN_END_ENCODED) {
+                            // the run end type defaults to `i32`
+                            let run_end = if nested.input.peek(syn::Token![=]) {
+                                let value = nested.value()?;
+                                let Lit::Str(string) = value.parse()? else {
+                                    return Err(nested.error("Unexpected attribute"));
+                                };
+                                if !RUN_END_TYPES.contains(&string.value().as_str()) {
+                                    abort!(
+                                        string.span(),
+                                        "Unknown run end type, expected one of {}",
+                                        RUN_END_TYPES.join(", ")
+                                    );
+                                }
+                                string.value()
+                            } else {
+                                "i32".to_string()
+                            };
+                            run_end_encoded = Some(syn::parse_str(&run_end)?);
+                        } else if nested.path.is_ident(FIELD_AS_LIST) {
+                            as_list = Some(nested.path.span());
+                        } else if nested.path.is_ident(FIELD_NULLABLE) {
+                            nullable = true;
+                        } else if nested.path.is_ident(VARIANT_FALLBACK) {
+                            fallback = true;
+                        } else if nested.path.is_ident(VARIANT_TYPE_ID) {
+                            let value = nested.value()?;
+                            let Lit::Int(id) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            // Arrow only supports non-negative type ids
+                            if !matches!(id.base10_parse::<i8>(), Ok(0..)) {
+                                abort!(id.span(), "`{}` must be between 0 and 127", VARIANT_TYPE_ID);
+                            }
+                            type_id = Some(id);
+                        } else if nested.path.is_ident(FIELD_INDEX) {
+                            let value = nested.value()?;
+                            let Lit::Int(position) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            if position.base10_parse::<usize>().is_err() {
+                                abort!(
+                                    position.span(),
+                                    "`{}` must be a non-negative integer",
+                                    FIELD_INDEX
+                                );
+                            }
+                            index = Some(position);
+                        } else if nested.path.is_ident(FIELD_DEFAULT) {
+                            if nested.input.peek(syn::Token![=]) {
+                                let value = nested.value()?;
+                                let Lit::Str(string) = value.parse()? else {
+                                    return Err(nested.error("Unexpected attribute"));
+                                };
+                                default = Some(string.parse()?);
+                            } else {
+                                default = Some(syn::parse_quote!(std::default::Default::default));
+                            }
+                        } else if nested.path.is_ident(FIELD_SERIALIZE_WITH) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            serialize_with = Some(string.parse()?);
+                        } else if nested.path.is_ident(FIELD_DESERIALIZE_WITH) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            deserialize_with = Some(string.parse()?);
+                        } else if nested.path.is_ident(VALIDATE) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            validate = Some(string.parse()?);
+                        } else if nested.path.is_ident(FIELD_ALIAS) {
+                            let value = nested.value()?;
+                            let Lit::Str(string) = value.parse()? else {
+                                return Err(nested.error("Unexpected attribute"));
+                            };
+                            aliases.push(string);
+                        } else if nested.path.is_ident(FIELD_METADATA) {
+                            parse_metadata(&nested, &mut metadata)?;
+                        } else if nested.path.is_ident(FIELD_PARQUET) {
+                            parquet = Some(parse_parquet(&nested)?);
                         } else if nested.path.is_ident(FIELD_TYPE) {
                             let value = nested.value()?;
                             let Lit::Str(string) = value.parse()? else {
-                                return Err(meta.error("Unexpected attribute"));
+                                return Err(nested.error("Unexpected attribute"));
                             };
                             field_type = Some(syn::parse_str(&string.value())?);
                         } else if nested.path.is_ident(FIELD_NAME) {
                             let value = nested.value()?;
                             let Lit::Str(string) = value.parse()? else {
-                                return Err(meta.error("Unexpected attribute"));
+                                return Err(nested.error("Unexpected attribute"));
                             };
                             field_name = Some(string.value());
                         } else {
-                            return Err(meta.error("Unexpected attribute"));
+                            return Err(nested.error("Unexpected attribute"));
                         }
                         Ok(())
                     })
-                })
-                .unwrap_or_default();
+                    .unwrap_or_abort();
+                }
             }
         }
 
@@ -171,6 +938,26 @@ impl FieldAttrs {
             field_type,
             field_name,
             skip,
+            skip_serializing,
+            skip_deserializing,
+            default,
+            serialize_with,
+            deserialize_with,
+            metadata,
+            nullable,
+            dictionary,
+            large,
+            as_list,
+            run_end_encoded,
+            fixed_size,
+            timestamp,
+            decimal,
+            fallback,
+            type_id,
+            validate,
+            aliases,
+            index,
+            parquet,
         }
     }
 }
@@ -180,19 +967,158 @@ impl DeriveStruct {
         let container_attrs = ContainerAttrs::from_ast(&input.attrs);
         let common = DeriveCommon::from_ast(input, &container_attrs);
 
+        let fields = ast
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(idx, f)| DeriveField::from_ast(f, Some(format_ident!("__{}{}TimeZone", input.ident, idx))))
+            .collect::<Vec<_>>();
+
+        for field in fields.iter().filter(|f| !(f.skip_serializing && f.skip_deserializing)) {
+            check_not_recursive(&field.field_type, &input.ident);
+        }
+
+        let is_unit = fields.is_empty();
+        if container_attrs.is_empty_struct.is_some() && !is_unit {
+            abort!(
+                input.span(),
+                "`mode = \"{}\"` and `mode = \"{}\"` are only supported on unit structs",
+                UNIT_MODE_NULL,
+                UNIT_MODE_STRUCT
+            );
+        }
+
         let is_transparent = if let Some(span) = container_attrs.transparent {
-            if ast.fields.len() > 1 {
-                abort!(span, "'transparent' is only supported on length-1 structs!");
+            // all other fields must be skipped, and are set to their default value when deserializing
+            let mut unskipped = fields.iter().filter(|f| !(f.skip_serializing && f.skip_deserializing));
+            match (unskipped.next(), unskipped.next()) {
+                (Some(f), None) if !f.skip_serializing && !f.skip_deserializing => {
+                    // the field isn't read from a named column
+                    if let Some(alias) = f.aliases.first() {
+                        abort!(
+                            alias.span(),
+                            "`{}` can't be used on transparent structs",
+                            FIELD_ALIAS
+                        );
+                    }
+                }
+                _ => abort!(span, "'transparent' is only supported on length-1 structs!"),
             }
             true
         } else {
             false
         };
 
+        let builder = container_attrs.builder.map(|(span, name)| {
+            if is_unit || is_transparent {
+                abort!(span, "`{}` is only supported on structs with fields", BUILDER);
+            }
+            if let Some(field) = fields
+                .iter()
+                .find(|f| !f.skip_serializing && f.syn.ident.as_ref().is_some_and(|i| i == "row"))
+            {
+                abort!(
+                    field.syn.span(),
+                    "`{}` can't be used on structs with a field named `row`, as `append_row` appends a whole row",
+                    BUILDER
+                );
+            }
+            match name {
+                Some(name) => name
+                    .parse()
+                    .unwrap_or_else(|_| abort!(name.span(), "Invalid builder name")),
+                None => format_ident!("{}Builder", input.ident),
+            }
+        });
+
+        let view = container_attrs.view.map(|(span, name)| {
+            if is_unit || is_transparent {
+                abort!(span, "`{}` is only supported on structs with fields", VIEW);
+            }
+            if !input.generics.params.is_empty() {
+                abort!(span, "`{}` is not supported on generic structs", VIEW);
+            }
+            // the view borrows the columns of the array, so it can't hold default values or converted fields
+            if let Some(field) = fields.iter().find(|f| {
+                !f.skip_deserializing && (f.skip_serializing || f.default.is_some() || f.deserialize_with.is_some())
+            }) {
+                abort!(
+                    field.syn.span(),
+                    "`{}` doesn't support fields with `{}`, `{}` or `{}`",
+                    VIEW,
+                    FIELD_DEFAULT,
+                    FIELD_SKIP_SERIALIZING,
+                    FIELD_DESERIALIZE_WITH
+                );
+            }
+            match name {
+                Some(name) => name
+                    .parse()
+                    .unwrap_or_else(|_| abort!(name.span(), "Invalid view name")),
+                None => format_ident!("{}View", input.ident),
+            }
+        });
+
+        // the name defaults to the name of the function
+        let computed = container_attrs
+            .computed
+            .into_iter()
+            .map(|attr| {
+                if is_unit || is_transparent {
+                    abort!(
+                        attr.span,
+                        "`{}` is only supported on structs with fields",
+                        COMPUTED
+                    );
+                }
+                let ident = attr.with.segments.last().unwrap().ident.clone();
+                let field_type = attr.field_type;
+                check_not_recursive(&field_type, &input.ident);
+                DeriveField {
+                    syn: syn::parse_quote!(#ident: #field_type),
+                    field_type,
+                    field_name: Some(attr.name.map_or_else(|| ident.to_string(), |name| name.value())),
+                    skip_serializing: false,
+                    skip_deserializing: true,
+                    default: None,
+                    serialize_with: None,
+                    deserialize_with: None,
+                    metadata: vec![],
+                    nullable: false,
+                    time_zone: None,
+                    validate: None,
+                    aliases: vec![],
+                    index: None,
+                    computed: Some(attr.with),
+                    parquet: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(remote) = &container_attrs.remote {
+            if is_unit {
+                abort!(
+                    remote.span(),
+                    "`{}` is only supported on structs with fields",
+                    REMOTE
+                );
+            }
+        }
+
         DeriveStruct {
             common,
-            fields: ast.fields.iter().map(DeriveField::from_ast).collect::<Vec<_>>(),
+            fields,
             is_transparent,
+            is_unit,
+            is_empty_struct: container_attrs.is_empty_struct.unwrap_or(false),
+            rename_all: container_attrs.rename_all,
+            schema_metadata: container_attrs.schema_metadata,
+            deny_unknown_fields: container_attrs.deny_unknown_fields.is_some(),
+            builder,
+            view,
+            remote: container_attrs.remote,
+            validate: container_attrs.validate,
+            computed,
         }
     }
 }
@@ -202,25 +1128,360 @@ impl DeriveEnum {
         let container_attrs = ContainerAttrs::from_ast(&input.attrs);
         let common = DeriveCommon::from_ast(input, &container_attrs);
 
+        if !input.generics.params.is_empty() {
+            abort!(input.generics.span(), "Generic enums are not supported");
+        }
+
+        // `mode = "struct"` stores enums as a struct, while `mode = "null"` is only meaningful for unit structs
+        if container_attrs.is_empty_struct == Some(false) {
+            abort!(
+                input.span(),
+                "`mode = \"{}\"` is only supported on unit structs",
+                UNIT_MODE_NULL
+            );
+        }
+        let is_struct = container_attrs.is_empty_struct == Some(true);
+        if !container_attrs.schema_metadata.is_empty() {
+            abort!(
+                input.span(),
+                "`{}` is only supported on structs",
+                SCHEMA_METADATA
+            );
+        }
+        if let Some(span) = container_attrs.deny_unknown_fields {
+            abort!(span, "`{}` is only supported on structs", DENY_UNKNOWN_FIELDS);
+        }
+        if let Some((span, _)) = container_attrs.builder {
+            abort!(span, "`{}` is only supported on structs", BUILDER);
+        }
+        if let Some((span, _)) = container_attrs.view {
+            abort!(span, "`{}` is only supported on structs", VIEW);
+        }
+        if let Some(remote) = &container_attrs.remote {
+            abort!(remote.span(), "`{}` is only supported on structs", REMOTE);
+        }
+        if let Some(validate) = &container_attrs.validate {
+            abort!(validate.span(), "`{}` is only supported on structs", VALIDATE);
+        }
+        if let Some(computed) = container_attrs.computed.first() {
+            abort!(computed.span, "`{}` is only supported on structs", COMPUTED);
+        }
+
+        let variants = ast.variants.iter().map(DeriveVariant::from_ast).collect::<Vec<_>>();
+        for v in &variants {
+            check_not_recursive(&v.field_type, &input.ident);
+        }
+
+        if container_attrs.is_dictionary && container_attrs.repr.is_some() {
+            abort!(
+                input.span(),
+                "`mode = \"{}\"` and `repr` can't be used together",
+                ENUM_MODE_DICTIONARY
+            );
+        }
+        if is_struct && container_attrs.repr.is_some() {
+            abort!(
+                input.span(),
+                "`mode = \"{}\"` and `repr` can't be used together",
+                ENUM_MODE_STRUCT
+            );
+        }
+        if container_attrs.is_dictionary || container_attrs.repr.is_some() {
+            let attr = if container_attrs.is_dictionary {
+                format!("mode = \"{ENUM_MODE_DICTIONARY}\"")
+            } else {
+                ENUM_REPR.to_string()
+            };
+            if let Some(v) = variants.iter().find(|v| !v.is_unit) {
+                abort!(
+                    v.syn.span(),
+                    "`{}` is only supported on enums with unit variants",
+                    attr
+                );
+            }
+        } else if let Some(v) = variants.iter().find(|v| v.is_fallback) {
+            abort!(
+                v.syn.span(),
+                "`{}` is only supported on enums with `mode = \"{}\"` or `repr`",
+                VARIANT_FALLBACK,
+                ENUM_MODE_DICTIONARY
+            );
+        }
+        if container_attrs.is_dictionary || container_attrs.repr.is_some() || is_struct {
+            if let Some(v) = variants.iter().find(|v| v.type_id.is_some()) {
+                abort!(
+                    v.syn.span(),
+                    "`{}` is only supported on enums stored as a union",
+                    VARIANT_TYPE_ID
+                );
+            }
+        }
+        if let Some(v) = variants.iter().filter(|v| v.is_fallback).nth(1) {
+            abort!(
+                v.syn.span(),
+                "Only one variant can be the `{}`",
+                VARIANT_FALLBACK
+            );
+        }
+
         DeriveEnum {
             common,
-            variants: ast.variants.iter().map(DeriveVariant::from_ast).collect::<Vec<_>>(),
-            is_dense: container_attrs
-                .is_dense
-                .unwrap_or_else(|| abort!(input.span(), "Missing mode attribute for enum")),
+            variants,
+            is_dense: container_attrs.is_dense.unwrap_or(true),
+            is_dictionary: container_attrs.is_dictionary,
+            is_struct,
+            repr: container_attrs.repr,
+            rename_all: container_attrs.rename_all,
         }
     }
 }
 
 impl DeriveField {
-    pub fn from_ast(input: &syn::Field) -> DeriveField {
+    /// `time_zone_marker` is the name of the marker type generated for a `timestamp` timezone, which isn't supported
+    /// if it's `None`.
+    pub fn from_ast(input: &syn::Field, time_zone_marker: Option<Ident>) -> DeriveField {
         let attrs = FieldAttrs::from_ast(&input.attrs);
 
+        if attrs.field_type.is_none() && (attrs.serialize_with.is_some() || attrs.deserialize_with.is_some()) {
+            abort!(
+                input.span(),
+                "`serialize_with` and `deserialize_with` require a `type` attribute"
+            );
+        }
+
+        if let Some(validate) = &attrs.validate {
+            if attrs.skip || attrs.skip_deserializing {
+                abort!(
+                    validate.span(),
+                    "`{}` is only supported on fields that are deserialized",
+                    VALIDATE
+                );
+            }
+        }
+        if let Some(alias) = attrs.aliases.first() {
+            if attrs.skip || attrs.skip_deserializing {
+                abort!(
+                    alias.span(),
+                    "`{}` is only supported on fields that are deserialized",
+                    FIELD_ALIAS
+                );
+            }
+        }
+        if let Some(index) = &attrs.index {
+            if attrs.skip || attrs.skip_serializing {
+                abort!(
+                    index.span(),
+                    "`{}` is only supported on fields that are serialized",
+                    FIELD_INDEX
+                );
+            }
+        }
+
+        if attrs.field_type.is_some() {
+            if attrs.dictionary.is_some() {
+                abort!(
+                    input.span(),
+                    "`{}` can't be used together with `type`, use a `Dictionary` type override instead",
+                    FIELD_DICTIONARY
+                );
+            }
+            if attrs.large {
+                abort!(
+                    input.span(),
+                    "`{}` can't be used together with `type`, use a large type override instead",
+                    FIELD_LARGE
+                );
+            }
+            if attrs.fixed_size.is_some() {
+                abort!(
+                    input.span(),
+                    "`{}` can't be used together with `type`, use a `FixedSizeVec` type override instead",
+                    FIELD_FIXED_SIZE
+                );
+            }
+        }
+        if let Some(timestamp) = &attrs.timestamp {
+            if attrs.field_type.is_some() || attrs.dictionary.is_some() || attrs.large || attrs.fixed_size.is_some() {
+                abort!(
+                    timestamp.span,
+                    "`{}` can't be used together with `type`, `{}`, `{}` or `{}`",
+                    FIELD_TIMESTAMP,
+                    FIELD_DICTIONARY,
+                    FIELD_LARGE,
+                    FIELD_FIXED_SIZE
+                );
+            }
+        }
+        if let Some(decimal) = &attrs.decimal {
+            if attrs.field_type.is_some()
+                || attrs.dictionary.is_some()
+                || attrs.large
+                || attrs.fixed_size.is_some()
+                || attrs.timestamp.is_some()
+            {
+                abort!(
+                    decimal.span,
+                    "`{}` can't be used together with `type`, `{}`, `{}`, `{}` or `{}`",
+                    FIELD_DECIMAL,
+                    FIELD_DICTIONARY,
+                    FIELD_LARGE,
+                    FIELD_FIXED_SIZE,
+                    FIELD_TIMESTAMP
+                );
+            }
+        }
+        if let Some(span) = attrs.as_list {
+            if attrs.field_type.is_some()
+                || attrs.dictionary.is_some()
+                || attrs.large
+                || attrs.fixed_size.is_some()
+                || attrs.timestamp.is_some()
+                || attrs.decimal.is_some()
+            {
+                abort!(
+                    span,
+                    "`{}` can't be used together with `type`, `{}`, `{}`, `{}`, `{}` or `{}`",
+                    FIELD_AS_LIST,
+                    FIELD_DICTIONARY,
+                    FIELD_LARGE,
+                    FIELD_FIXED_SIZE,
+                    FIELD_TIMESTAMP,
+                    FIELD_DECIMAL
+                );
+            }
+        }
+        if attrs.run_end_encoded.is_some() {
+            if attrs.field_type.is_some() {
+                abort!(
+                    input.span(),
+                    "`{}` can't be used together with `type`, use a `RunEnd` type override instead",
+                    FIELD_RUN_END_ENCODED
+                );
+            }
+            if attrs.dictionary.is_some()
+                || attrs.fixed_size.is_some()
+                || attrs.timestamp.is_some()
+                || attrs.decimal.is_some()
+                || attrs.as_list.is_some()
+            {
+                abort!(
+                    input.span(),
+                    "`{}` can't be used together with `{}`, `{}`, `{}`, `{}` or `{}`",
+                    FIELD_RUN_END_ENCODED,
+                    FIELD_DICTIONARY,
+                    FIELD_FIXED_SIZE,
+                    FIELD_TIMESTAMP,
+                    FIELD_DECIMAL,
+                    FIELD_AS_LIST
+                );
+            }
+        }
+        if attrs.large && attrs.fixed_size.is_some() {
+            abort!(
+                input.span(),
+                "`{}` and `{}` can't be used together",
+                FIELD_LARGE,
+                FIELD_FIXED_SIZE
+            );
+        }
+
+        let mut field_type = attrs.field_type.unwrap_or_else(|| input.ty.clone());
+        if attrs.large {
+            field_type = map_option_inner(&field_type, |ty| {
+                large_type(ty).unwrap_or_else(|| {
+                    abort!(
+                        input.span(),
+                        "`{}` is only supported on `String`, `Vec<u8>` and `Vec<T>` fields",
+                        FIELD_LARGE
+                    )
+                })
+            });
+        }
+        if attrs.as_list.is_some() {
+            field_type = map_option_inner(&field_type, |ty| {
+                byte_list_type(ty).unwrap_or_else(|| {
+                    abort!(
+                        input.span(),
+                        "`{}` is only supported on `Vec<u8>` fields",
+                        FIELD_AS_LIST
+                    )
+                })
+            });
+        }
+        if let Some(size) = &attrs.fixed_size {
+            field_type = map_option_inner(&field_type, |ty| {
+                fixed_size_type(ty, size).unwrap_or_else(|| {
+                    abort!(
+                        input.span(),
+                        "`{}` is only supported on `Vec<T>` and `[T; N]` fields",
+                        FIELD_FIXED_SIZE
+                    )
+                })
+            });
+        }
+        let mut time_zone = None;
+        if let Some(timestamp) = &attrs.timestamp {
+            let placeholder = &timestamp.placeholder;
+            let tz_type: syn::Type = match &timestamp.tz {
+                None => syn::parse_quote!(arrow_convert::field::NoTimeZone),
+                Some(tz) if tz.value() == "UTC" => syn::parse_quote!(arrow_convert::field::UtcTimeZone),
+                Some(tz) => {
+                    let Some(marker) = time_zone_marker else {
+                        abort!(
+                            tz.span(),
+                            "Only `tz = \"UTC\"` is supported on enum variant fields"
+                        );
+                    };
+                    time_zone = Some((marker.clone(), tz.value()));
+                    syn::parse_quote!(#marker)
+                }
+            };
+            field_type = map_option_inner(
+                &field_type,
+                |ty| syn::parse_quote!(arrow_convert::field::#placeholder<#ty, #tz_type>),
+            );
+        }
+        if let Some(decimal) = &attrs.decimal {
+            field_type = map_option_inner(&field_type, |ty| {
+                decimal_type(ty, &decimal.precision, &decimal.scale).unwrap_or_else(|| {
+                    abort!(
+                        input.span(),
+                        "`{}` is only supported on `i128` and `rust_decimal::Decimal` fields",
+                        FIELD_DECIMAL
+                    )
+                })
+            });
+        }
+        if let Some(run_end) = &attrs.run_end_encoded {
+            field_type = map_option_inner(
+                &field_type,
+                |ty| syn::parse_quote!(arrow_convert::field::RunEnd<#run_end, #ty>),
+            );
+        }
+        if let Some(key) = &attrs.dictionary {
+            field_type = map_option_inner(
+                &field_type,
+                |ty| syn::parse_quote!(arrow_convert::field::Dictionary<#key, #ty>),
+            );
+        }
+
         DeriveField {
             syn: input.clone(),
-            field_type: attrs.field_type.unwrap_or_else(|| input.ty.clone()),
+            field_type,
             field_name: attrs.field_name,
-            skip: attrs.skip,
+            skip_serializing: attrs.skip || attrs.skip_serializing,
+            skip_deserializing: attrs.skip || attrs.skip_deserializing,
+            default: attrs.default,
+            serialize_with: attrs.serialize_with,
+            deserialize_with: attrs.deserialize_with,
+            metadata: attrs.metadata,
+            nullable: attrs.nullable,
+            time_zone,
+            validate: attrs.validate,
+            aliases: attrs.aliases,
+            index: attrs.index,
+            computed: None,
+            parquet: attrs.parquet,
         }
     }
 }
@@ -229,24 +1490,56 @@ impl DeriveVariant {
     pub fn from_ast(input: &syn::Variant) -> DeriveVariant {
         let attrs = FieldAttrs::from_ast(&input.attrs);
 
-        let (is_unit, field_type) = match &input.fields {
-            syn::Fields::Named(_f) => {
-                unimplemented!()
-            }
-            syn::Fields::Unnamed(f) => {
-                if f.unnamed.len() > 1 {
-                    unimplemented!()
-                } else {
-                    (false, f.unnamed[0].ty.clone())
+        let (is_unit, field_type, fields) = match &input.fields {
+            syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => (false, f.unnamed[0].ty.clone(), vec![]),
+            syn::Fields::Named(_) | syn::Fields::Unnamed(_) => {
+                let fields = input
+                    .fields
+                    .iter()
+                    .map(|f| DeriveField::from_ast(f, None))
+                    .collect::<Vec<_>>();
+                if fields.is_empty() {
+                    abort!(input.span(), "Expected enum variant to have at least one field");
                 }
+                for field in &fields {
+                    if field.skip_serializing
+                        || field.skip_deserializing
+                        || field.default.is_some()
+                        || field.serialize_with.is_some()
+                        || field.deserialize_with.is_some()
+                        || !field.metadata.is_empty()
+                        || field.nullable
+                        || field.validate.is_some()
+                        || !field.aliases.is_empty()
+                        || field.index.is_some()
+                        || field.parquet.is_some()
+                    {
+                        abort!(
+                            field.syn.span(),
+                            "Only the `name`, `type`, `dictionary`, `large`, `fixed_size`, `timestamp`, `decimal`, `as_list` and `run_end_encoded` attributes are supported on enum variant fields"
+                        );
+                    }
+                }
+                if attrs.field_type.is_some() {
+                    abort!(
+                        input.span(),
+                        "The `type` attribute is only supported on enum variants with a single unnamed field"
+                    );
+                }
+                let types = fields.iter().map(|f| &f.field_type);
+                (false, syn::parse_quote!((#(#types,)*)), fields)
             }
 
-            syn::Fields::Unit => (true, syn::parse_str("bool").unwrap()),
+            syn::Fields::Unit => (true, syn::parse_str("bool").unwrap(), vec![]),
         };
         DeriveVariant {
             syn: input.clone(),
             field_type: attrs.field_type.unwrap_or_else(|| field_type.clone()),
+            field_name: attrs.field_name,
             is_unit,
+            is_fallback: attrs.fallback,
+            type_id: attrs.type_id,
+            fields,
         }
     }
 }